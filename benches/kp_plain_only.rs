@@ -4,10 +4,11 @@
 //!
 //! Compares:
 //! - Keypath approach: Kp.then().then().then() chain
+//! - Offset keypath: OffsetKp composed in const (one pointer add)
 //! - Direct field access: root.l1.inner.leaf
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use rust_key_paths::Kp;
+use rust_key_paths::{Kp, OffsetKp, offset_kp};
 
 // Root -> Level1
 #[derive(Clone)]
@@ -75,6 +76,11 @@ fn build_and_get_mut(root: &mut Root) -> Option<&mut i32> {
     chain.get_mut(root)
 }
 
+const LEAF: OffsetKp<Root, i32> = offset_kp!(Root, l1)
+    .then(offset_kp!(Level1, inner))
+    .then(offset_kp!(Level2, inner))
+    .then(offset_kp!(Level3, leaf));
+
 fn bench_kp_plain_read(c: &mut Criterion) {
     let mut group = c.benchmark_group("kp_plain_read");
 
@@ -88,6 +94,16 @@ fn bench_kp_plain_read(c: &mut Criterion) {
         })
     });
 
+    // Offset keypath
+    group.bench_function("offset", |b| {
+        let root = make_root();
+        b.iter(|| {
+            let root_ref = black_box(&root);
+            let leaf = LEAF.get(root_ref);
+            black_box(leaf);
+        })
+    });
+
     // Direct field access
     group.bench_function("direct", |b| {
         let root = make_root();
@@ -114,6 +130,14 @@ fn bench_kp_plain_write(c: &mut Criterion) {
         })
     });
 
+    // Offset keypath
+    group.bench_function("offset", |b| {
+        let mut root = make_root();
+        b.iter(|| {
+            *LEAF.get_mut(black_box(&mut root)) = 99;
+        })
+    });
+
     // Direct field access
     group.bench_function("direct", |b| {
         let mut root = make_root();
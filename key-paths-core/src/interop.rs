@@ -0,0 +1,223 @@
+//! Conversions between [`KeyPaths`] / [`SyncKeyPaths`] and `rust_key_paths::Kp` (`kp` feature).
+//!
+//! `Kp` is the hub: `rust-keypaths` converts its `KpType` and readable + writable pairs into a
+//! `Kp` (its `lens` feature), and from there into the enums here, so all three models meet.
+//!
+//! - A `Kp` becomes [`KeyPaths::FailableCombined`] / [`SyncKeyPaths::FailableCombined`] with its
+//!   getter and setter; the owned accessor is always `None`.
+//! - [`KeyPaths`] becomes a [`KeyPathsKp`] and [`SyncKeyPaths`] a `KpDynamic`. A variant without
+//!   a reader (or writer) gives a `Kp` whose `get` (or `get_mut`) is always `None`; owned
+//!   variants have neither.
+//!
+//! ```
+//! use key_paths_core::{KeyPaths, KeyPathsKp, SyncKeyPaths};
+//! use rust_key_paths::{KpDynamic, KpType};
+//!
+//! struct Account { balance: i64 }
+//!
+//! let kp: KpType<'static, Account, i64> = KpType::new(
+//!     |a: &Account| Some(&a.balance),
+//!     |a: &mut Account| Some(&mut a.balance),
+//! );
+//! let mut account = Account { balance: 10 };
+//!
+//! let legacy: KeyPaths<Account, i64> = kp.into();
+//! *legacy.get_mut(&mut account).unwrap() += 5;
+//!
+//! assert_eq!(legacy.get(&account), Some(&15));
+//!
+//! let back: KeyPathsKp<Account, i64> = legacy.into();
+//! assert_eq!(back.get(&account), Some(&15));
+//! assert_eq!(back.get_mut(&mut account), Some(&mut 15));
+//!
+//! let shared: KpDynamic<Account, i64> =
+//!     SyncKeyPaths::failable_readable(|a: &Account| Some(&a.balance)).into();
+//! assert_eq!(shared.get(&account), Some(&15));
+//! assert_eq!(shared.get_mut(&mut account), None);
+//! ```
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use rust_key_paths::{Kp, KpDynamic};
+
+use crate::{KeyPaths, SyncKeyPaths};
+
+type Getter<R, V> = Box<dyn for<'a> Fn(&'a R) -> Option<&'a V>>;
+type Setter<R, V> = Box<dyn for<'a> Fn(&'a mut R) -> Option<&'a mut V>>;
+type SyncGetter<R, V> = Box<dyn for<'a> Fn(&'a R) -> Option<&'a V> + Send + Sync>;
+type SyncSetter<R, V> = Box<dyn for<'a> Fn(&'a mut R) -> Option<&'a mut V> + Send + Sync>;
+
+/// `Kp` over the boxed, non-`Send` closures of a [`KeyPaths`].
+pub type KeyPathsKp<R, V> = Kp<
+    R,
+    V,
+    &'static R,
+    &'static V,
+    &'static mut R,
+    &'static mut V,
+    Getter<R, V>,
+    Setter<R, V>,
+>;
+
+impl<'x, R, V, G, S> From<Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>> for KeyPaths<R, V>
+where
+    G: for<'a> Fn(&'a R) -> Option<&'a V> + 'static,
+    S: for<'a> Fn(&'a mut R) -> Option<&'a mut V> + 'static,
+{
+    fn from(kp: Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>) -> Self {
+        let (get, set) = kp.into_parts();
+        KeyPaths::FailableCombined {
+            readable: Rc::new(get),
+            writable: Rc::new(set),
+            owned: Rc::new(|_| None),
+        }
+    }
+}
+
+impl<'x, R, V, G, S> From<Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>> for SyncKeyPaths<R, V>
+where
+    G: for<'a> Fn(&'a R) -> Option<&'a V> + Send + Sync + 'static,
+    S: for<'a> Fn(&'a mut R) -> Option<&'a mut V> + Send + Sync + 'static,
+{
+    fn from(kp: Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>) -> Self {
+        let (get, set) = kp.into_parts();
+        SyncKeyPaths::FailableCombined {
+            readable: Arc::new(get),
+            writable: Arc::new(set),
+            owned: Arc::new(|_| None),
+        }
+    }
+}
+
+impl<R: 'static, V: 'static> From<KeyPaths<R, V>> for KeyPathsKp<R, V> {
+    fn from(kp: KeyPaths<R, V>) -> Self {
+        let get: Getter<R, V> = match &kp {
+            KeyPaths::Readable(f) => {
+                let f = f.clone();
+                Box::new(move |r| Some(f(r)))
+            }
+            KeyPaths::FailableReadable(f)
+            | KeyPaths::ReadableEnum { extract: f, .. }
+            | KeyPaths::WritableEnum { extract: f, .. }
+            | KeyPaths::FailableCombined { readable: f, .. } => {
+                let f = f.clone();
+                Box::new(move |r| f(r))
+            }
+            _ => Box::new(|_| None),
+        };
+        let set: Setter<R, V> = match kp {
+            KeyPaths::Writable(f) | KeyPaths::ReferenceWritable(f) => Box::new(move |r| Some(f(r))),
+            KeyPaths::FailableWritable(f)
+            | KeyPaths::WritableEnum { extract_mut: f, .. }
+            | KeyPaths::FailableCombined { writable: f, .. } => Box::new(move |r| f(r)),
+            _ => Box::new(|_| None),
+        };
+        Kp::new(get, set)
+    }
+}
+
+impl<R: 'static, V: 'static> From<SyncKeyPaths<R, V>> for KpDynamic<R, V> {
+    fn from(kp: SyncKeyPaths<R, V>) -> Self {
+        let get: SyncGetter<R, V> = match &kp {
+            SyncKeyPaths::Readable(f) => {
+                let f = f.clone();
+                Box::new(move |r| Some(f(r)))
+            }
+            SyncKeyPaths::FailableReadable(f)
+            | SyncKeyPaths::ReadableEnum { extract: f, .. }
+            | SyncKeyPaths::WritableEnum { extract: f, .. }
+            | SyncKeyPaths::FailableCombined { readable: f, .. } => {
+                let f = f.clone();
+                Box::new(move |r| f(r))
+            }
+            _ => Box::new(|_| None),
+        };
+        let set: SyncSetter<R, V> = match kp {
+            SyncKeyPaths::Writable(f) | SyncKeyPaths::ReferenceWritable(f) => {
+                Box::new(move |r| Some(f(r)))
+            }
+            SyncKeyPaths::FailableWritable(f)
+            | SyncKeyPaths::WritableEnum { extract_mut: f, .. }
+            | SyncKeyPaths::FailableCombined { writable: f, .. } => Box::new(move |r| f(r)),
+            _ => Box::new(|_| None),
+        };
+        Kp::new(get, set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_key_paths::KpType;
+
+    struct Account {
+        balance: i64,
+        owner: Option<String>,
+    }
+
+    fn balance() -> KpType<'static, Account, i64> {
+        KpType::new(|a: &Account| Some(&a.balance), |a: &mut Account| Some(&mut a.balance))
+    }
+
+    fn owner() -> KpType<'static, Account, String> {
+        KpType::new(|a: &Account| a.owner.as_ref(), |a: &mut Account| a.owner.as_mut())
+    }
+
+    fn account() -> Account {
+        Account { balance: 10, owner: None }
+    }
+
+    #[test]
+    fn kp_into_keypaths_keeps_getter_and_setter() {
+        let mut account = account();
+
+        let legacy: KeyPaths<Account, i64> = balance().into();
+        assert_eq!(legacy.kind_name(), "FailableCombined");
+        assert_eq!(legacy.get(&account), Some(&10));
+        *legacy.get_mut(&mut account).unwrap() += 1;
+        assert_eq!(account.balance, 11);
+        assert_eq!(legacy.get_failable_owned(account), None);
+
+        let shared: SyncKeyPaths<Account, String> = owner().into();
+        assert_eq!(shared.kind_name(), "FailableCombined");
+        let mut account = Account { balance: 0, owner: Some("ada".into()) };
+        assert_eq!(shared.get(&account).map(String::as_str), Some("ada"));
+        shared.get_mut(&mut account).unwrap().push('!');
+        assert_eq!(account.owner.as_deref(), Some("ada!"));
+        assert_eq!(shared.get(&self::account()), None);
+    }
+
+    #[test]
+    fn round_trip_through_kp() {
+        let mut account = account();
+
+        let back: KeyPathsKp<Account, i64> = KeyPaths::from(balance()).into();
+        assert_eq!(back.get(&account), Some(&10));
+        *back.get_mut(&mut account).unwrap() = 3;
+        assert_eq!(account.balance, 3);
+
+        let back: KpDynamic<Account, i64> = SyncKeyPaths::from(balance()).into();
+        assert_eq!(back.get(&account), Some(&3));
+        assert_eq!(back.get_mut(&mut account), Some(&mut 3));
+    }
+
+    #[test]
+    fn one_sided_variants_leave_the_other_side_empty() {
+        let mut account = account();
+
+        let read_only: KeyPathsKp<Account, i64> =
+            KeyPaths::readable(|a: &Account| &a.balance).into();
+        assert_eq!(read_only.get(&account), Some(&10));
+        assert_eq!(read_only.get_mut(&mut account), None);
+
+        let write_only: KpDynamic<Account, i64> =
+            SyncKeyPaths::writable(|a: &mut Account| &mut a.balance).into();
+        assert_eq!(write_only.get(&account), None);
+        assert_eq!(write_only.get_mut(&mut account), Some(&mut 10));
+
+        let owned: KpDynamic<Account, i64> = SyncKeyPaths::owned(|a: Account| a.balance).into();
+        assert_eq!(owned.get(&account), None);
+        assert_eq!(owned.get_mut(&mut account), None);
+    }
+}
@@ -6,6 +6,16 @@ use std::any::Any;
 #[cfg(feature = "tagged_core")]
 use tagged_core::Tagged;
 
+// Arc-based, Send + Sync variants of KeyPaths / PartialKeyPath / AnyKeyPath
+pub mod sync;
+pub use sync::{SyncAnyKeyPath, SyncKeyPaths, SyncPartialKeyPath};
+
+// From/Into conversions with rust_key_paths::Kp
+#[cfg(feature = "kp")]
+pub mod interop;
+#[cfg(feature = "kp")]
+pub use interop::KeyPathsKp;
+
 /// Trait for no-clone callback-based access to container types
 /// Provides methods to execute closures with references to values inside containers
 /// without requiring cloning of the values
@@ -0,0 +1,891 @@
+//! Thread-safe counterparts of [KeyPaths], [PartialKeyPath] and [AnyKeyPath].
+//!
+//! The closures live in `Arc<dyn Fn + Send + Sync>` instead of `Rc<dyn Fn>`, so the keypaths
+//! are `Send + Sync` and can be shared with worker threads. The variants, constructors,
+//! `compose` / `then` and the `for_arc*` adapters mirror the `Rc`-based types, except that
+//! unsupported combinations return `None` instead of panicking.
+//!
+//! Every `Sync*` keypath converts into its `Rc` counterpart with `From`, keeping the variant.
+//! The conversion is one-way: an `Rc` keypath may capture non-`Send` state, so build the
+//! keypath as a `SyncKeyPaths` from the start where both are needed.
+//!
+//! # Example
+//! ```
+//! use std::sync::{Arc, Mutex};
+//! use std::thread;
+//! use key_paths_core::{KeyPaths, SyncKeyPaths};
+//!
+//! #[derive(Clone)]
+//! struct Config { name: String }
+//! struct App { config: Config }
+//!
+//! let config = SyncKeyPaths::readable(|a: &App| &a.config);
+//! let name = SyncKeyPaths::readable(|c: &Config| &c.name);
+//! let app_name = config.then(name).unwrap();
+//!
+//! let shared = Arc::new(Mutex::new(App { config: Config { name: "demo".into() } }));
+//! let locked = app_name.clone().for_arc_mutex().unwrap();
+//! let worker = {
+//!     let shared = Arc::clone(&shared);
+//!     thread::spawn(move || locked.get_failable_owned(shared))
+//! };
+//! assert_eq!(worker.join().unwrap().as_deref(), Some("demo"));
+//!
+//! // Same variant on the `Rc` side
+//! let local: KeyPaths<App, String> = app_name.into();
+//! assert_eq!(local.kind_name(), "Readable");
+//! ```
+
+use std::any::Any;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::{AnyKeyPath, KeyPaths, PartialKeyPath};
+
+// Shared closure shapes of the variants below (`Value` / `Root` may be `dyn Any`).
+type SyncGet<Root, Value> = Arc<dyn for<'a> Fn(&'a Root) -> &'a Value + Send + Sync>;
+type SyncTryGet<Root, Value> = Arc<dyn for<'a> Fn(&'a Root) -> Option<&'a Value> + Send + Sync>;
+type SyncGetMut<Root, Value> = Arc<dyn for<'a> Fn(&'a mut Root) -> &'a mut Value + Send + Sync>;
+type SyncTryGetMut<Root, Value> = Arc<dyn for<'a> Fn(&'a mut Root) -> Option<&'a mut Value> + Send + Sync>;
+type SyncFn<In, Out> = Arc<dyn Fn(In) -> Out + Send + Sync>;
+
+pub enum SyncKeyPaths<Root, Value> {
+    Readable(SyncGet<Root, Value>),
+    ReadableEnum {
+        extract: SyncTryGet<Root, Value>,
+        embed: SyncFn<Value, Root>,
+    },
+    FailableReadable(SyncTryGet<Root, Value>),
+
+    Writable(SyncGetMut<Root, Value>),
+    FailableWritable(SyncTryGetMut<Root, Value>),
+    WritableEnum {
+        extract: SyncTryGet<Root, Value>,
+        extract_mut: SyncTryGetMut<Root, Value>,
+        embed: SyncFn<Value, Root>,
+    },
+
+    ReferenceWritable(SyncGetMut<Root, Value>),
+
+    Owned(SyncFn<Root, Value>),
+    FailableOwned(SyncFn<Root, Option<Value>>),
+
+    FailableCombined {
+        readable: SyncTryGet<Root, Value>,
+        writable: SyncTryGetMut<Root, Value>,
+        owned: SyncFn<Root, Option<Value>>,
+    },
+}
+
+/// Thread-safe [PartialKeyPath]: known Root, type-erased Value.
+#[derive(Clone)]
+pub enum SyncPartialKeyPath<Root> {
+    Readable(SyncGet<Root, dyn Any>),
+    ReadableEnum {
+        extract: SyncTryGet<Root, dyn Any>,
+        embed: SyncFn<Box<dyn Any>, Root>,
+    },
+    FailableReadable(SyncTryGet<Root, dyn Any>),
+
+    Writable(SyncGetMut<Root, dyn Any>),
+    FailableWritable(SyncTryGetMut<Root, dyn Any>),
+    WritableEnum {
+        extract: SyncTryGet<Root, dyn Any>,
+        extract_mut: SyncTryGetMut<Root, dyn Any>,
+        embed: SyncFn<Box<dyn Any>, Root>,
+    },
+
+    ReferenceWritable(SyncGetMut<Root, dyn Any>),
+
+    Owned(SyncFn<Root, Box<dyn Any>>),
+    FailableOwned(SyncFn<Root, Option<Box<dyn Any>>>),
+
+    FailableCombined {
+        readable: SyncTryGet<Root, dyn Any>,
+        writable: SyncTryGetMut<Root, dyn Any>,
+        owned: SyncFn<Root, Option<Box<dyn Any>>>,
+    },
+}
+
+/// Thread-safe [AnyKeyPath]: Root and Value both type-erased.
+#[derive(Clone)]
+pub enum SyncAnyKeyPath {
+    Readable(SyncGet<dyn Any, dyn Any>),
+    ReadableEnum {
+        extract: SyncTryGet<dyn Any, dyn Any>,
+        embed: SyncFn<Box<dyn Any>, Box<dyn Any>>,
+    },
+    FailableReadable(SyncTryGet<dyn Any, dyn Any>),
+
+    Writable(SyncGetMut<dyn Any, dyn Any>),
+    FailableWritable(SyncTryGetMut<dyn Any, dyn Any>),
+    WritableEnum {
+        extract: SyncTryGet<dyn Any, dyn Any>,
+        extract_mut: SyncTryGetMut<dyn Any, dyn Any>,
+        embed: SyncFn<Box<dyn Any>, Box<dyn Any>>,
+    },
+
+    ReferenceWritable(SyncGetMut<dyn Any, dyn Any>),
+
+    Owned(SyncFn<Box<dyn Any>, Box<dyn Any>>),
+    FailableOwned(SyncFn<Box<dyn Any>, Option<Box<dyn Any>>>),
+
+    FailableCombined {
+        readable: SyncTryGet<dyn Any, dyn Any>,
+        writable: SyncTryGetMut<dyn Any, dyn Any>,
+        owned: SyncFn<Box<dyn Any>, Option<Box<dyn Any>>>,
+    },
+}
+
+impl<Root, Value> Clone for SyncKeyPaths<Root, Value> {
+    fn clone(&self) -> Self {
+        use SyncKeyPaths::*;
+        match self {
+            Readable(f) => Readable(f.clone()),
+            Writable(f) => Writable(f.clone()),
+            FailableReadable(f) => FailableReadable(f.clone()),
+            FailableWritable(f) => FailableWritable(f.clone()),
+            ReadableEnum { extract, embed } => ReadableEnum {
+                extract: extract.clone(),
+                embed: embed.clone(),
+            },
+            WritableEnum { extract, extract_mut, embed } => WritableEnum {
+                extract: extract.clone(),
+                extract_mut: extract_mut.clone(),
+                embed: embed.clone(),
+            },
+            ReferenceWritable(f) => ReferenceWritable(f.clone()),
+            Owned(f) => Owned(f.clone()),
+            FailableOwned(f) => FailableOwned(f.clone()),
+            FailableCombined { readable, writable, owned } => FailableCombined {
+                readable: readable.clone(),
+                writable: writable.clone(),
+                owned: owned.clone(),
+            },
+        }
+    }
+}
+
+impl<Root, Value> SyncKeyPaths<Root, Value> {
+    #[inline]
+    pub fn readable(get: impl for<'a> Fn(&'a Root) -> &'a Value + Send + Sync + 'static) -> Self {
+        Self::Readable(Arc::new(get))
+    }
+
+    #[inline]
+    pub fn writable(
+        get_mut: impl for<'a> Fn(&'a mut Root) -> &'a mut Value + Send + Sync + 'static,
+    ) -> Self {
+        Self::Writable(Arc::new(get_mut))
+    }
+
+    #[inline]
+    pub fn failable_readable(
+        get: impl for<'a> Fn(&'a Root) -> Option<&'a Value> + Send + Sync + 'static,
+    ) -> Self {
+        Self::FailableReadable(Arc::new(get))
+    }
+
+    #[inline]
+    pub fn failable_writable(
+        get_mut: impl for<'a> Fn(&'a mut Root) -> Option<&'a mut Value> + Send + Sync + 'static,
+    ) -> Self {
+        Self::FailableWritable(Arc::new(get_mut))
+    }
+
+    #[inline]
+    pub fn readable_enum(
+        embed: impl Fn(Value) -> Root + Send + Sync + 'static,
+        extract: impl for<'a> Fn(&'a Root) -> Option<&'a Value> + Send + Sync + 'static,
+    ) -> Self {
+        Self::ReadableEnum {
+            extract: Arc::new(extract),
+            embed: Arc::new(embed),
+        }
+    }
+
+    #[inline]
+    pub fn writable_enum(
+        embed: impl Fn(Value) -> Root + Send + Sync + 'static,
+        extract: impl for<'a> Fn(&'a Root) -> Option<&'a Value> + Send + Sync + 'static,
+        extract_mut: impl for<'a> Fn(&'a mut Root) -> Option<&'a mut Value> + Send + Sync + 'static,
+    ) -> Self {
+        Self::WritableEnum {
+            extract: Arc::new(extract),
+            extract_mut: Arc::new(extract_mut),
+            embed: Arc::new(embed),
+        }
+    }
+
+    #[inline]
+    pub fn owned(get: impl Fn(Root) -> Value + Send + Sync + 'static) -> Self {
+        Self::Owned(Arc::new(get))
+    }
+
+    #[inline]
+    pub fn failable_owned(get: impl Fn(Root) -> Option<Value> + Send + Sync + 'static) -> Self {
+        Self::FailableOwned(Arc::new(get))
+    }
+
+    #[inline]
+    pub fn failable_combined(
+        readable: impl for<'a> Fn(&'a Root) -> Option<&'a Value> + Send + Sync + 'static,
+        writable: impl for<'a> Fn(&'a mut Root) -> Option<&'a mut Value> + Send + Sync + 'static,
+        owned: impl Fn(Root) -> Option<Value> + Send + Sync + 'static,
+    ) -> Self {
+        Self::FailableCombined {
+            readable: Arc::new(readable),
+            writable: Arc::new(writable),
+            owned: Arc::new(owned),
+        }
+    }
+
+    #[inline]
+    pub fn reference_writable(
+        get_mut: impl for<'a> Fn(&'a mut Root) -> &'a mut Value + Send + Sync + 'static,
+    ) -> Self {
+        Self::ReferenceWritable(Arc::new(get_mut))
+    }
+
+    /// Get an immutable reference if possible
+    #[inline(always)]
+    pub fn get<'a>(&'a self, root: &'a Root) -> Option<&'a Value> {
+        use SyncKeyPaths::*;
+        match self {
+            Readable(f) => Some(f(root)),
+            FailableReadable(f) => f(root),
+            ReadableEnum { extract, .. } | WritableEnum { extract, .. } => extract(root),
+            FailableCombined { readable, .. } => readable(root),
+            Writable(_) | FailableWritable(_) | ReferenceWritable(_) => None, // needs mut
+            Owned(_) | FailableOwned(_) => None, // owned keypaths don't work with references
+        }
+    }
+
+    /// Get a mutable reference if possible
+    #[inline(always)]
+    pub fn get_mut<'a>(&'a self, root: &'a mut Root) -> Option<&'a mut Value> {
+        use SyncKeyPaths::*;
+        match self {
+            Writable(f) | ReferenceWritable(f) => Some(f(root)),
+            FailableWritable(f) => f(root),
+            WritableEnum { extract_mut, .. } => extract_mut(root),
+            FailableCombined { writable, .. } => writable(root),
+            Readable(_) | FailableReadable(_) | ReadableEnum { .. } => None, // immutable only
+            Owned(_) | FailableOwned(_) => None, // owned keypaths don't work with references
+        }
+    }
+
+    /// Get an owned value (primary method for owned keypaths)
+    #[inline]
+    pub fn get_owned(self, root: Root) -> Value {
+        match self {
+            SyncKeyPaths::Owned(f) => f(root),
+            _ => panic!("get_owned only works with owned keypaths"),
+        }
+    }
+
+    /// Get an owned value with failable access
+    #[inline]
+    pub fn get_failable_owned(self, root: Root) -> Option<Value> {
+        match self {
+            SyncKeyPaths::FailableOwned(f) => f(root),
+            SyncKeyPaths::FailableCombined { owned, .. } => owned(root),
+            _ => panic!("get_failable_owned only works with failable owned keypaths"),
+        }
+    }
+
+    pub fn embed(&self, value: Value) -> Option<Root> {
+        match self {
+            SyncKeyPaths::ReadableEnum { embed, .. } | SyncKeyPaths::WritableEnum { embed, .. } => {
+                Some(embed(value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the kind name of this keypath
+    #[inline]
+    pub fn kind_name(&self) -> &'static str {
+        use SyncKeyPaths::*;
+        match self {
+            Readable(_) => "Readable",
+            Writable(_) => "Writable",
+            FailableReadable(_) => "FailableReadable",
+            FailableWritable(_) => "FailableWritable",
+            ReadableEnum { .. } => "ReadableEnum",
+            WritableEnum { .. } => "WritableEnum",
+            ReferenceWritable(_) => "ReferenceWritable",
+            Owned(_) => "Owned",
+            FailableOwned(_) => "FailableOwned",
+            FailableCombined { .. } => "FailableCombined",
+        }
+    }
+}
+
+impl<Root, Value> SyncKeyPaths<Root, Value>
+where
+    Root: 'static,
+    Value: 'static,
+{
+    /// Convert this keypath to a [SyncPartialKeyPath] (type-erased Value)
+    pub fn to_partial(self) -> SyncPartialKeyPath<Root> {
+        use SyncKeyPaths::*;
+        match self {
+            Readable(f) => SyncPartialKeyPath::Readable(Arc::new(move |root| f(root) as &dyn Any)),
+            Writable(f) => SyncPartialKeyPath::Writable(Arc::new(move |root| f(root) as &mut dyn Any)),
+            FailableReadable(f) => SyncPartialKeyPath::FailableReadable(Arc::new(move |root| {
+                f(root).map(|v| v as &dyn Any)
+            })),
+            FailableWritable(f) => SyncPartialKeyPath::FailableWritable(Arc::new(move |root| {
+                f(root).map(|v| v as &mut dyn Any)
+            })),
+            ReadableEnum { extract, embed } => SyncPartialKeyPath::ReadableEnum {
+                extract: Arc::new(move |root| extract(root).map(|v| v as &dyn Any)),
+                embed: Arc::new(move |value| embed(*value.downcast::<Value>().unwrap())),
+            },
+            WritableEnum { extract, extract_mut, embed } => SyncPartialKeyPath::WritableEnum {
+                extract: Arc::new(move |root| extract(root).map(|v| v as &dyn Any)),
+                extract_mut: Arc::new(move |root| extract_mut(root).map(|v| v as &mut dyn Any)),
+                embed: Arc::new(move |value| embed(*value.downcast::<Value>().unwrap())),
+            },
+            ReferenceWritable(f) => {
+                SyncPartialKeyPath::ReferenceWritable(Arc::new(move |root| f(root) as &mut dyn Any))
+            }
+            Owned(f) => SyncPartialKeyPath::Owned(Arc::new(move |root| Box::new(f(root)) as Box<dyn Any>)),
+            FailableOwned(f) => SyncPartialKeyPath::FailableOwned(Arc::new(move |root| {
+                f(root).map(|v| Box::new(v) as Box<dyn Any>)
+            })),
+            FailableCombined { readable, writable, owned } => SyncPartialKeyPath::FailableCombined {
+                readable: Arc::new(move |root| readable(root).map(|v| v as &dyn Any)),
+                writable: Arc::new(move |root| writable(root).map(|v| v as &mut dyn Any)),
+                owned: Arc::new(move |root| owned(root).map(|v| Box::new(v) as Box<dyn Any>)),
+            },
+        }
+    }
+
+    /// Convert this keypath to a [SyncAnyKeyPath] (fully type-erased)
+    pub fn to_any(self) -> SyncAnyKeyPath {
+        self.to_partial().to_any()
+    }
+
+    /// Alias for `compose` for ergonomic chaining.
+    #[inline]
+    pub fn then<Next>(self, next: SyncKeyPaths<Value, Next>) -> Option<SyncKeyPaths<Root, Next>>
+    where
+        Next: 'static,
+    {
+        self.compose(next)
+    }
+
+    /// Same combinations as [KeyPaths::compose]; `None` for the unsupported ones.
+    pub fn compose<Next>(self, next: SyncKeyPaths<Value, Next>) -> Option<SyncKeyPaths<Root, Next>>
+    where
+        Next: 'static,
+    {
+        use SyncKeyPaths::*;
+
+        Some(match (self, next) {
+            (Readable(f1), Readable(f2)) => Readable(Arc::new(move |r| f2(f1(r)))),
+            (Writable(f1), Writable(f2)) => Writable(Arc::new(move |r| f2(f1(r)))),
+
+            (FailableReadable(f1), Readable(f2)) => {
+                FailableReadable(Arc::new(move |r| f1(r).map(|m| f2(m))))
+            }
+            (Readable(f1), FailableReadable(f2)) => FailableReadable(Arc::new(move |r| f2(f1(r)))),
+            (FailableReadable(f1), FailableReadable(f2)) => {
+                FailableReadable(Arc::new(move |r| f1(r).and_then(|m| f2(m))))
+            }
+
+            (FailableWritable(f1), Writable(f2)) => {
+                FailableWritable(Arc::new(move |r| f1(r).map(|m| f2(m))))
+            }
+            (Writable(f1), FailableWritable(f2)) => FailableWritable(Arc::new(move |r| f2(f1(r)))),
+            (FailableWritable(f1), FailableWritable(f2)) => {
+                FailableWritable(Arc::new(move |r| f1(r).and_then(|m| f2(m))))
+            }
+
+            (FailableReadable(f1), ReadableEnum { extract, .. }) => {
+                FailableReadable(Arc::new(move |r| f1(r).and_then(|m| extract(m))))
+            }
+            (ReadableEnum { extract, .. } | WritableEnum { extract, .. }, Readable(f2)) => {
+                FailableReadable(Arc::new(move |r| extract(r).map(|m| f2(m))))
+            }
+            (ReadableEnum { extract, .. } | WritableEnum { extract, .. }, FailableReadable(f2)) => {
+                FailableReadable(Arc::new(move |r| extract(r).and_then(|m| f2(m))))
+            }
+
+            (WritableEnum { extract_mut, .. }, Writable(f2)) => {
+                FailableWritable(Arc::new(move |r| extract_mut(r).map(|m| f2(m))))
+            }
+            (WritableEnum { extract_mut, .. }, FailableWritable(f2)) => {
+                FailableWritable(Arc::new(move |r| extract_mut(r).and_then(|m| f2(m))))
+            }
+            (FailableWritable(f1), WritableEnum { extract_mut, .. }) => {
+                FailableWritable(Arc::new(move |r| f1(r).and_then(|m| extract_mut(m))))
+            }
+            (Writable(f1), WritableEnum { extract_mut, .. }) => {
+                FailableWritable(Arc::new(move |r| extract_mut(f1(r))))
+            }
+
+            (
+                ReadableEnum { extract: ex1, embed: em1 } | WritableEnum { extract: ex1, embed: em1, .. },
+                ReadableEnum { extract: ex2, embed: em2 },
+            ) => ReadableEnum {
+                extract: Arc::new(move |r| ex1(r).and_then(|m| ex2(m))),
+                embed: Arc::new(move |v| em1(em2(v))),
+            },
+            (
+                WritableEnum { extract: ex1, extract_mut: exm1, embed: em1 },
+                WritableEnum { extract: ex2, extract_mut: exm2, embed: em2 },
+            ) => WritableEnum {
+                extract: Arc::new(move |r| ex1(r).and_then(|m| ex2(m))),
+                extract_mut: Arc::new(move |r| exm1(r).and_then(|m| exm2(m))),
+                embed: Arc::new(move |v| em1(em2(v))),
+            },
+
+            (Owned(f1), Owned(f2)) => Owned(Arc::new(move |r| f2(f1(r)))),
+            (FailableOwned(f1), Owned(f2)) => FailableOwned(Arc::new(move |r| f1(r).map(|m| f2(m)))),
+            (Owned(f1), FailableOwned(f2)) => FailableOwned(Arc::new(move |r| f2(f1(r)))),
+            (FailableOwned(f1), FailableOwned(f2)) => {
+                FailableOwned(Arc::new(move |r| f1(r).and_then(|m| f2(m))))
+            }
+
+            _ => return None,
+        })
+    }
+
+    /// Adapt this keypath to work with Arc<Root>; `None` for writable and owned keypaths.
+    #[inline]
+    pub fn for_arc(self) -> Option<SyncKeyPaths<Arc<Root>, Value>> {
+        use SyncKeyPaths::*;
+        Some(match self {
+            Readable(f) => Readable(Arc::new(move |root: &Arc<Root>| f(&**root))),
+            FailableReadable(f) => FailableReadable(Arc::new(move |root: &Arc<Root>| f(&**root))),
+            ReadableEnum { extract, embed } => ReadableEnum {
+                extract: Arc::new(move |root: &Arc<Root>| extract(&**root)),
+                embed: Arc::new(move |value| Arc::new(embed(value))),
+            },
+            _ => return None,
+        })
+    }
+
+    /// Adapt this keypath to work with Arc<RwLock<Root>>
+    /// Note: This creates a FailableOwned keypath since RwLock access can fail and we need to clone values.
+    /// `None` unless this keypath is readable.
+    #[inline]
+    pub fn for_arc_rwlock(self) -> Option<SyncKeyPaths<Arc<RwLock<Root>>, Value>>
+    where
+        Value: Clone,
+    {
+        use SyncKeyPaths::*;
+        let read = match self {
+            Readable(f) => always_some(f),
+            FailableReadable(f) => f,
+            ReadableEnum { extract, .. } => extract,
+            _ => return None,
+        };
+        Some(FailableOwned(Arc::new(move |root: Arc<RwLock<Root>>| {
+            let guard = root.read().ok()?;
+            read(&*guard).cloned()
+        })))
+    }
+
+    /// Adapt this keypath to work with Arc<Mutex<Root>>
+    /// Note: This creates a FailableOwned keypath since Mutex access can fail and we need to clone values.
+    /// `None` unless this keypath is readable.
+    #[inline]
+    pub fn for_arc_mutex(self) -> Option<SyncKeyPaths<Arc<Mutex<Root>>, Value>>
+    where
+        Value: Clone,
+    {
+        use SyncKeyPaths::*;
+        let read = match self {
+            Readable(f) => always_some(f),
+            FailableReadable(f) => f,
+            ReadableEnum { extract, .. } => extract,
+            _ => return None,
+        };
+        Some(FailableOwned(Arc::new(move |root: Arc<Mutex<Root>>| {
+            let guard = root.lock().ok()?;
+            read(&*guard).cloned()
+        })))
+    }
+}
+
+impl<Root> SyncPartialKeyPath<Root> {
+    #[inline]
+    pub fn get<'a>(&'a self, root: &'a Root) -> Option<&'a dyn Any> {
+        use SyncPartialKeyPath::*;
+        match self {
+            Readable(f) => Some(f(root)),
+            FailableReadable(f) => f(root),
+            ReadableEnum { extract, .. } | WritableEnum { extract, .. } => extract(root),
+            FailableCombined { readable, .. } => readable(root),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn get_mut<'a>(&'a self, root: &'a mut Root) -> Option<&'a mut dyn Any> {
+        use SyncPartialKeyPath::*;
+        match self {
+            Writable(f) | ReferenceWritable(f) => Some(f(root)),
+            FailableWritable(f) => f(root),
+            WritableEnum { extract_mut, .. } => extract_mut(root),
+            FailableCombined { writable, .. } => writable(root),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn kind_name(&self) -> &'static str {
+        use SyncPartialKeyPath::*;
+        match self {
+            Readable(_) => "Readable",
+            Writable(_) => "Writable",
+            FailableReadable(_) => "FailableReadable",
+            FailableWritable(_) => "FailableWritable",
+            ReadableEnum { .. } => "ReadableEnum",
+            WritableEnum { .. } => "WritableEnum",
+            ReferenceWritable(_) => "ReferenceWritable",
+            Owned(_) => "Owned",
+            FailableOwned(_) => "FailableOwned",
+            FailableCombined { .. } => "FailableCombined",
+        }
+    }
+
+    /// Convert this keypath to a [SyncAnyKeyPath] (fully type-erased)
+    pub fn to_any(self) -> SyncAnyKeyPath
+    where
+        Root: 'static,
+    {
+        use SyncPartialKeyPath::*;
+        fn root_ref<Root: 'static>(root: &dyn Any) -> &Root {
+            root.downcast_ref::<Root>().unwrap()
+        }
+        fn root_mut<Root: 'static>(root: &mut dyn Any) -> &mut Root {
+            root.downcast_mut::<Root>().unwrap()
+        }
+        match self {
+            Readable(f) => SyncAnyKeyPath::Readable(Arc::new(move |root| f(root_ref(root)))),
+            Writable(f) => SyncAnyKeyPath::Writable(Arc::new(move |root| f(root_mut(root)))),
+            FailableReadable(f) => SyncAnyKeyPath::FailableReadable(Arc::new(move |root| f(root_ref(root)))),
+            FailableWritable(f) => SyncAnyKeyPath::FailableWritable(Arc::new(move |root| f(root_mut(root)))),
+            ReadableEnum { extract, embed } => SyncAnyKeyPath::ReadableEnum {
+                extract: Arc::new(move |root| extract(root_ref(root))),
+                embed: Arc::new(move |value| Box::new(embed(value)) as Box<dyn Any>),
+            },
+            WritableEnum { extract, extract_mut, embed } => SyncAnyKeyPath::WritableEnum {
+                extract: Arc::new(move |root| extract(root_ref(root))),
+                extract_mut: Arc::new(move |root| extract_mut(root_mut(root))),
+                embed: Arc::new(move |value| Box::new(embed(value)) as Box<dyn Any>),
+            },
+            ReferenceWritable(f) => SyncAnyKeyPath::ReferenceWritable(Arc::new(move |root| f(root_mut(root)))),
+            Owned(f) => SyncAnyKeyPath::Owned(Arc::new(move |root| f(*root.downcast::<Root>().unwrap()))),
+            FailableOwned(f) => {
+                SyncAnyKeyPath::FailableOwned(Arc::new(move |root| f(*root.downcast::<Root>().unwrap())))
+            }
+            FailableCombined { readable, writable, owned } => SyncAnyKeyPath::FailableCombined {
+                readable: Arc::new(move |root| readable(root_ref(root))),
+                writable: Arc::new(move |root| writable(root_mut(root))),
+                owned: Arc::new(move |root| owned(*root.downcast::<Root>().unwrap())),
+            },
+        }
+    }
+}
+
+impl SyncAnyKeyPath {
+    #[inline]
+    pub fn get<'a>(&'a self, root: &'a dyn Any) -> Option<&'a dyn Any> {
+        use SyncAnyKeyPath::*;
+        match self {
+            Readable(f) => Some(f(root)),
+            FailableReadable(f) => f(root),
+            ReadableEnum { extract, .. } | WritableEnum { extract, .. } => extract(root),
+            FailableCombined { readable, .. } => readable(root),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn get_mut<'a>(&'a self, root: &'a mut dyn Any) -> Option<&'a mut dyn Any> {
+        use SyncAnyKeyPath::*;
+        match self {
+            Writable(f) | ReferenceWritable(f) => Some(f(root)),
+            FailableWritable(f) => f(root),
+            WritableEnum { extract_mut, .. } => extract_mut(root),
+            FailableCombined { writable, .. } => writable(root),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn kind_name(&self) -> &'static str {
+        use SyncAnyKeyPath::*;
+        match self {
+            Readable(_) => "Readable",
+            Writable(_) => "Writable",
+            FailableReadable(_) => "FailableReadable",
+            FailableWritable(_) => "FailableWritable",
+            ReadableEnum { .. } => "ReadableEnum",
+            WritableEnum { .. } => "WritableEnum",
+            ReferenceWritable(_) => "ReferenceWritable",
+            Owned(_) => "Owned",
+            FailableOwned(_) => "FailableOwned",
+            FailableCombined { .. } => "FailableCombined",
+        }
+    }
+}
+
+/// The getter of a `Readable` keypath, shaped like a `FailableReadable` one.
+fn always_some<Root: 'static, Value: 'static>(
+    f: SyncGet<Root, Value>,
+) -> SyncTryGet<Root, Value> {
+    Arc::new(move |root| Some(f(root)))
+}
+
+// ===== Into the Rc-based family =====
+// Each Arc closure is wrapped in an Rc closure that calls it; variants are kept as they are.
+
+impl<Root: 'static, Value: 'static> From<SyncKeyPaths<Root, Value>> for KeyPaths<Root, Value> {
+    fn from(kp: SyncKeyPaths<Root, Value>) -> Self {
+        use SyncKeyPaths as S;
+        match kp {
+            S::Readable(f) => KeyPaths::Readable(Rc::new(move |r| f(r))),
+            S::Writable(f) => KeyPaths::Writable(Rc::new(move |r| f(r))),
+            S::FailableReadable(f) => KeyPaths::FailableReadable(Rc::new(move |r| f(r))),
+            S::FailableWritable(f) => KeyPaths::FailableWritable(Rc::new(move |r| f(r))),
+            S::ReadableEnum { extract, embed } => KeyPaths::ReadableEnum {
+                extract: Rc::new(move |r| extract(r)),
+                embed: Rc::new(move |v| embed(v)),
+            },
+            S::WritableEnum { extract, extract_mut, embed } => KeyPaths::WritableEnum {
+                extract: Rc::new(move |r| extract(r)),
+                extract_mut: Rc::new(move |r| extract_mut(r)),
+                embed: Rc::new(move |v| embed(v)),
+            },
+            S::ReferenceWritable(f) => KeyPaths::ReferenceWritable(Rc::new(move |r| f(r))),
+            S::Owned(f) => KeyPaths::Owned(Rc::new(move |r| f(r))),
+            S::FailableOwned(f) => KeyPaths::FailableOwned(Rc::new(move |r| f(r))),
+            S::FailableCombined { readable, writable, owned } => KeyPaths::FailableCombined {
+                readable: Rc::new(move |r| readable(r)),
+                writable: Rc::new(move |r| writable(r)),
+                owned: Rc::new(move |r| owned(r)),
+            },
+        }
+    }
+}
+
+impl<Root: 'static> From<SyncPartialKeyPath<Root>> for PartialKeyPath<Root> {
+    fn from(kp: SyncPartialKeyPath<Root>) -> Self {
+        use SyncPartialKeyPath as S;
+        match kp {
+            S::Readable(f) => PartialKeyPath::Readable(Rc::new(move |r| f(r))),
+            S::Writable(f) => PartialKeyPath::Writable(Rc::new(move |r| f(r))),
+            S::FailableReadable(f) => PartialKeyPath::FailableReadable(Rc::new(move |r| f(r))),
+            S::FailableWritable(f) => PartialKeyPath::FailableWritable(Rc::new(move |r| f(r))),
+            S::ReadableEnum { extract, embed } => PartialKeyPath::ReadableEnum {
+                extract: Rc::new(move |r| extract(r)),
+                embed: Rc::new(move |v| embed(v)),
+            },
+            S::WritableEnum { extract, extract_mut, embed } => PartialKeyPath::WritableEnum {
+                extract: Rc::new(move |r| extract(r)),
+                extract_mut: Rc::new(move |r| extract_mut(r)),
+                embed: Rc::new(move |v| embed(v)),
+            },
+            S::ReferenceWritable(f) => PartialKeyPath::ReferenceWritable(Rc::new(move |r| f(r))),
+            S::Owned(f) => PartialKeyPath::Owned(Rc::new(move |r| f(r))),
+            S::FailableOwned(f) => PartialKeyPath::FailableOwned(Rc::new(move |r| f(r))),
+            S::FailableCombined { readable, writable, owned } => PartialKeyPath::FailableCombined {
+                readable: Rc::new(move |r| readable(r)),
+                writable: Rc::new(move |r| writable(r)),
+                owned: Rc::new(move |r| owned(r)),
+            },
+        }
+    }
+}
+
+impl From<SyncAnyKeyPath> for AnyKeyPath {
+    fn from(kp: SyncAnyKeyPath) -> Self {
+        use SyncAnyKeyPath as S;
+        match kp {
+            S::Readable(f) => AnyKeyPath::Readable(Rc::new(move |r| f(r))),
+            S::Writable(f) => AnyKeyPath::Writable(Rc::new(move |r| f(r))),
+            S::FailableReadable(f) => AnyKeyPath::FailableReadable(Rc::new(move |r| f(r))),
+            S::FailableWritable(f) => AnyKeyPath::FailableWritable(Rc::new(move |r| f(r))),
+            S::ReadableEnum { extract, embed } => AnyKeyPath::ReadableEnum {
+                extract: Rc::new(move |r| extract(r)),
+                embed: Rc::new(move |v| embed(v)),
+            },
+            S::WritableEnum { extract, extract_mut, embed } => AnyKeyPath::WritableEnum {
+                extract: Rc::new(move |r| extract(r)),
+                extract_mut: Rc::new(move |r| extract_mut(r)),
+                embed: Rc::new(move |v| embed(v)),
+            },
+            S::ReferenceWritable(f) => AnyKeyPath::ReferenceWritable(Rc::new(move |r| f(r))),
+            S::Owned(f) => AnyKeyPath::Owned(Rc::new(move |r| f(r))),
+            S::FailableOwned(f) => AnyKeyPath::FailableOwned(Rc::new(move |r| f(r))),
+            S::FailableCombined { readable, writable, owned } => AnyKeyPath::FailableCombined {
+                readable: Rc::new(move |r| readable(r)),
+                writable: Rc::new(move |r| writable(r)),
+                owned: Rc::new(move |r| owned(r)),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Config {
+        name: String,
+        port: Option<u16>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Source {
+        File(Config),
+        Env,
+    }
+
+    struct App {
+        config: Config,
+        source: Source,
+    }
+
+    fn app() -> App {
+        App {
+            config: Config { name: "api".into(), port: Some(80) },
+            source: Source::File(Config { name: "file".into(), port: None }),
+        }
+    }
+
+    fn config() -> SyncKeyPaths<App, Config> {
+        SyncKeyPaths::readable(|a: &App| &a.config)
+    }
+
+    fn config_mut() -> SyncKeyPaths<App, Config> {
+        SyncKeyPaths::writable(|a: &mut App| &mut a.config)
+    }
+
+    fn file() -> SyncKeyPaths<Source, Config> {
+        SyncKeyPaths::writable_enum(
+            Source::File,
+            |s: &Source| match s {
+                Source::File(c) => Some(c),
+                Source::Env => None,
+            },
+            |s: &mut Source| match s {
+                Source::File(c) => Some(c),
+                Source::Env => None,
+            },
+        )
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn keypaths_are_send_and_sync() {
+        assert_send_sync::<SyncKeyPaths<App, Config>>();
+        assert_send_sync::<SyncPartialKeyPath<App>>();
+        assert_send_sync::<SyncAnyKeyPath>();
+    }
+
+    #[test]
+    fn compose_and_then() {
+        let app = app();
+        let name = config().then(SyncKeyPaths::readable(|c: &Config| &c.name)).unwrap();
+        assert_eq!(name.kind_name(), "Readable");
+        assert_eq!(name.get(&app).map(String::as_str), Some("api"));
+
+        let port = config()
+            .compose(SyncKeyPaths::failable_readable(|c: &Config| c.port.as_ref()))
+            .unwrap();
+        assert_eq!(port.kind_name(), "FailableReadable");
+        assert_eq!(port.get(&app), Some(&80));
+
+        let source = SyncKeyPaths::writable(|a: &mut App| &mut a.source);
+        let file_name = source
+            .then(file())
+            .and_then(|kp| kp.then(SyncKeyPaths::writable(|c: &mut Config| &mut c.name)))
+            .unwrap();
+        let mut app = app;
+        file_name.get_mut(&mut app).unwrap().push_str(".toml");
+        assert_eq!(app.source, Source::File(Config { name: "file.toml".into(), port: None }));
+        app.source = Source::Env;
+        assert!(file_name.get_mut(&mut app).is_none());
+
+        let nested = file().then(file_name_enum()).unwrap();
+        assert_eq!(nested.kind_name(), "ReadableEnum");
+
+        // Unsupported combinations are reported instead of panicking
+        assert!(config().then(SyncKeyPaths::writable(|c: &mut Config| &mut c.name)).is_none());
+        assert!(config_mut().then(SyncKeyPaths::readable(|c: &Config| &c.name)).is_none());
+    }
+
+    fn file_name_enum() -> SyncKeyPaths<Config, String> {
+        SyncKeyPaths::readable_enum(
+            |name| Config { name, port: None },
+            |c: &Config| Some(&c.name),
+        )
+    }
+
+    #[test]
+    fn arc_adapters() {
+        let name = config().then(SyncKeyPaths::readable(|c: &Config| &c.name)).unwrap();
+
+        let shared = Arc::new(app());
+        assert_eq!(name.clone().for_arc().unwrap().get(&shared).map(String::as_str), Some("api"));
+
+        let locked = Arc::new(Mutex::new(app()));
+        let by_mutex = name.clone().for_arc_mutex().unwrap();
+        let worker = {
+            let locked = Arc::clone(&locked);
+            thread::spawn(move || by_mutex.get_failable_owned(locked))
+        };
+        assert_eq!(worker.join().unwrap().as_deref(), Some("api"));
+
+        let by_rwlock = name.for_arc_rwlock().unwrap();
+        assert_eq!(
+            by_rwlock.get_failable_owned(Arc::new(RwLock::new(app()))).as_deref(),
+            Some("api")
+        );
+
+        assert!(config_mut().for_arc().is_none());
+        assert!(config_mut().for_arc_mutex().is_none());
+        assert!(config_mut().for_arc_rwlock().is_none());
+    }
+
+    #[test]
+    fn from_keeps_variant_and_behaviour() {
+        let mut app = app();
+
+        let local: KeyPaths<App, Config> = config().into();
+        assert_eq!(local.kind_name(), "Readable");
+        assert_eq!(local.get(&app), Some(&app.config));
+
+        let local: KeyPaths<App, Config> = config_mut().into();
+        assert_eq!(local.kind_name(), "Writable");
+        local.get_mut(&mut app).unwrap().port = None;
+        assert_eq!(app.config.port, None);
+
+        let local: KeyPaths<Source, Config> = file().into();
+        assert_eq!(local.kind_name(), "WritableEnum");
+        assert_eq!(local.get(&Source::Env), None);
+        let embedded = local.embed_mut(app.config.clone());
+        assert_eq!(embedded, Some(Source::File(app.config.clone())));
+
+        let partial: PartialKeyPath<App> = config().to_partial().into();
+        assert_eq!(partial.kind_name(), "PartialKeyPath::Readable");
+        let value = partial.get(&app).and_then(|v| v.downcast_ref::<Config>());
+        assert_eq!(value, Some(&app.config));
+
+        let any: AnyKeyPath = config().to_any().into();
+        assert_eq!(any.kind_name(), "AnyKeyPath::Readable");
+        let value = any.get(&app as &dyn Any).and_then(|v| v.downcast_ref::<Config>());
+        assert_eq!(value, Some(&app.config));
+    }
+}
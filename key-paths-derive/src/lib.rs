@@ -93,15 +93,43 @@ enum WrapperKind {
     TokioArcRwLock,
     OptionTokioArcMutex,
     OptionTokioArcRwLock,
+    // Arc<tokio::sync::OnceCell<T>> (async read once initialised - requires tokio feature)
+    TokioArcOnceCell,
+    OptionTokioArcOnceCell,
+    // Runtime-agnostic async locks: futures::lock and async_lock (each behind the matching feature)
+    FuturesMutex,
+    AsyncLockMutex,
+    AsyncLockRwLock,
+    FuturesArcMutex,
+    AsyncLockArcMutex,
+    AsyncLockArcRwLock,
+    OptionFuturesArcMutex,
+    OptionAsyncLockArcMutex,
+    OptionAsyncLockArcRwLock,
     // Tagged types
     Tagged,
     OptionTagged,
     // Clone-on-write (std::borrow::Cow)
     Cow,
     OptionCow,
-    // Reference types (&T, &str, &[T], etc.)
+    // Reference types (&T, &str, etc.)
     Reference,
     OptionReference,
+    // Slice-like fields: [T; N], Box<[T]>, &[T] (index + range access)
+    Array,
+    BoxSlice,
+    SliceRef,
+    // Tuple fields (A, B, ...) - per-element access
+    Tuple,
+    // Third-party containers (each behind the matching key-paths-derive feature)
+    IndexMap,
+    SmallVec,
+    ArrayVec,
+    ImVector,
+    ImHashMap,
+    DashMap,
+    Bytes,
+    ArcSwap,
     // Atomic types (std::sync::atomic::*)
     Atomic,
     OptionAtomic,
@@ -134,6 +162,19 @@ fn is_tokio_sync_type(path: &syn::Path) -> bool {
         && segments.contains(&"sync".to_string())
 }
 
+/// Helper function to check if a type path is futures::lock (or futures_util::lock)
+fn is_futures_lock_type(path: &syn::Path) -> bool {
+    let segments: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    segments.contains(&"lock".to_string())
+        && (segments.contains(&"futures".to_string())
+            || segments.contains(&"futures_util".to_string()))
+}
+
+/// Helper function to check if a type path includes the async_lock crate
+fn is_async_lock_type(path: &syn::Path) -> bool {
+    path.segments.iter().any(|s| s.ident == "async_lock")
+}
+
 /// Helper function to check if a type path includes std::sync::atomic module
 fn is_std_sync_atomic_type(path: &syn::Path) -> bool {
     let segments: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
@@ -151,16 +192,38 @@ const ATOMIC_TYPE_IDENTS: &[&str] = &[
 fn extract_wrapper_inner_type(ty: &Type) -> (WrapperKind, Option<Type>) {
     use syn::{GenericArgument, PathArguments};
 
-    // Handle reference types: &T, &'a str, &[T], etc.
+    // Handle reference types: &T, &'a str, etc.; &[T] gets index/range access
     if let Type::Reference(tr) = ty {
+        if let Type::Slice(ts) = &*tr.elem {
+            return (WrapperKind::SliceRef, Some((*ts.elem).clone()));
+        }
         return (WrapperKind::Reference, Some((*tr.elem).clone()));
     }
 
+    // Fixed-size arrays [T; N]
+    if let Type::Array(ta) = ty {
+        return (WrapperKind::Array, Some((*ta.elem).clone()));
+    }
+
+    // Tuples (A, B, ...): element types are read from the field type itself
+    if let Type::Tuple(tt) = ty {
+        if !tt.elems.is_empty() {
+            return (WrapperKind::Tuple, None);
+        }
+    }
+
     if let Type::Path(tp) = ty {
+        if let Some(third_party) = extract_third_party_inner_type(&tp.path) {
+            return third_party;
+        }
+
         // Check if this is explicitly a std::sync type
         let is_std_sync = is_std_sync_type(&tp.path);
         // Check if this is explicitly a tokio::sync type
         let is_tokio_sync = is_tokio_sync_type(&tp.path);
+        // futures::lock / async_lock types are only recognised with the matching feature
+        let is_futures_lock = cfg!(feature = "futures") && is_futures_lock_type(&tp.path);
+        let is_async_lock = cfg!(feature = "async_lock") && is_async_lock_type(&tp.path);
 
         if let Some(seg) = tp.path.segments.last() {
             let ident_str = seg.ident.to_string();
@@ -207,6 +270,13 @@ fn extract_wrapper_inner_type(ty: &Type) -> (WrapperKind, Option<Type>) {
                 // Handle single-parameter container types
                 else if let Some(arg) = args.get(0) {
                     if let GenericArgument::Type(inner) = arg {
+                        // Box<[T]>: the slice itself is unsized, index/range into it instead
+                        if ident_str == "Box" {
+                            if let Type::Slice(ts) = inner {
+                                return (WrapperKind::BoxSlice, Some((*ts.elem).clone()));
+                            }
+                        }
+
                         // Check for nested containers first
                         let (inner_kind, inner_inner) = extract_wrapper_inner_type(inner);
 
@@ -278,6 +348,18 @@ fn extract_wrapper_inner_type(ty: &Type) -> (WrapperKind, Option<Type>) {
                             ("Option", WrapperKind::TokioArcRwLock) => {
                                 return (WrapperKind::OptionTokioArcRwLock, inner_inner);
                             }
+                            ("Option", WrapperKind::TokioArcOnceCell) => {
+                                return (WrapperKind::OptionTokioArcOnceCell, inner_inner);
+                            }
+                            ("Option", WrapperKind::FuturesArcMutex) => {
+                                return (WrapperKind::OptionFuturesArcMutex, inner_inner);
+                            }
+                            ("Option", WrapperKind::AsyncLockArcMutex) => {
+                                return (WrapperKind::OptionAsyncLockArcMutex, inner_inner);
+                            }
+                            ("Option", WrapperKind::AsyncLockArcRwLock) => {
+                                return (WrapperKind::OptionAsyncLockArcRwLock, inner_inner);
+                            }
                             ("Option", WrapperKind::Cow) => {
                                 return (WrapperKind::OptionCow, inner_inner);
                             }
@@ -369,6 +451,21 @@ fn extract_wrapper_inner_type(ty: &Type) -> (WrapperKind, Option<Type>) {
                             ("Arc", WrapperKind::TokioRwLock) => {
                                 return (WrapperKind::TokioArcRwLock, inner_inner);
                             }
+                            ("Arc", WrapperKind::OnceCell)
+                                if matches!(inner, Type::Path(p) if is_tokio_sync_type(&p.path)) =>
+                            {
+                                return (WrapperKind::TokioArcOnceCell, inner_inner);
+                            }
+                            // futures::lock / async_lock variants
+                            ("Arc", WrapperKind::FuturesMutex) => {
+                                return (WrapperKind::FuturesArcMutex, inner_inner);
+                            }
+                            ("Arc", WrapperKind::AsyncLockMutex) => {
+                                return (WrapperKind::AsyncLockArcMutex, inner_inner);
+                            }
+                            ("Arc", WrapperKind::AsyncLockRwLock) => {
+                                return (WrapperKind::AsyncLockArcRwLock, inner_inner);
+                            }
                             _ => {
                                 // Handle single-level containers
                                 // For Mutex and RwLock:
@@ -400,7 +497,16 @@ fn extract_wrapper_inner_type(ty: &Type) -> (WrapperKind, Option<Type>) {
                                     "RwLock" if is_tokio_sync => {
                                         (WrapperKind::TokioRwLock, Some(inner.clone()))
                                     }
-                                    // Default: parking_lot (no std::sync or tokio::sync prefix)
+                                    "Mutex" if is_futures_lock => {
+                                        (WrapperKind::FuturesMutex, Some(inner.clone()))
+                                    }
+                                    "Mutex" if is_async_lock => {
+                                        (WrapperKind::AsyncLockMutex, Some(inner.clone()))
+                                    }
+                                    "RwLock" if is_async_lock => {
+                                        (WrapperKind::AsyncLockRwLock, Some(inner.clone()))
+                                    }
+                                    // Default: parking_lot (no std::sync, tokio::sync, futures or async_lock prefix)
                                     "Mutex" => (WrapperKind::Mutex, Some(inner.clone())),
                                     "RwLock" => (WrapperKind::RwLock, Some(inner.clone())),
                                     "Weak" => (WrapperKind::Weak, Some(inner.clone())),
@@ -437,6 +543,832 @@ fn extract_wrapper_inner_type(ty: &Type) -> (WrapperKind, Option<Type>) {
     (WrapperKind::None, None)
 }
 
+/// Third-party containers. Each is only recognised when the matching key-paths-derive feature is
+/// enabled and the type is written with its crate path (`indexmap::IndexMap<K, V>`,
+/// `im::Vector<T>`, ...). Features unify across a build, so a bare `Vector` or `DashMap` may well
+/// be a user type of the same name.
+fn extract_third_party_inner_type(path: &syn::Path) -> Option<(WrapperKind, Option<Type>)> {
+    use syn::{GenericArgument, PathArguments};
+
+    let seg = path.segments.last()?;
+    let type_args: Vec<Type> = match &seg.arguments {
+        PathArguments::AngleBracketed(ab) => ab
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(t) => Some(t.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let from_crate = |krate: &str| path.segments.len() > 1 && path.segments.iter().any(|s| s.ident == krate);
+    let is_im = from_crate("im") || from_crate("im_rc");
+
+    match seg.ident.to_string().as_str() {
+        "IndexMap" if cfg!(feature = "indexmap") && from_crate("indexmap") => {
+            Some((WrapperKind::IndexMap, type_args.get(1).cloned()))
+        }
+        // SmallVec<[T; N]>: element type comes from the backing array
+        "SmallVec" if cfg!(feature = "smallvec") && from_crate("smallvec") => match type_args.first() {
+            Some(Type::Array(ta)) => Some((WrapperKind::SmallVec, Some((*ta.elem).clone()))),
+            _ => None,
+        },
+        "ArrayVec" if cfg!(feature = "arrayvec") && from_crate("arrayvec") => {
+            Some((WrapperKind::ArrayVec, type_args.first().cloned()))
+        }
+        "Vector" if cfg!(feature = "im") && is_im => Some((WrapperKind::ImVector, type_args.first().cloned())),
+        "HashMap" if cfg!(feature = "im") && is_im => {
+            Some((WrapperKind::ImHashMap, type_args.get(1).cloned()))
+        }
+        "DashMap" if cfg!(feature = "dashmap") && from_crate("dashmap") => {
+            Some((WrapperKind::DashMap, type_args.get(1).cloned()))
+        }
+        "Bytes" if cfg!(feature = "bytes") && from_crate("bytes") && type_args.is_empty() => Some((WrapperKind::Bytes, None)),
+        "ArcSwap" if cfg!(feature = "arc_swap") && from_crate("arc_swap") => {
+            Some((WrapperKind::ArcSwap, type_args.first().cloned()))
+        }
+        _ => None,
+    }
+}
+
+/// Options from `#[kp(...)]` on a field. See `rust_key_paths::container` for the runtime side.
+#[derive(Default)]
+struct KpFieldAttrs {
+    /// `container`: the field type implements `rust_key_paths::KpContainer`
+    container: bool,
+    /// `via = "path"`: read accessor, `fn(&C) -> &T` (or `fn(&C, key)` with `at`)
+    via: Option<syn::Path>,
+    /// `mut_via = "path"`: write accessor matching `via`
+    mut_via: Option<syn::Path>,
+    /// `at`: the via accessors take a key
+    at: bool,
+    /// `key = "Type"`: key type for `at` (default `usize`)
+    key: Option<Type>,
+    /// `item = "Type"`: element type (default: first generic argument of the field type)
+    item: Option<Type>,
+    /// `lock = "Access"`: a `LockAccess` impl for the field's lock type
+    lock: Option<Type>,
+    /// `transparent`: the field type implements `rust_key_paths::Transparent`
+    transparent: bool,
+}
+
+/// Field kinds for which the derive already generates `{field}_at`.
+fn has_builtin_at(kind: WrapperKind) -> bool {
+    matches!(
+        kind,
+        WrapperKind::Vec
+            | WrapperKind::VecDeque
+            | WrapperKind::VecDequeOption
+            | WrapperKind::HashMap
+            | WrapperKind::BTreeMap
+            | WrapperKind::BTreeMapOption
+            | WrapperKind::HashSet
+            | WrapperKind::HashSetOption
+            | WrapperKind::BTreeSet
+            | WrapperKind::BTreeSetOption
+            | WrapperKind::Array
+            | WrapperKind::BoxSlice
+            | WrapperKind::SliceRef
+            | WrapperKind::IndexMap
+            | WrapperKind::SmallVec
+            | WrapperKind::ArrayVec
+            | WrapperKind::ImVector
+            | WrapperKind::ImHashMap
+            | WrapperKind::DashMap
+            | WrapperKind::Bytes
+    )
+}
+
+/// `#[kp(...)]` is only read on the fields of non-generic structs; anywhere else it is an error
+/// rather than silently ignored.
+fn reject_unsupported_kp_attrs(input: &DeriveInput) -> syn::Result<()> {
+    let is_kp = |a: &&syn::Attribute| a.path().is_ident("kp");
+    match &input.data {
+        Data::Enum(data_enum) => {
+            for variant in &data_enum.variants {
+                let mut attrs = variant.attrs.iter().chain(variant.fields.iter().flat_map(|f| &f.attrs));
+                if let Some(attr) = attrs.find(is_kp) {
+                    return Err(syn::Error::new(attr.span(), "#[kp(...)] is not supported on enum variants"));
+                }
+            }
+        }
+        Data::Struct(data_struct) if !input.generics.params.is_empty() => {
+            if let Some(attr) = data_struct.fields.iter().flat_map(|f| &f.attrs).find(is_kp) {
+                return Err(syn::Error::new(attr.span(), "#[kp(...)] field attributes are not supported on generic structs"));
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn parse_kp_field_attrs(field: &syn::Field) -> syn::Result<KpFieldAttrs> {
+    let mut attrs = KpFieldAttrs::default();
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("kp")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("container") {
+                attrs.container = true;
+            } else if meta.path.is_ident("transparent") {
+                attrs.transparent = true;
+            } else if meta.path.is_ident("at") {
+                attrs.at = true;
+            } else if meta.path.is_ident("via") {
+                attrs.via = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+            } else if meta.path.is_ident("mut_via") {
+                attrs.mut_via = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+            } else if meta.path.is_ident("key") {
+                attrs.key = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+            } else if meta.path.is_ident("item") {
+                attrs.item = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+            } else if meta.path.is_ident("lock") {
+                attrs.lock = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+            } else {
+                return Err(meta.error("unsupported kp attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    if attrs.mut_via.is_some() && attrs.via.is_none() {
+        return Err(syn::Error::new(field.span(), "`mut_via` requires `via`"));
+    }
+    Ok(attrs)
+}
+
+/// First generic type argument of a path type (`MyVec<T>` -> `T`).
+fn first_type_arg(ty: &Type) -> Option<Type> {
+    use syn::{GenericArgument, PathArguments};
+
+    if let Type::Path(tp) = ty {
+        if let Some(seg) = tp.path.segments.last() {
+            if let PathArguments::AngleBracketed(ab) = &seg.arguments {
+                return ab.args.iter().find_map(|arg| match arg {
+                    GenericArgument::Type(t) => Some(t.clone()),
+                    _ => None,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// `AsyncLockLike` access type for the Arc-wrapped async lock kinds that share one `_async()`
+/// shape, whether the `Arc` sits behind an `Option`, and whether the access hands out guards.
+fn async_lock_access(kind: WrapperKind, inner_ty: &Type) -> (proc_macro2::TokenStream, bool, bool) {
+    let access = match kind {
+        WrapperKind::TokioArcOnceCell | WrapperKind::OptionTokioArcOnceCell => {
+            quote!(rust_key_paths::async_lock::TokioOnceCellAccess<#inner_ty>)
+        }
+        WrapperKind::FuturesArcMutex | WrapperKind::OptionFuturesArcMutex => {
+            quote!(rust_key_paths::async_lock::FuturesMutexAccess<#inner_ty>)
+        }
+        WrapperKind::AsyncLockArcMutex | WrapperKind::OptionAsyncLockArcMutex => {
+            quote!(rust_key_paths::async_lock::AsyncLockMutexAccess<#inner_ty>)
+        }
+        WrapperKind::AsyncLockArcRwLock | WrapperKind::OptionAsyncLockArcRwLock => {
+            quote!(rust_key_paths::async_lock::AsyncLockRwLockAccess<#inner_ty>)
+        }
+        _ => unreachable!("not an async lock kind: {:?}", kind),
+    };
+    let is_option = matches!(
+        kind,
+        WrapperKind::OptionTokioArcOnceCell
+            | WrapperKind::OptionFuturesArcMutex
+            | WrapperKind::OptionAsyncLockArcMutex
+            | WrapperKind::OptionAsyncLockArcRwLock
+    );
+    let guarded = !matches!(
+        kind,
+        WrapperKind::TokioArcOnceCell | WrapperKind::OptionTokioArcOnceCell
+    );
+    (access, is_option, guarded)
+}
+
+/// Return type and `next` keypath of the `_async()` accessor built from [async_lock_access]:
+/// guard-returning accesses end in the guard itself, the rest in a reference to the inner value.
+fn async_lock_kp(
+    name: &syn::Ident,
+    lock_ty: &Type,
+    inner_ty: &Type,
+    access: &proc_macro2::TokenStream,
+    guarded: bool,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if guarded {
+        (
+            quote!(rust_key_paths::async_lock::AsyncLockKpGuardFor<#name, #lock_ty, #inner_ty, #access>),
+            quote! {
+                rust_key_paths::Kp::new(Some, Some)
+            },
+        )
+    } else {
+        (
+            quote!(rust_key_paths::async_lock::AsyncLockKpFor<#name, #lock_ty, #inner_ty, #access>),
+            quote! {
+                rust_key_paths::Kp::new(
+                    |v: &#inner_ty| Some(v),
+                    |v: &mut #inner_ty| Some(v),
+                )
+            },
+        )
+    }
+}
+
+/// `pub const {BASE}_KP: KpStatic<Name, V>` for the `{base}()` accessor, whose `KpType` has value
+/// type `V`.
+fn static_kp_const(
+    name: &syn::Ident,
+    kp_fn: &syn::Ident,
+    value_ty: proc_macro2::TokenStream,
+    segment: &str,
+) -> proc_macro2::TokenStream {
+    let const_name = format_ident!("{}_KP", kp_fn.to_string().trim_start_matches("r#").to_uppercase());
+    quote! {
+        /// `const` form of the accessor of the same name; see `rust_key_paths::KpStatic`.
+        pub const #const_name: rust_key_paths::KpStatic<#name, #value_ty> = rust_key_paths::KpStatic::named(
+            #segment,
+            |root: &#name| #name::#kp_fn().get(root),
+            |root: &mut #name| #name::#kp_fn().get_mut(root),
+        );
+    }
+}
+
+/// Does `tokens` mention the identifier `ident` anywhere (`T`, `Vec<T>`, `[T; 2]`, `T: Clone`)?
+fn tokens_mention(tokens: proc_macro2::TokenStream, ident: &syn::Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Ident(i) => i == *ident,
+        proc_macro2::TokenTree::Group(g) => tokens_mention(g.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Kp derive for structs with generic parameters: `{field}()` for every field and
+/// `{field}_poly::<B>()` for fields typed by a type parameter no other field uses.
+fn generate_generic_struct_kps(input: &DeriveInput, fields: &Fields) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let members: Vec<(syn::Member, String, &syn::Field)> = match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|f| {
+                let ident = f.ident.clone().unwrap();
+                (syn::Member::Named(ident.clone()), ident.to_string(), f)
+            })
+            .collect(),
+        Fields::Unnamed(unnamed) => unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (syn::Member::Unnamed(syn::Index::from(i)), format!("f{}", i), f))
+            .collect(),
+        Fields::Unit => {
+            return Err(syn::Error::new(input.span(), "Kp derive does not support unit structs"));
+        }
+    };
+    let bindings: Vec<syn::Ident> = (0..members.len()).map(|i| format_ident!("__kp_{}", i)).collect();
+    let pattern: Vec<proc_macro2::TokenStream> = members
+        .iter()
+        .zip(&bindings)
+        .map(|((member, _, _), binding)| quote! { #member: #binding })
+        .collect();
+
+    let mut tokens = proc_macro2::TokenStream::new();
+    for (idx, (member, base, field)) in members.iter().enumerate() {
+        let ty = &field.ty;
+        let kp_fn = format_ident!("{}", base);
+        tokens.extend(quote! {
+            #[inline(always)]
+            pub fn #kp_fn() -> rust_key_paths::KpType<'static, Self, #ty>
+            where
+                Self: 'static,
+            {
+                rust_key_paths::Kp::new(|r: &Self| Some(&r.#member), |r: &mut Self| Some(&mut r.#member))
+            }
+        });
+
+        // &'a [T]: element and window keypaths over the field's own lifetime
+        if let (Type::Reference(tr), (WrapperKind::SliceRef, Some(elem_ty))) = (ty, extract_wrapper_inner_type(ty)) {
+            let lifetime = tr.lifetime.clone().unwrap_or_else(|| syn::Lifetime::new("'static", tr.span()));
+            let kp_at_fn = format_ident!("{}_at", base);
+            let kp_range_fn = format_ident!("{}_range", base);
+            tokens.extend(quote! {
+                #[inline(always)]
+                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpBox<#lifetime, Self, #elem_ty>
+                where
+                    Self: #lifetime,
+                {
+                    rust_key_paths::Kp::new(
+                        rust_key_paths::__private::Box::new(move |r: &#lifetime Self| r.#member.get(index)),
+                        rust_key_paths::__private::Box::new(move |_r: &#lifetime mut Self| None),
+                    )
+                }
+                #[inline(always)]
+                pub fn #kp_range_fn(range: core::ops::Range<usize>) -> rust_key_paths::SliceKp<Self, #elem_ty> {
+                    rust_key_paths::SliceKp::new(|r: &Self| Some(r.#member), |_r: &mut Self| None, range)
+                }
+            });
+        }
+
+        // The field's type must be exactly one type parameter, used by no other field.
+        let Some(param) = input.generics.type_params().find(|p| {
+            matches!(ty, Type::Path(tp) if tp.qself.is_none() && tp.path.is_ident(&p.ident))
+        }) else {
+            continue;
+        };
+        let shared = members
+            .iter()
+            .enumerate()
+            .any(|(other, (_, _, f))| {
+                let other_ty = &f.ty;
+                other != idx && tokens_mention(quote!(#other_ty), &param.ident)
+            });
+        let in_where = where_clause.is_some_and(|w| tokens_mention(quote!(#w), &param.ident));
+        if shared || in_where {
+            continue;
+        }
+
+        let param_ident = &param.ident;
+        let bounds = &param.bounds;
+        let target_args = input.generics.params.iter().map(|p| match p {
+            syn::GenericParam::Type(t) if t.ident == *param_ident => quote!(__KpB),
+            syn::GenericParam::Type(t) => {
+                let ident = &t.ident;
+                quote!(#ident)
+            }
+            syn::GenericParam::Lifetime(l) => {
+                let lifetime = &l.lifetime;
+                quote!(#lifetime)
+            }
+            syn::GenericParam::Const(c) => {
+                let ident = &c.ident;
+                quote!(#ident)
+            }
+        });
+        let target = quote!(#name<#(#target_args),*>);
+        let poly_fn = format_ident!("{}_poly", base);
+        let binding = &bindings[idx];
+        let rebuilt = members.iter().zip(&bindings).map(|((m, _, _), b)| {
+            if b == binding {
+                quote! { #m: __kp_f(#b) }
+            } else {
+                quote! { #m: #b }
+            }
+        });
+        let bound_clause = if bounds.is_empty() {
+            quote!()
+        } else {
+            quote!(__KpB: #bounds,)
+        };
+        tokens.extend(quote! {
+            /// Type-changing keypath: replace this field with a value of another type.
+            #[inline(always)]
+            pub fn #poly_fn<__KpB>() -> rust_key_paths::PolyKp<Self, #target, #param_ident, __KpB>
+            where
+                #bound_clause
+            {
+                rust_key_paths::PolyKp::new(
+                    |r: &Self| &r.#member,
+                    |r: Self, __kp_f: &mut dyn FnMut(#param_ident) -> __KpB| {
+                        let #name { #(#pattern),* } = r;
+                        #name { #(#rebuilt),* }
+                    },
+                )
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #tokens
+        }
+    })
+}
+
+/// `{base}_each()`: a `Traversal` over every element of a std collection field (Vec, VecDeque,
+/// LinkedList, HashSet, BTreeSet, and HashMap / BTreeMap values). Other fields get nothing.
+fn generate_each_accessor(
+    name: &syn::Ident,
+    field: &syn::Field,
+    access: proc_macro2::TokenStream,
+    base: &str,
+) -> proc_macro2::TokenStream {
+    let (kind, inner_ty) = extract_wrapper_inner_type(&field.ty);
+    let inner_ty = match (kind, inner_ty) {
+        (
+            WrapperKind::Vec
+            | WrapperKind::VecDeque
+            | WrapperKind::LinkedList
+            | WrapperKind::HashSet
+            | WrapperKind::BTreeSet
+            | WrapperKind::HashMap
+            | WrapperKind::BTreeMap,
+            Some(inner_ty),
+        ) => inner_ty,
+        _ => return proc_macro2::TokenStream::new(),
+    };
+    let each_fn = format_ident!("{}_each", base);
+    quote! {
+        /// Traversal over every element of the collection (`field[*]`).
+        #[inline(always)]
+        pub fn #each_fn() -> rust_key_paths::Traversal<'static, #name, #inner_ty> {
+            rust_key_paths::Traversal::new(
+                |root: &#name, f| rust_key_paths::Traversable::each(&root.#access, f),
+                |root: &mut #name, f| rust_key_paths::Traversable::each_mut(&mut root.#access, f),
+            )
+        }
+    }
+}
+
+/// Auto-vivifying accessors (see `rust_key_paths::vivify`): `{base}_or_default()` for `Option<T>`
+/// and `Option<Box<T>>` fields, `{base}_entry(key)` for `HashMap`, `BTreeMap`, `Vec` and
+/// `VecDeque` fields. Other fields get nothing.
+fn generate_fill_accessors(
+    name: &syn::Ident,
+    field: &syn::Field,
+    access: proc_macro2::TokenStream,
+    base: &str,
+) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    let field_kp = quote! {
+        let kp: rust_key_paths::KpType<'static, #name, #ty> = rust_key_paths::Kp::new(
+            |root: &#name| Some(&root.#access),
+            |root: &mut #name| Some(&mut root.#access),
+        );
+    };
+    let (kind, inner_ty) = extract_wrapper_inner_type(ty);
+    match (kind, inner_ty) {
+        (WrapperKind::Option, Some(_)) => {
+            let or_default_fn = format_ident!("{}_or_default", base);
+            quote! {
+                /// Like the plain accessor, but `get_mut_or_default` stores `Default::default()` into an empty option.
+                #[inline(always)]
+                pub fn #or_default_fn() -> rust_key_paths::OrDefault<rust_key_paths::KpType<'static, #name, #ty>> {
+                    #field_kp
+                    rust_key_paths::OrDefault::new(kp)
+                }
+            }
+        }
+        (WrapperKind::OptionBox, Some(inner_ty)) => {
+            let or_default_fn = format_ident!("{}_or_default", base);
+            quote! {
+                /// Through the box to the value; `get_mut_or_default` boxes `Default::default()` into an empty option.
+                #[inline(always)]
+                pub fn #or_default_fn() -> rust_key_paths::Then<
+                    rust_key_paths::OrDefault<rust_key_paths::KpType<'static, #name, #ty>>,
+                    rust_key_paths::KpType<'static, rust_key_paths::__private::Box<#inner_ty>, #inner_ty>,
+                > {
+                    #field_kp
+                    rust_key_paths::Lens::compose(rust_key_paths::OrDefault::new(kp), rust_key_paths::kp_box())
+                }
+            }
+        }
+        (WrapperKind::HashMap | WrapperKind::BTreeMap, Some(_)) => {
+            let Some((key_ty, _)) = extract_map_key_value(ty) else {
+                return proc_macro2::TokenStream::new();
+            };
+            let entry_fn = format_ident!("{}_entry", base);
+            quote! {
+                /// The value at `key`; `get_mut_or_default` inserts `Default::default()` when it is missing.
+                #[inline(always)]
+                pub fn #entry_fn(key: #key_ty) -> rust_key_paths::Entry<rust_key_paths::KpType<'static, #name, #ty>, #key_ty> {
+                    #field_kp
+                    rust_key_paths::Entry::new(kp, key)
+                }
+            }
+        }
+        (WrapperKind::Vec | WrapperKind::VecDeque, Some(_)) => {
+            let entry_fn = format_ident!("{}_entry", base);
+            quote! {
+                /// The element at `index`; `get_mut_or_default` grows the collection with defaults to reach it.
+                #[inline(always)]
+                pub fn #entry_fn(index: usize) -> rust_key_paths::Entry<rust_key_paths::KpType<'static, #name, #ty>, usize> {
+                    #field_kp
+                    rust_key_paths::Entry::new(kp, index)
+                }
+            }
+        }
+        _ => proc_macro2::TokenStream::new(),
+    }
+}
+
+/// `{base}_offset()`: the field as an `OffsetKp`. Not for `#[repr(packed)]` structs, whose fields
+/// may be unaligned.
+fn generate_offset_accessor(
+    name: &syn::Ident,
+    field: &syn::Field,
+    access: proc_macro2::TokenStream,
+    base: &str,
+) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    let offset_fn = format_ident!("{}_offset", base);
+    quote! {
+        /// The field as a byte offset into the struct; composes with `then` in `const` context.
+        #[inline(always)]
+        pub const fn #offset_fn() -> rust_key_paths::OffsetKp<#name, #ty> {
+            rust_key_paths::offset_kp!(#name, #access)
+        }
+    }
+}
+
+fn is_repr_packed(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("repr")).any(|attr| {
+        let mut packed = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            packed |= meta.path.is_ident("packed");
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+        packed
+    })
+}
+
+/// `{base}_cow()` for `Arc<T>` / `Rc<T>` fields: a `MakeMut` step for copy-on-write updates.
+fn generate_cow_accessors(
+    name: &syn::Ident,
+    field: &syn::Field,
+    access: proc_macro2::TokenStream,
+    base: &str,
+) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    match extract_wrapper_inner_type(ty) {
+        (WrapperKind::Arc | WrapperKind::Rc, Some(_)) => {
+            let cow_fn = format_ident!("{}_cow", base);
+            quote! {
+                /// Through the pointer to the value; `update_cow` clones it only while it is shared.
+                #[inline(always)]
+                pub fn #cow_fn() -> rust_key_paths::MakeMut<rust_key_paths::KpType<'static, #name, #ty>> {
+                    let kp: rust_key_paths::KpType<'static, #name, #ty> = rust_key_paths::Kp::new(
+                        |root: &#name| Some(&root.#access),
+                        |root: &mut #name| Some(&mut root.#access),
+                    );
+                    rust_key_paths::MakeMut::new(kp)
+                }
+            }
+        }
+        _ => proc_macro2::TokenStream::new(),
+    }
+}
+
+/// Extra accessors for a field opted in with `#[kp(...)]`: `_at(key)` via `KpContainer` or the
+/// `via` functions, `_inner()` for keyless `via` and `transparent` newtypes, and `_lock()` through
+/// a user `LockAccess`.
+/// `access` is the field accessor (`name` or tuple index); `base` the generated method prefix.
+fn generate_kp_attr_accessors(
+    name: &syn::Ident,
+    field: &syn::Field,
+    access: proc_macro2::TokenStream,
+    base: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attrs = parse_kp_field_attrs(field)?;
+    let ty = &field.ty;
+    let kp_at_fn = format_ident!("{}_at", base);
+    let mut tokens = proc_macro2::TokenStream::new();
+
+    let attr_at = attrs.container || (attrs.via.is_some() && attrs.at);
+    if attr_at && has_builtin_at(extract_wrapper_inner_type(ty).0) {
+        return Err(syn::Error::new(
+            field.span(),
+            format!("`{}_at` is already generated for this field type; remove `container` / `via ... at`", base),
+        ));
+    }
+    if attrs.container && attrs.via.is_some() && attrs.at {
+        return Err(syn::Error::new(field.span(), "`container` and `via ... at` both generate `_at`; use one"));
+    }
+
+    let item_ty = || {
+        attrs.item.clone().or_else(|| first_type_arg(ty)).ok_or_else(|| {
+            syn::Error::new(field.span(), "cannot infer the element type; add `item = \"Type\"`")
+        })
+    };
+
+    if attrs.container {
+        tokens.extend(quote! {
+            #[inline(always)]
+            pub fn #kp_at_fn(
+                key: <#ty as rust_key_paths::KpContainer>::Key,
+            ) -> rust_key_paths::KpDynamic<#name, <#ty as rust_key_paths::KpContainer>::Item>
+            where
+                <#ty as rust_key_paths::KpContainer>::Key: Clone,
+            {
+                let key2 = key.clone();
+                rust_key_paths::Kp::new(
+                    rust_key_paths::__private::Box::new(move |root: &#name| rust_key_paths::KpContainer::kp_get(&root.#access, &key)),
+                    rust_key_paths::__private::Box::new(move |root: &mut #name| rust_key_paths::KpContainer::kp_get_mut(&mut root.#access, &key2)),
+                )
+            }
+        });
+    }
+
+    if let Some(via) = &attrs.via {
+        let item_ty = item_ty()?;
+        if attrs.at {
+            let key_ty = attrs.key.clone().unwrap_or_else(|| syn::parse_quote!(usize));
+            let set = match &attrs.mut_via {
+                Some(mut_via) => quote! {
+                    rust_key_paths::__private::Box::new(move |root: &mut #name| {
+                        <_ as rust_key_paths::IntoKpOption<&mut #item_ty>>::into_kp_option(#mut_via(&mut root.#access, key2.clone()))
+                    })
+                },
+                None => quote! { rust_key_paths::__private::Box::new(move |_root: &mut #name| { let _ = &key2; None }) },
+            };
+            tokens.extend(quote! {
+                #[inline(always)]
+                pub fn #kp_at_fn(key: #key_ty) -> rust_key_paths::KpDynamic<#name, #item_ty>
+                where
+                    #key_ty: Clone,
+                {
+                    let key2 = key.clone();
+                    rust_key_paths::Kp::new(
+                        rust_key_paths::__private::Box::new(move |root: &#name| {
+                            <_ as rust_key_paths::IntoKpOption<&#item_ty>>::into_kp_option(#via(&root.#access, key.clone()))
+                        }),
+                        #set,
+                    )
+                }
+            });
+        } else {
+            let kp_inner_fn = format_ident!("{}_inner", base);
+            let set = match &attrs.mut_via {
+                Some(mut_via) => quote! {
+                    |root: &mut #name| {
+                        <_ as rust_key_paths::IntoKpOption<&mut #item_ty>>::into_kp_option(#mut_via(&mut root.#access))
+                    }
+                },
+                None => quote! { |_root: &mut #name| None },
+            };
+            tokens.extend(quote! {
+                #[inline(always)]
+                pub fn #kp_inner_fn() -> rust_key_paths::KpType<'static, #name, #item_ty> {
+                    rust_key_paths::Kp::new(
+                        |root: &#name| {
+                            <_ as rust_key_paths::IntoKpOption<&#item_ty>>::into_kp_option(#via(&root.#access))
+                        },
+                        #set,
+                    )
+                }
+            });
+        }
+    }
+
+    if attrs.transparent {
+        let kp_inner_fn = format_ident!("{}_inner", base);
+        tokens.extend(quote! {
+            #[inline(always)]
+            pub fn #kp_inner_fn() -> rust_key_paths::KpType<'static, #name, <#ty as rust_key_paths::Transparent>::Inner> {
+                rust_key_paths::Kp::new(
+                    |root: &#name| Some(rust_key_paths::Transparent::kp_inner(&root.#access)),
+                    |root: &mut #name| Some(rust_key_paths::Transparent::kp_inner_mut(&mut root.#access)),
+                )
+            }
+        });
+    }
+
+    if let Some(lock) = &attrs.lock {
+        let item_ty = item_ty()?;
+        let kp_lock_fn = format_ident!("{}_lock", base);
+        tokens.extend(quote! {
+            pub fn #kp_lock_fn() -> rust_key_paths::lock::LockKpFor<#name, #ty, #item_ty, #lock> {
+                rust_key_paths::lock::LockKp::new(
+                    rust_key_paths::Kp::new(
+                        |root: &#name| Some(&root.#access),
+                        |root: &mut #name| Some(&mut root.#access),
+                    ),
+                    <#lock as Default>::default(),
+                    rust_key_paths::Kp::new(
+                        |v: &#item_ty| Some(v),
+                        |v: &mut #item_ty| Some(v),
+                    ),
+                )
+            }
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// `#[kp(transparent)]` on a single-field struct: `Transparent` and `From` impls in both
+/// directions, plus `iso()` returning an `IsoKp` between the newtype and its inner value.
+fn generate_transparent_impls(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut transparent = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("kp")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("transparent") {
+                transparent = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported kp attribute"))
+            }
+        })?;
+    }
+    if !transparent {
+        return Ok(proc_macro2::TokenStream::new());
+    }
+
+    let name = &input.ident;
+    let field = match &input.data {
+        Data::Struct(data_struct) if data_struct.fields.len() == 1 => {
+            data_struct.fields.iter().next().unwrap()
+        }
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "#[kp(transparent)] requires a struct with exactly one field",
+            ));
+        }
+    };
+    let ty = &field.ty;
+    let (access, construct) = match &field.ident {
+        Some(ident) => (quote!(#ident), quote!(Self { #ident: value })),
+        None => (quote!(0), quote!(Self(value))),
+    };
+
+    if !input.generics.params.is_empty() {
+        // `impl<T> From<Wrapper<T>> for T` breaks the orphan rule, so generic newtypes get no
+        // newtype -> inner `From`, and `iso()` unwraps through the field instead.
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+        return Ok(quote! {
+            impl #impl_generics rust_key_paths::Transparent for #name #ty_generics #where_clause {
+                type Inner = #ty;
+                #[inline(always)]
+                fn kp_inner(&self) -> &#ty {
+                    &self.#access
+                }
+                #[inline(always)]
+                fn kp_inner_mut(&mut self) -> &mut #ty {
+                    &mut self.#access
+                }
+            }
+
+            impl #impl_generics From<#ty> for #name #ty_generics #where_clause {
+                #[inline(always)]
+                fn from(value: #ty) -> Self {
+                    #construct
+                }
+            }
+
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Iso keypath between this newtype and its inner value; `.to_kp()` composes with `then`.
+                #[inline(always)]
+                pub fn iso() -> rust_key_paths::IsoKp<Self, #ty> {
+                    rust_key_paths::IsoKp::new(
+                        |r: &Self| Some(&r.#access),
+                        |r: &mut Self| Some(&mut r.#access),
+                        <Self as From<#ty>>::from,
+                        |r: Self| r.#access,
+                    )
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl rust_key_paths::Transparent for #name {
+            type Inner = #ty;
+            #[inline(always)]
+            fn kp_inner(&self) -> &#ty {
+                &self.#access
+            }
+            #[inline(always)]
+            fn kp_inner_mut(&mut self) -> &mut #ty {
+                &mut self.#access
+            }
+        }
+
+        impl From<#ty> for #name {
+            #[inline(always)]
+            fn from(value: #ty) -> Self {
+                #construct
+            }
+        }
+
+        impl From<#name> for #ty {
+            #[inline(always)]
+            fn from(value: #name) -> Self {
+                value.#access
+            }
+        }
+
+        impl #name {
+            /// Iso keypath between this newtype and its inner value; `.to_kp()` composes with `then`.
+            #[inline(always)]
+            pub const fn iso() -> rust_key_paths::IsoKp<#name, #ty> {
+                const fn __kp_get_iso(r: &#name) -> Option<&#ty> { Some(&r.#access) }
+                const fn __kp_set_iso(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#access) }
+                rust_key_paths::IsoKp::new(
+                    __kp_get_iso,
+                    __kp_set_iso,
+                    <#name as From<#ty>>::from,
+                    <#ty as From<#name>>::from,
+                )
+            }
+        }
+    })
+}
+
 /// Check if a field has the #[pin] attribute (pin_project pattern).
 fn field_has_pin_attr(field: &syn::Field) -> bool {
     field.attrs.iter().any(|attr| {
@@ -528,14 +1460,14 @@ fn extract_future_output(ty: &Type) -> Option<Type> {
     None
 }
 
-/// For HashMap<K,V> or BTreeMap<K,V>, returns Some((key_ty, value_ty)).
+/// For HashMap<K,V>, BTreeMap<K,V>, IndexMap<K,V> or DashMap<K,V>, returns Some((key_ty, value_ty)).
 fn extract_map_key_value(ty: &Type) -> Option<(Type, Type)> {
     use syn::{GenericArgument, PathArguments};
 
     if let Type::Path(tp) = ty {
         if let Some(seg) = tp.path.segments.last() {
             let ident_str = seg.ident.to_string();
-            if ident_str == "HashMap" || ident_str == "BTreeMap" {
+            if matches!(ident_str.as_str(), "HashMap" | "BTreeMap" | "IndexMap" | "DashMap") {
                 if let PathArguments::AngleBracketed(ab) = &seg.arguments {
                     let args: Vec<_> = ab.args.iter().collect();
                     if let (Some(key_arg), Some(value_arg)) = (args.get(0), args.get(1)) {
@@ -552,6 +1484,14 @@ fn extract_map_key_value(ty: &Type) -> Option<(Type, Type)> {
     None
 }
 
+/// For a tuple type (A, B, ...), returns the element types in order.
+fn extract_tuple_elems(ty: &Type) -> Vec<Type> {
+    match ty {
+        Type::Tuple(tt) => tt.elems.iter().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
 fn to_snake_case(name: &str) -> String {
     let mut out = String::new();
     for (i, c) in name.chars().enumerate() {
@@ -591,11 +1531,49 @@ fn to_snake_case(name: &str) -> String {
 /// //     pub fn addresses() -> Kp<...> { ... } // accesses first element
 /// // }
 /// ```
-#[proc_macro_derive(Kp)]
+///
+/// `#[kp(transparent)]` on a single-field struct implements `Transparent` and `From` both ways and
+/// generates `iso()`; on a field it generates `{field}_inner()` through the newtype. Generic
+/// newtypes only get `From<Inner>`: the reverse impl is ruled out by the orphan rule.
+///
+/// Generic structs get plain field keypaths, `{field}_at(i)` / `{field}_range(a..b)` for `&'a [T]`
+/// fields, plus `{field}_poly::<B>()` (a type-changing `PolyKp`) for every field whose type is a
+/// type parameter used by no other field.
+///
+/// `#[kp(...)]` field attributes are read on non-generic structs only. On enum variants or generic
+/// structs, or where `container` / `via ... at` would clash with a built-in `{field}_at`, they are
+/// a compile error:
+///
+/// ```compile_fail
+/// #[derive(key_paths_derive::Kp)]
+/// enum Event {
+///     Click(#[kp(container)] Vec<u8>),
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(key_paths_derive::Kp)]
+/// struct Log {
+///     #[kp(container)]
+///     lines: Vec<String>,
+/// }
+/// ```
+#[proc_macro_derive(Kp, attributes(kp))]
 pub fn derive_keypaths(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
     let input_span = input.span();
+    if let Err(err) = reject_unsupported_kp_attrs(&input) {
+        return err.to_compile_error().into();
+    }
+    if let (false, Data::Struct(data_struct)) = (input.generics.params.is_empty(), &input.data) {
+        let mut tokens = generate_generic_struct_kps(&input, &data_struct.fields)
+            .unwrap_or_else(|err| err.to_compile_error());
+        tokens.extend(generate_transparent_impls(&input).unwrap_or_else(|err| err.to_compile_error()));
+        return tokens.into();
+    }
+    let transparent = generate_transparent_impls(&input).unwrap_or_else(|err| err.to_compile_error());
+    let packed = is_repr_packed(&input.attrs);
 
     let methods = match input.data {
         Data::Struct(data_struct) => match data_struct.fields {
@@ -617,8 +1595,8 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         fn(MutRoot) -> Option<MutRoot>,
                     >
                     where
-                        Root: std::borrow::Borrow<#name>,
-                        MutRoot: std::borrow::BorrowMut<#name>,
+                        Root: core::borrow::Borrow<#name>,
+                        MutRoot: core::borrow::BorrowMut<#name>,
                     {
                         rust_key_paths::Kp::new(
                             |r: Root| Some(r),
@@ -641,6 +1619,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
 
                 for field in fields_named.named.iter() {
                     let field_ident = field.ident.as_ref().unwrap();
+                    let segment = field_ident.to_string();
                     let ty = &field.ty;
                     // Centralized keypath method names – change here to adjust for all types
                     let kp_fn = format_ident!("{}", field_ident);
@@ -671,6 +1650,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             // For Option<T>, unwrap and access inner type
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -689,6 +1669,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         | (WrapperKind::OptionBTreeMap, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -701,6 +1682,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::Vec, Some(inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -711,8 +1693,8 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 #[inline(always)]
                                 pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        Box::new(move |root: &#name| root.#field_ident.get(index)),
-                                        Box::new(move |root: &mut #name| root.#field_ident.get_mut(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#field_ident.get_mut(index)),
                                     )
                                 }
                             });
@@ -721,6 +1703,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             if let Some((key_ty, _)) = extract_map_key_value(ty) {
+                                tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                                 tokens.extend(quote! {
                                     #[inline(always)]
                                     pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -731,16 +1714,17 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     #[inline(always)]
                                     pub fn #kp_at_fn(key: #key_ty) -> rust_key_paths::KpDynamic<#name, #inner_ty>
                                     where
-                                        #key_ty: Clone + std::hash::Hash + Eq + 'static,
+                                        #key_ty: Clone + core::hash::Hash + Eq + 'static,
                                     {
                                         let key2 = key.clone();
                                         rust_key_paths::Kp::new(
-                                            Box::new(move |root: &#name| root.#field_ident.get(&key)),
-                                            Box::new(move |root: &mut #name| root.#field_ident.get_mut(&key2)),
+                                            rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(&key)),
+                                            rust_key_paths::__private::Box::new(move |root: &mut #name| root.#field_ident.get_mut(&key2)),
                                         )
                                     }
                                 });
                             } else {
+                                tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                                 tokens.extend(quote! {
                                     #[inline(always)]
                                     pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -755,6 +1739,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             if let Some((key_ty, _)) = extract_map_key_value(ty) {
+                                tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                                 tokens.extend(quote! {
                                     #[inline(always)]
                                     pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -769,12 +1754,13 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     {
                                         let key2 = key.clone();
                                         rust_key_paths::Kp::new(
-                                            Box::new(move |root: &#name| root.#field_ident.get(&key)),
-                                            Box::new(move |root: &mut #name| root.#field_ident.get_mut(&key2)),
+                                            rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(&key)),
+                                            rust_key_paths::__private::Box::new(move |root: &mut #name| root.#field_ident.get_mut(&key2)),
                                         )
                                     }
                                 });
                             } else {
+                                tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                                 tokens.extend(quote! {
                                     #[inline(always)]
                                     pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -787,6 +1773,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::Box, Some(inner_ty)) => {
                             // For Box<T>, deref to inner type (returns &T / &mut T, not &Box<T>)
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -801,6 +1788,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_inner_fn = format_ident!("{}_inner", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -810,11 +1798,11 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                                 #[inline(always)]
                                 pub fn #kp_inner_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty>
-                                where #inner_ty: std::marker::Unpin
+                                where #inner_ty: core::marker::Unpin
                                 {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| Some(std::pin::Pin::as_ref(&root.#field_ident).get_ref()),
-                                        |root: &mut #name| Some(std::pin::Pin::as_mut(&mut root.#field_ident).get_mut()),
+                                        |root: &#name| Some(core::pin::Pin::as_ref(&root.#field_ident).get_ref()),
+                                        |root: &mut #name| Some(core::pin::Pin::as_mut(&mut root.#field_ident).get_mut()),
                                     )
                                 }
                             });
@@ -823,6 +1811,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_inner_fn = format_ident!("{}_inner", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -832,11 +1821,11 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                                 #[inline(always)]
                                 pub fn #kp_inner_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty>
-                                where #inner_ty: std::marker::Unpin
+                                where #inner_ty: core::marker::Unpin
                                 {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| Some(std::pin::Pin::as_ref(&root.#field_ident).get_ref()),
-                                        |root: &mut #name| Some(std::pin::Pin::as_mut(&mut root.#field_ident).get_mut()),
+                                        |root: &#name| Some(core::pin::Pin::as_ref(&root.#field_ident).get_ref()),
+                                        |root: &mut #name| Some(core::pin::Pin::as_mut(&mut root.#field_ident).get_mut()),
                                     )
                                 }
                             });
@@ -845,6 +1834,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_pinned_fn = format_ident!("{}_pinned", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -854,7 +1844,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                                 /// Pinned projection for #[pin] field. Requires #[pin_project] on struct.
                                 #[inline(always)]
-                                pub fn #kp_pinned_fn(this: std::pin::Pin<&mut #name>) -> std::pin::Pin<&mut #ty> {
+                                pub fn #kp_pinned_fn(this: core::pin::Pin<&mut #name>) -> core::pin::Pin<&mut #ty> {
                                     this.project().#field_ident
                                 }
                             });
@@ -865,7 +1855,8 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let kp_pinned_fn = format_ident!("{}_pinned", field_ident);
                             let kp_await_fn = format_ident!("{}_await", field_ident);
                             let kp_pin_future_fn = format_ident!("{}_pin_future_kp", field_ident);
-                            let output_ty = quote! { <#ty as std::future::Future>::Output };
+                            let output_ty = quote! { <#ty as core::future::Future>::Output };
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -875,14 +1866,14 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                                 /// Pinned projection for #[pin] Future field. Requires #[pin_project] on struct.
                                 #[inline(always)]
-                                pub fn #kp_pinned_fn(this: std::pin::Pin<&mut #name>) -> std::pin::Pin<&mut #ty> {
+                                pub fn #kp_pinned_fn(this: core::pin::Pin<&mut #name>) -> core::pin::Pin<&mut #ty> {
                                     this.project().#field_ident
                                 }
                                 /// Poll the pinned future. Requires #[pin_project] on struct.
-                                pub async fn #kp_await_fn(this: std::pin::Pin<&mut #name>) -> Option<#output_ty>
-                                where #ty: std::future::Future
+                                pub async fn #kp_await_fn(this: core::pin::Pin<&mut #name>) -> Option<#output_ty>
+                                where #ty: core::future::Future
                                 {
-                                    use std::future::Future;
+                                    use core::future::Future;
                                     Some(this.project().#field_ident.await)
                                 }
                                 /// Keypath for [rust_key_paths::Kp::then_pin_future]. Composable pin future await.
@@ -898,6 +1889,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let kp_pinned_fn = format_ident!("{}_pinned", field_ident);
                             let kp_await_fn = format_ident!("{}_await", field_ident);
                             let kp_pin_future_fn = format_ident!("{}_pin_future_kp", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -907,11 +1899,11 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                                 /// Pinned projection for #[pin] Box<dyn Future> field. Requires #[pin_project] on struct.
                                 #[inline(always)]
-                                pub fn #kp_pinned_fn(this: std::pin::Pin<&mut #name>) -> std::pin::Pin<&mut #ty> {
+                                pub fn #kp_pinned_fn(this: core::pin::Pin<&mut #name>) -> core::pin::Pin<&mut #ty> {
                                     this.project().#field_ident
                                 }
                                 /// Poll the pinned boxed future. Requires #[pin_project] on struct.
-                                pub async fn #kp_await_fn(this: std::pin::Pin<&mut #name>) -> Option<#output_ty> {
+                                pub async fn #kp_await_fn(this: core::pin::Pin<&mut #name>) -> Option<#output_ty> {
                                     Some(this.project().#field_ident.await)
                                 }
                                 /// Keypath for [rust_key_paths::Kp::then_pin_future]. Composable pin future await.
@@ -923,30 +1915,33 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::Rc, Some(inner_ty)) => {
                             // For Rc<T>, deref to inner type (returns &T; get_mut when uniquely owned)
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| Some(root.#field_ident.as_ref()),
-                                        |root: &mut #name| std::rc::Rc::get_mut(&mut root.#field_ident),
+                                        |root: &mut #name| rust_key_paths::__private::Rc::get_mut(&mut root.#field_ident),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::Arc, Some(inner_ty)) => {
                             // For Arc<T>, deref to inner type (returns &T; get_mut when uniquely owned)
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| Some(root.#field_ident.as_ref()),
-                                        |root: &mut #name| std::sync::Arc::get_mut(&mut root.#field_ident),
+                                        |root: &mut #name| rust_key_paths::__private::Arc::get_mut(&mut root.#field_ident),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::Cow, Some(inner_ty)) => {
                             // For Cow<'_, B>, deref to inner type (as_ref/to_mut)
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -960,6 +1955,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         
                         (WrapperKind::OptionCow, Some(inner_ty)) => {
                             // For Option<Cow<'_, B>>
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -972,18 +1968,20 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::OptionTagged, Some(inner_ty)) => {
                             // For Option<Tagged<Tag, T>> - Tagged implements Deref/DerefMut
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#field_ident.as_ref().map(|t| std::ops::Deref::deref(t)),
-                                        |root: &mut #name| root.#field_ident.as_mut().map(|t| std::ops::DerefMut::deref_mut(t)),
+                                        |root: &#name| root.#field_ident.as_ref().map(|t| core::ops::Deref::deref(t)),
+                                        |root: &mut #name| root.#field_ident.as_mut().map(|t| core::ops::DerefMut::deref_mut(t)),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::OptionReference, Some(inner_ty)) => {
                             // For Option<&T>, Option<&str>, Option<&[T]> - read-only, setter returns None
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -999,6 +1997,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_at_fn = format_ident!("{}_at", field_ident);
 
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1012,11 +2011,11 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 #[inline(always)]
                                 pub fn #kp_at_fn(key: #inner_ty) -> rust_key_paths::KpDynamic<#name, #inner_ty>
                                 where
-                                    #inner_ty: Clone + std::hash::Hash + Eq + 'static,
+                                    #inner_ty: Clone + core::hash::Hash + Eq + 'static,
                                 {
                                     rust_key_paths::Kp::new(
-                                        Box::new(move |root: &#name| root.#field_ident.get(&key)),
-                                        Box::new(move |_root: &mut #name| None),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(&key)),
+                                        rust_key_paths::__private::Box::new(move |_root: &mut #name| None),
                                     )
                                 }
                             });
@@ -1026,6 +2025,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_at_fn = format_ident!("{}_at", field_ident);
 
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1042,8 +2042,8 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     #inner_ty: Clone + Ord + 'static,
                                 {
                                     rust_key_paths::Kp::new(
-                                        Box::new(move |root: &#name| root.#field_ident.get(&key)),
-                                        Box::new(move |_root: &mut #name| None),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(&key)),
+                                        rust_key_paths::__private::Box::new(move |_root: &mut #name| None),
                                     )
                                 }
                             });
@@ -1051,6 +2051,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::VecDeque, Some(inner_ty)) | (WrapperKind::VecDequeOption, Some(inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1061,8 +2062,8 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 #[inline(always)]
                                 pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        Box::new(move |root: &#name| root.#field_ident.get(index)),
-                                        Box::new(move |root: &mut #name| root.#field_ident.get_mut(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#field_ident.get_mut(index)),
                                     )
                                 }
                             });
@@ -1070,6 +2071,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::LinkedList, Some(_inner_ty)) | (WrapperKind::LinkedListOption, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1082,6 +2084,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::BinaryHeap, Some(_inner_ty)) | (WrapperKind::BinaryHeapOption, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1093,6 +2096,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::Result, Some(inner_ty)) => {
                             // Result::as_ref().ok() / as_mut().ok() are not const fn in stable
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -1107,6 +2111,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_lock_fn = format_ident!("{}_lock", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1130,6 +2135,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_lock_fn = format_ident!("{}_lock", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1153,6 +2159,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_lock_fn = format_ident!("{}_lock", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1176,6 +2183,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_lock_fn = format_ident!("{}_lock", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1199,6 +2207,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         | (WrapperKind::StdMutex, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1212,6 +2221,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         | (WrapperKind::StdRwLock, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1225,6 +2235,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_async_fn = format_ident!("{}_async", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1248,6 +2259,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_async_fn = format_ident!("{}_async", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1267,10 +2279,57 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                             });
                         }
+                        (
+                            WrapperKind::TokioArcOnceCell
+                            | WrapperKind::OptionTokioArcOnceCell
+                            | WrapperKind::FuturesArcMutex
+                            | WrapperKind::OptionFuturesArcMutex
+                            | WrapperKind::AsyncLockArcMutex
+                            | WrapperKind::OptionAsyncLockArcMutex
+                            | WrapperKind::AsyncLockArcRwLock
+                            | WrapperKind::OptionAsyncLockArcRwLock,
+                            Some(inner_ty),
+                        ) => {
+                            let (access, is_option, guarded) = async_lock_access(kind, &inner_ty);
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            let kp_async_fn = format_ident!("{}_async", field_ident);
+                            let (lock_ty, prev) = if is_option {
+                                (
+                                    first_type_arg(ty).unwrap(),
+                                    quote! {
+                                        rust_key_paths::Kp::new(
+                                            |root: &#name| root.#field_ident.as_ref(),
+                                            |root: &mut #name| root.#field_ident.as_mut(),
+                                        )
+                                    },
+                                )
+                            } else {
+                                (ty.clone(), quote!(Self::#kp_fn()))
+                            };
+                            let (async_ty, next) = async_lock_kp(name, &lock_ty, &inner_ty, &access, guarded);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                                pub fn #kp_async_fn() -> #async_ty {
+                                    rust_key_paths::async_lock::AsyncLockKp::new(
+                                        #prev,
+                                        <#access>::new(),
+                                        #next,
+                                    )
+                                }
+                            });
+                        }
                         (WrapperKind::OptionTokioArcMutex, Some(inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_async_fn = format_ident!("{}_async", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1297,6 +2356,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_async_fn = format_ident!("{}_async", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1324,6 +2384,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_unlocked_fn = format_ident!("{}_unlocked", field_ident);
                             let kp_lock_fn = format_ident!("{}_lock", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1357,6 +2418,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_unlocked_fn = format_ident!("{}_unlocked", field_ident);
                             let kp_lock_fn = format_ident!("{}_lock", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1390,6 +2452,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_unlocked_fn = format_ident!("{}_unlocked", field_ident);
                             let kp_lock_fn = format_ident!("{}_lock", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1423,6 +2486,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_unlocked_fn = format_ident!("{}_unlocked", field_ident);
                             let kp_lock_fn = format_ident!("{}_lock", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1456,6 +2520,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_unlocked_fn = format_ident!("{}_unlocked", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1476,6 +2541,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
                             let kp_unlocked_fn = format_ident!("{}_unlocked", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1492,8 +2558,11 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             });
                         }
                         (WrapperKind::Weak, Some(_inner_ty)) => {
+                            // Weak: kp_fn() gives the container; _upgrade() yields the owned Arc<T> / Rc<T>
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            let kp_upgrade_fn = format_ident!("{}_upgrade", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1501,11 +2570,19 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     const fn #set_fn(_r: &mut #name) -> Option<&mut #ty> { None }
                                     rust_key_paths::Kp::new_const(#get_fn, #set_fn)
                                 }
+                                #[inline(always)]
+                                pub fn #kp_upgrade_fn() -> rust_key_paths::KpOwned<'static, #name, <#ty as rust_key_paths::WeakUpgrade>::Strong> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| rust_key_paths::WeakUpgrade::upgrade(&root.#field_ident),
+                                        |root: &mut #name| rust_key_paths::WeakUpgrade::upgrade(&root.#field_ident),
+                                    )
+                                }
                             });
                         }
                         (WrapperKind::Atomic, None | Some(_)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1518,6 +2595,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::OptionAtomic, Some(inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -1530,6 +2608,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::String, None) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1542,18 +2621,22 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::OptionString, None) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(rust_key_paths::__private::String), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, std::string::String> {
-                                    const fn #get_fn(r: &#name) -> Option<&std::string::String> { r.#field_ident.as_ref() }
-                                    const fn #set_fn(r: &mut #name) -> Option<&mut std::string::String> { r.#field_ident.as_mut() }
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, rust_key_paths::__private::String> {
+                                    const fn #get_fn(r: &#name) -> Option<&rust_key_paths::__private::String> { r.#field_ident.as_ref() }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut rust_key_paths::__private::String> { r.#field_ident.as_mut() }
                                     rust_key_paths::Kp::new_const(#get_fn, #set_fn)
                                 }
                             });
                         }
-                        (WrapperKind::Cell, Some(_inner_ty)) => {
+                        (WrapperKind::Cell, Some(inner_ty)) => {
+                            // Cell: kp_fn() gives the container; _cell() copies the value in/out through &Root
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            let kp_cell_fn = format_ident!("{}_cell", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1561,11 +2644,18 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
                                     rust_key_paths::Kp::new_const(#get_fn, #set_fn)
                                 }
+                                #[inline(always)]
+                                pub const fn #kp_cell_fn() -> rust_key_paths::CellKp<#name, #inner_ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::CellKp::new(#get_fn, #set_fn)
+                                }
                             });
                         }
                         (WrapperKind::RefCell, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1576,24 +2666,36 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             });
                         }
                         (WrapperKind::OnceCell, Some(inner_ty)) => {
-                            // OnceLock/OnceCell: keypath to inner value; get = .get() -> Option<&T>, set = None
+                            // OnceLock/OnceCell: keypath to inner value; get = .get(), set = .get_mut() (None until initialized)
+                            // _once() gives get_or_init / set through the path
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            let kp_once_fn = format_ident!("{}_once", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| root.#field_ident.get(),
-                                        |_root: &mut #name| None,
+                                        |root: &mut #name| root.#field_ident.get_mut(),
                                     )
                                 }
+                                #[inline(always)]
+                                pub const fn #kp_once_fn() -> rust_key_paths::OnceKp<#name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::OnceKp::new(#get_fn, #set_fn)
+                                }
                             });
                         }
                         (WrapperKind::Lazy, Some(inner_ty)) => {
-                            // Lazy/LazyLock: keypath to inner value; get = .get() -> &T wrapped in Some, set = None
+                            // Lazy/LazyLock: keypath to inner value; get forces via Deref, set = None
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| Some(root.#field_ident.get()),
+                                        |root: &#name| Some(core::ops::Deref::deref(&root.#field_ident)),
                                         |_root: &mut #name| None,
                                     )
                                 }
@@ -1602,6 +2704,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::PhantomData, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1614,6 +2717,192 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::Range, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionCell, Some(inner_ty)) => {
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            let kp_cell_fn = format_ident!("{}_cell", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                                #[inline(always)]
+                                pub fn #kp_cell_fn() -> rust_key_paths::CellKp<#name, #inner_ty> {
+                                    rust_key_paths::CellKp::new(
+                                        |root: &#name| root.#field_ident.as_ref(),
+                                        |root: &mut #name| root.#field_ident.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionRefCell, Some(_inner_ty)) => {
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionOnceCell, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#field_ident.as_ref().and_then(|c| c.get()),
+                                        |root: &mut #name| root.#field_ident.as_mut().and_then(|c| c.get_mut()),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionLazy, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#field_ident.as_ref().map(|c| core::ops::Deref::deref(c)),
+                                        |_root: &mut #name| None,
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionPhantomData, Some(_inner_ty)) => {
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionRange, Some(_inner_ty)) => {
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                            });
+                        }
+                        (WrapperKind::Array, Some(inner_ty)) | (WrapperKind::BoxSlice, Some(inner_ty)) => {
+                            // [T; N] / Box<[T]>: kp_fn() gives the container; _at(i) an element, _range(a..b) a window
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            let kp_range_fn = format_ident!("{}_range", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                                #[inline(always)]
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#field_ident.get_mut(index)),
+                                    )
+                                }
+                                #[inline(always)]
+                                pub fn #kp_range_fn(range: core::ops::Range<usize>) -> rust_key_paths::SliceKp<#name, #inner_ty> {
+                                    rust_key_paths::SliceKp::new(
+                                        |root: &#name| Some(&root.#field_ident[..]),
+                                        |root: &mut #name| Some(&mut root.#field_ident[..]),
+                                        range,
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::SliceRef, Some(inner_ty)) => {
+                            // &[T]: read-only; _at(i) an element, _range(a..b) a window
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            let kp_range_fn = format_ident!("{}_range", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(_r: &mut #name) -> Option<&mut #ty> { None }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                                #[inline(always)]
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(index)),
+                                        rust_key_paths::__private::Box::new(move |_root: &mut #name| None),
+                                    )
+                                }
+                                #[inline(always)]
+                                pub fn #kp_range_fn(range: core::ops::Range<usize>) -> rust_key_paths::SliceKp<#name, #inner_ty> {
+                                    rust_key_paths::SliceKp::new(
+                                        |root: &#name| Some(root.#field_ident),
+                                        |_root: &mut #name| None,
+                                        range,
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::Tuple, None) => {
+                            // (A, B, ...): kp_fn() gives the whole tuple; kp_fn_0(), kp_fn_1(), ... each element
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                            });
+                            for (elem_idx, elem_ty) in extract_tuple_elems(ty).iter().enumerate() {
+                                let elem_lit = syn::Index::from(elem_idx);
+                                let kp_elem_fn = format_ident!("{}_{}", field_ident, elem_idx);
+                                let elem_get_fn = format_ident!("__kp_get_{}_{}", field_ident, elem_idx);
+                                let elem_set_fn = format_ident!("__kp_set_{}_{}", field_ident, elem_idx);
+                                tokens.extend(quote! {
+                                    #[inline(always)]
+                                    pub const fn #kp_elem_fn() -> rust_key_paths::KpType<'static, #name, #elem_ty> {
+                                        const fn #elem_get_fn(r: &#name) -> Option<&#elem_ty> { Some(&r.#field_ident.#elem_lit) }
+                                        const fn #elem_set_fn(r: &mut #name) -> Option<&mut #elem_ty> { Some(&mut r.#field_ident.#elem_lit) }
+                                        rust_key_paths::Kp::new_const(#elem_get_fn, #elem_set_fn)
+                                    }
+                                });
+                            }
+                        }
+                        (WrapperKind::SmallVec, Some(inner_ty)) | (WrapperKind::ArrayVec, Some(inner_ty)) => {
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1621,11 +2910,43 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
                                     rust_key_paths::Kp::new_const(#get_fn, #set_fn)
                                 }
+                                #[inline(always)]
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#field_ident.get_mut(index)),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::ImVector, Some(inner_ty)) => {
+                            // im::Vector::get_mut copies shared chunks on write, so it needs T: Clone
+                            let get_fn = format_ident!("__kp_get_{}", field_ident);
+                            let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
+                                    rust_key_paths::Kp::new_const(#get_fn, #set_fn)
+                                }
+                                #[inline(always)]
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty>
+                                where
+                                    #inner_ty: Clone,
+                                {
+                                    rust_key_paths::Kp::new(
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#field_ident.get_mut(index)),
+                                    )
+                                }
                             });
                         }
-                        (WrapperKind::OptionCell, Some(_inner_ty)) => {
+                        (WrapperKind::IndexMap, Some(inner_ty)) | (WrapperKind::ImHashMap, Some(inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1634,10 +2955,34 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     rust_key_paths::Kp::new_const(#get_fn, #set_fn)
                                 }
                             });
+                            if let Some((key_ty, _)) = extract_map_key_value(ty) {
+                                // im::HashMap::get_mut copies shared nodes on write, so it needs V: Clone
+                                let value_bound = if kind == WrapperKind::ImHashMap {
+                                    quote! { #inner_ty: Clone, }
+                                } else {
+                                    quote! {}
+                                };
+                                tokens.extend(quote! {
+                                    #[inline(always)]
+                                    pub fn #kp_at_fn(key: #key_ty) -> rust_key_paths::KpDynamic<#name, #inner_ty>
+                                    where
+                                        #key_ty: Clone + core::hash::Hash + Eq + 'static,
+                                        #value_bound
+                                    {
+                                        let key2 = key.clone();
+                                        rust_key_paths::Kp::new(
+                                            rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(&key)),
+                                            rust_key_paths::__private::Box::new(move |root: &mut #name| root.#field_ident.get_mut(&key2)),
+                                        )
+                                    }
+                                });
+                            }
                         }
-                        (WrapperKind::OptionRefCell, Some(_inner_ty)) => {
+                        (WrapperKind::Bytes, None) => {
+                            // bytes::Bytes is immutable: _at(i) is read-only
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1645,33 +2990,20 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
                                     rust_key_paths::Kp::new_const(#get_fn, #set_fn)
                                 }
-                            });
-                        }
-                        (WrapperKind::OptionOnceCell, Some(inner_ty)) => {
-                            tokens.extend(quote! {
-                                #[inline(always)]
-                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
-                                    rust_key_paths::Kp::new(
-                                        |root: &#name| root.#field_ident.as_ref().and_then(|c| c.get()),
-                                        |_root: &mut #name| None,
-                                    )
-                                }
-                            });
-                        }
-                        (WrapperKind::OptionLazy, Some(inner_ty)) => {
-                            tokens.extend(quote! {
                                 #[inline(always)]
-                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, u8> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#field_ident.as_ref().map(|c| c.get()),
-                                        |_root: &mut #name| None,
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#field_ident.get(index)),
+                                        rust_key_paths::__private::Box::new(move |_root: &mut #name| None),
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::OptionPhantomData, Some(_inner_ty)) => {
+                        (WrapperKind::DashMap, Some(_inner_ty)) => {
+                            // DashMap: _at(key) returns shard-locked guards (requires rust-key-paths `dashmap` feature)
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1680,10 +3012,21 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     rust_key_paths::Kp::new_const(#get_fn, #set_fn)
                                 }
                             });
+                            if let Some((key_ty, _)) = extract_map_key_value(ty) {
+                                tokens.extend(quote! {
+                                    #[inline(always)]
+                                    pub fn #kp_at_fn(key: #key_ty) -> rust_key_paths::DashMapKp<#name, #ty, #key_ty> {
+                                        rust_key_paths::DashMapKp::new(|root: &#name| Some(&root.#field_ident), key)
+                                    }
+                                });
+                            }
                         }
-                        (WrapperKind::OptionRange, Some(_inner_ty)) => {
+                        (WrapperKind::ArcSwap, Some(inner_ty)) => {
+                            // ArcSwap: _load() gives snapshot/store access (requires rust-key-paths `arc_swap` feature)
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            let kp_load_fn = format_ident!("{}_load", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1691,11 +3034,17 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     const fn #set_fn(r: &mut #name) -> Option<&mut #ty> { Some(&mut r.#field_ident) }
                                     rust_key_paths::Kp::new_const(#get_fn, #set_fn)
                                 }
+                                #[inline(always)]
+                                pub const fn #kp_load_fn() -> rust_key_paths::ArcSwapKp<#name, #inner_ty> {
+                                    const fn #get_fn(r: &#name) -> Option<&#ty> { Some(&r.#field_ident) }
+                                    rust_key_paths::ArcSwapKp::new(#get_fn)
+                                }
                             });
                         }
                         (WrapperKind::Reference, Some(_inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1709,6 +3058,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             // For basic types, direct access
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1721,6 +3071,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         _ => {
                             let get_fn = format_ident!("__kp_get_{}", field_ident);
                             let set_fn = format_ident!("__kp_set_{}", field_ident);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1731,6 +3082,17 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             });
                         }
                     }
+
+                    match generate_kp_attr_accessors(name, field, quote!(#field_ident), &field_ident.to_string()) {
+                        Ok(extra) => tokens.extend(extra),
+                        Err(err) => tokens.extend(err.to_compile_error()),
+                    }
+                    tokens.extend(generate_each_accessor(name, field, quote!(#field_ident), &field_ident.to_string()));
+                    tokens.extend(generate_fill_accessors(name, field, quote!(#field_ident), &field_ident.to_string()));
+                    tokens.extend(generate_cow_accessors(name, field, quote!(#field_ident), &field_ident.to_string()));
+                    if !packed {
+                        tokens.extend(generate_offset_accessor(name, field, quote!(#field_ident), &field_ident.to_string()));
+                    }
                 }
                 
                 tokens
@@ -1753,8 +3115,8 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         fn(MutRoot) -> Option<MutRoot>,
                     >
                     where
-                        Root: std::borrow::Borrow<#name>,
-                        MutRoot: std::borrow::BorrowMut<#name>,
+                        Root: core::borrow::Borrow<#name>,
+                        MutRoot: core::borrow::BorrowMut<#name>,
                     {
                         rust_key_paths::Kp::new(
                             |r: Root| Some(r),
@@ -1774,6 +3136,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
 
                 for (idx, field) in unnamed.unnamed.iter().enumerate() {
                     let idx_lit = syn::Index::from(idx);
+                    let segment = idx.to_string();
                     let ty = &field.ty;
                     // Centralized keypath method names for tuple fields – change here to adjust for all types
                     let kp_fn = format_ident!("f{}", idx);
@@ -1785,6 +3148,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::Option, Some(inner_ty)) => {
                             let get_fn = format_ident!("__kp_get_f{}", idx);
                             let set_fn = format_ident!("__kp_set_f{}", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -1801,6 +3165,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         | (WrapperKind::OptionBTreeSet, Some(_inner_ty))
                         | (WrapperKind::OptionResult, Some(_inner_ty))
                         | (WrapperKind::OptionBTreeMap, Some(_inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1812,6 +3177,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             });
                         }
                         (WrapperKind::Vec, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1823,14 +3189,15 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 #[inline(always)]
                                 pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        Box::new(move |root: &#name| root.#idx_lit.get(index)),
-                                        Box::new(move |root: &mut #name| root.#idx_lit.get_mut(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#idx_lit.get_mut(index)),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::HashMap, Some(inner_ty)) => {
                             if let Some((key_ty, _)) = extract_map_key_value(ty) {
+                                tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                                 tokens.extend(quote! {
                                     #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1842,16 +3209,17 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     #[inline(always)]
                                     pub fn #kp_at_fn(key: #key_ty) -> rust_key_paths::KpDynamic<#name, #inner_ty>
                                     where
-                                        #key_ty: Clone + std::hash::Hash + Eq + 'static,
+                                        #key_ty: Clone + core::hash::Hash + Eq + 'static,
                                     {
                                         let key2 = key.clone();
                                         rust_key_paths::Kp::new(
-                                            Box::new(move |root: &#name| root.#idx_lit.get(&key)),
-                                            Box::new(move |root: &mut #name| root.#idx_lit.get_mut(&key2)),
+                                            rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(&key)),
+                                            rust_key_paths::__private::Box::new(move |root: &mut #name| root.#idx_lit.get_mut(&key2)),
                                         )
                                     }
                                 });
                             } else {
+                                tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                                 tokens.extend(quote! {
                                     #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1865,6 +3233,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::BTreeMap, Some(inner_ty)) | (WrapperKind::BTreeMapOption, Some(inner_ty)) => {
                             if let Some((key_ty, _)) = extract_map_key_value(ty) {
+                                tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                                 tokens.extend(quote! {
                                     #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1880,12 +3249,13 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     {
                                         let key2 = key.clone();
                                         rust_key_paths::Kp::new(
-                                            Box::new(move |root: &#name| root.#idx_lit.get(&key)),
-                                            Box::new(move |root: &mut #name| root.#idx_lit.get_mut(&key2)),
+                                            rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(&key)),
+                                            rust_key_paths::__private::Box::new(move |root: &mut #name| root.#idx_lit.get_mut(&key2)),
                                         )
                                     }
                                 });
                             } else {
+                                tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                                 tokens.extend(quote! {
                                     #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1899,6 +3269,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::Box, Some(inner_ty)) => {
                             // Box: deref to inner (returns &T / &mut T)
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -1911,6 +3282,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::Pin, Some(inner_ty)) => {
                             let kp_inner_fn = format_ident!("{}_inner", kp_fn);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1921,17 +3293,18 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                                 #[inline(always)]
                                 pub fn #kp_inner_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty>
-                                where #inner_ty: std::marker::Unpin
+                                where #inner_ty: core::marker::Unpin
                                 {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| Some(std::pin::Pin::as_ref(&root.#idx_lit).get_ref()),
-                                        |root: &mut #name| Some(std::pin::Pin::as_mut(&mut root.#idx_lit).get_mut()),
+                                        |root: &#name| Some(core::pin::Pin::as_ref(&root.#idx_lit).get_ref()),
+                                        |root: &mut #name| Some(core::pin::Pin::as_mut(&mut root.#idx_lit).get_mut()),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::PinBox, Some(inner_ty)) => {
                             let kp_inner_fn = format_ident!("{}_inner", kp_fn);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -1942,39 +3315,42 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                                 #[inline(always)]
                                 pub fn #kp_inner_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty>
-                                where #inner_ty: std::marker::Unpin
+                                where #inner_ty: core::marker::Unpin
                                 {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| Some(std::pin::Pin::as_ref(&root.#idx_lit).get_ref()),
-                                        |root: &mut #name| Some(std::pin::Pin::as_mut(&mut root.#idx_lit).get_mut()),
+                                        |root: &#name| Some(core::pin::Pin::as_ref(&root.#idx_lit).get_ref()),
+                                        |root: &mut #name| Some(core::pin::Pin::as_mut(&mut root.#idx_lit).get_mut()),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::Rc, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| Some(root.#idx_lit.as_ref()),
-                                        |root: &mut #name| std::rc::Rc::get_mut(&mut root.#idx_lit),
+                                        |root: &mut #name| rust_key_paths::__private::Rc::get_mut(&mut root.#idx_lit),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::Arc, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| Some(root.#idx_lit.as_ref()),
-                                        |root: &mut #name| std::sync::Arc::get_mut(&mut root.#idx_lit),
+                                        |root: &mut #name| rust_key_paths::__private::Arc::get_mut(&mut root.#idx_lit),
                                     )
                                 }
                             });
                         }
                         
                         (WrapperKind::Cow, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -1987,6 +3363,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         
                         (WrapperKind::OptionCow, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -1998,17 +3375,19 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             });
                         }
                         (WrapperKind::OptionTagged, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref().map(|t| std::ops::Deref::deref(t)),
-                                        |root: &mut #name| root.#idx_lit.as_mut().map(|t| std::ops::DerefMut::deref_mut(t)),
+                                        |root: &#name| root.#idx_lit.as_ref().map(|t| core::ops::Deref::deref(t)),
+                                        |root: &mut #name| root.#idx_lit.as_mut().map(|t| core::ops::DerefMut::deref_mut(t)),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::OptionReference, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -2022,6 +3401,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::HashSet, Some(inner_ty)) | (WrapperKind::HashSetOption, Some(inner_ty)) => {
                             let kp_at_fn = format_ident!("f{}_at", idx);
 
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2036,11 +3416,11 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 #[inline(always)]
                                 pub fn #kp_at_fn(key: #inner_ty) -> rust_key_paths::KpDynamic<#name, #inner_ty>
                                 where
-                                    #inner_ty: Clone + std::hash::Hash + Eq + 'static,
+                                    #inner_ty: Clone + core::hash::Hash + Eq + 'static,
                                 {
                                     rust_key_paths::Kp::new(
-                                        Box::new(move |root: &#name| root.#idx_lit.get(&key)),
-                                        Box::new(move |_root: &mut #name| None),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(&key)),
+                                        rust_key_paths::__private::Box::new(move |_root: &mut #name| None),
                                     )
                                 }
                             });
@@ -2048,6 +3428,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::BTreeSet, Some(inner_ty)) | (WrapperKind::BTreeSetOption, Some(inner_ty)) => {
                             let kp_at_fn = format_ident!("f{}_at", idx);
 
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2065,13 +3446,14 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     #inner_ty: Clone + Ord + 'static,
                                 {
                                     rust_key_paths::Kp::new(
-                                        Box::new(move |root: &#name| root.#idx_lit.get(&key)),
-                                        Box::new(move |_root: &mut #name| None),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(&key)),
+                                        rust_key_paths::__private::Box::new(move |_root: &mut #name| None),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::VecDeque, Some(inner_ty)) | (WrapperKind::VecDequeOption, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2083,13 +3465,14 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 #[inline(always)]
                                 pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        Box::new(move |root: &#name| root.#idx_lit.get(index)),
-                                        Box::new(move |root: &mut #name| root.#idx_lit.get_mut(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#idx_lit.get_mut(index)),
                                     )
                                 }
                             });
                         }
                         (WrapperKind::LinkedList, Some(_inner_ty)) | (WrapperKind::LinkedListOption, Some(_inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2101,6 +3484,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             });
                         }
                         (WrapperKind::BinaryHeap, Some(_inner_ty)) | (WrapperKind::BinaryHeapOption, Some(_inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2112,6 +3496,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             });
                         }
                         (WrapperKind::Result, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -2124,6 +3509,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::Mutex, Some(_inner_ty))
                         | (WrapperKind::StdMutex, Some(_inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2136,6 +3522,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::RwLock, Some(_inner_ty))
                         | (WrapperKind::StdRwLock, Some(_inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2148,6 +3535,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::TokioArcMutex, Some(inner_ty)) => {
                             let kp_async_fn = format_ident!("f{}_async", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2173,6 +3561,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::TokioArcRwLock, Some(inner_ty)) => {
                             let kp_async_fn = format_ident!("f{}_async", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2196,8 +3585,62 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                             });
                         }
+                        (
+                            WrapperKind::TokioArcOnceCell
+                            | WrapperKind::OptionTokioArcOnceCell
+                            | WrapperKind::FuturesArcMutex
+                            | WrapperKind::OptionFuturesArcMutex
+                            | WrapperKind::AsyncLockArcMutex
+                            | WrapperKind::OptionAsyncLockArcMutex
+                            | WrapperKind::AsyncLockArcRwLock
+                            | WrapperKind::OptionAsyncLockArcRwLock,
+                            Some(inner_ty),
+                        ) => {
+                            let (access, is_option, guarded) = async_lock_access(kind, &inner_ty);
+                            let kp_async_fn = format_ident!("f{}_async", idx);
+                            let (lock_ty, prev) = if is_option {
+                                (
+                                    first_type_arg(ty).unwrap(),
+                                    quote! {
+                                        rust_key_paths::Kp::new(
+                                            |root: &#name| root.#idx_lit.as_ref(),
+                                            |root: &mut #name| root.#idx_lit.as_mut(),
+                                        )
+                                    },
+                                )
+                            } else {
+                                (
+                                    ty.clone(),
+                                    quote! {
+                                        rust_key_paths::Kp::new(
+                                            |root: &#name| Some(&root.#idx_lit),
+                                            |root: &mut #name| Some(&mut root.#idx_lit),
+                                        )
+                                    },
+                                )
+                            };
+                            let (async_ty, next) = async_lock_kp(name, &lock_ty, &inner_ty, &access, guarded);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                                pub fn #kp_async_fn() -> #async_ty {
+                                    rust_key_paths::async_lock::AsyncLockKp::new(
+                                        #prev,
+                                        <#access>::new(),
+                                        #next,
+                                    )
+                                }
+                            });
+                        }
                         (WrapperKind::OptionTokioArcMutex, Some(inner_ty)) => {
                             let kp_async_fn = format_ident!("f{}_async", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2223,6 +3666,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         }
                         (WrapperKind::OptionTokioArcRwLock, Some(inner_ty)) => {
                             let kp_async_fn = format_ident!("f{}_async", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                     pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2246,117 +3690,332 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 }
                             });
                         }
-                        (WrapperKind::OptionStdArcMutex, Some(inner_ty)) => {
-                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                        (WrapperKind::OptionStdArcMutex, Some(inner_ty)) => {
+                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Arc<std::sync::Mutex<#inner_ty>>> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.as_ref(),
+                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionArcMutex, Some(inner_ty)) => {
+                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Arc<parking_lot::Mutex<#inner_ty>>> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.as_ref(),
+                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionStdArcRwLock, Some(inner_ty)) => {
+                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Arc<std::sync::RwLock<#inner_ty>>> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.as_ref(),
+                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionArcRwLock, Some(inner_ty)) => {
+                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Arc<parking_lot::RwLock<#inner_ty>>> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.as_ref(),
+                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionStdMutex, Some(inner_ty))
+                        | (WrapperKind::OptionMutex, Some(inner_ty)) => {
+                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Mutex<#inner_ty>> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.as_ref(),
+                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionStdRwLock, Some(inner_ty))
+                        | (WrapperKind::OptionRwLock, Some(inner_ty)) => {
+                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::RwLock<#inner_ty>> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.as_ref(),
+                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::Weak, Some(_inner_ty)) => {
+                            let kp_upgrade_fn = format_ident!("{}_upgrade", kp_fn);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |_root: &mut #name| None,
+                                    )
+                                }
+                                #[inline(always)]
+                                pub fn #kp_upgrade_fn() -> rust_key_paths::KpOwned<'static, #name, <#ty as rust_key_paths::WeakUpgrade>::Strong> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| rust_key_paths::WeakUpgrade::upgrade(&root.#idx_lit),
+                                        |root: &mut #name| rust_key_paths::WeakUpgrade::upgrade(&root.#idx_lit),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::Atomic, None | Some(_)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionAtomic, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.as_ref(),
+                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::String, None) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OptionString, None) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(rust_key_paths::__private::String), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, rust_key_paths::__private::String> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.as_ref(),
+                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::OnceCell, Some(inner_ty)) => {
+                            let kp_once_fn = format_ident!("{}_once", kp_fn);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        |root: &#name| root.#idx_lit.get(),
+                                        |root: &mut #name| root.#idx_lit.get_mut(),
+                                    )
+                                }
+                                #[inline(always)]
+                                pub fn #kp_once_fn() -> rust_key_paths::OnceKp<#name, #ty> {
+                                    rust_key_paths::OnceKp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                            });
+                        }
+                        (WrapperKind::Lazy, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
-                                    rust_key_paths::Kp::new(
-                                        |root: &#name| Some(&root.#idx_lit),
-                                        |root: &mut #name| Some(&mut root.#idx_lit),
-                                    )
-                                }
-                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Arc<std::sync::Mutex<#inner_ty>>> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref(),
-                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                        |root: &#name| Some(core::ops::Deref::deref(&root.#idx_lit)),
+                                        |_root: &mut #name| None,
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::OptionArcMutex, Some(inner_ty)) => {
-                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                        (WrapperKind::OptionOnceCell, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| Some(&root.#idx_lit),
-                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                        |root: &#name| root.#idx_lit.as_ref().and_then(|c| c.get()),
+                                        |root: &mut #name| root.#idx_lit.as_mut().and_then(|c| c.get_mut()),
                                     )
                                 }
-                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Arc<parking_lot::Mutex<#inner_ty>>> {
+                            });
+                        }
+                        (WrapperKind::OptionLazy, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#inner_ty), &segment));
+                            tokens.extend(quote! {
+                                #[inline(always)]
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref(),
-                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                        |root: &#name| root.#idx_lit.as_ref().map(|c| core::ops::Deref::deref(c)),
+                                        |_root: &mut #name| None,
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::OptionStdArcRwLock, Some(inner_ty)) => {
-                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                        (WrapperKind::Cell, Some(inner_ty)) => {
+                            let kp_cell_fn = format_ident!("{}_cell", kp_fn);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| Some(&root.#idx_lit),
                                         |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
-                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Arc<std::sync::RwLock<#inner_ty>>> {
-                                    rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref(),
-                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                #[inline(always)]
+                                pub fn #kp_cell_fn() -> rust_key_paths::CellKp<#name, #inner_ty> {
+                                    rust_key_paths::CellKp::new(
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::OptionArcRwLock, Some(inner_ty)) => {
-                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                        (WrapperKind::OptionCell, Some(inner_ty)) => {
+                            let kp_cell_fn = format_ident!("{}_cell", kp_fn);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| Some(&root.#idx_lit),
                                         |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
-                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Arc<parking_lot::RwLock<#inner_ty>>> {
-                                    rust_key_paths::Kp::new(
+                                #[inline(always)]
+                                pub fn #kp_cell_fn() -> rust_key_paths::CellKp<#name, #inner_ty> {
+                                    rust_key_paths::CellKp::new(
                                         |root: &#name| root.#idx_lit.as_ref(),
                                         |root: &mut #name| root.#idx_lit.as_mut(),
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::OptionStdMutex, Some(inner_ty))
-                        | (WrapperKind::OptionMutex, Some(inner_ty)) => {
-                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                        (WrapperKind::RefCell, Some(_inner_ty))
+                        | (WrapperKind::PhantomData, Some(_inner_ty)) | (WrapperKind::Range, Some(_inner_ty))
+                        | (WrapperKind::OptionRefCell, Some(_inner_ty))
+                        | (WrapperKind::OptionPhantomData, Some(_inner_ty)) | (WrapperKind::OptionRange, Some(_inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| Some(&root.#idx_lit),
                                         |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
-                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::Mutex<#inner_ty>> {
-                                    rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref(),
-                                        |root: &mut #name| root.#idx_lit.as_mut(),
-                                    )
-                                }
                             });
                         }
-                        (WrapperKind::OptionStdRwLock, Some(inner_ty))
-                        | (WrapperKind::OptionRwLock, Some(inner_ty)) => {
-                            let kp_unlocked_fn = format_ident!("f{}_unlocked", idx);
+                        (WrapperKind::Array, Some(inner_ty)) | (WrapperKind::BoxSlice, Some(inner_ty)) => {
+                            let kp_range_fn = format_ident!("f{}_range", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                    pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
                                     rust_key_paths::Kp::new(
                                         |root: &#name| Some(&root.#idx_lit),
                                         |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
-                                pub fn #kp_unlocked_fn() -> rust_key_paths::KpType<'static, #name, std::sync::RwLock<#inner_ty>> {
+                                #[inline(always)]
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref(),
-                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#idx_lit.get_mut(index)),
+                                    )
+                                }
+                                #[inline(always)]
+                                pub fn #kp_range_fn(range: core::ops::Range<usize>) -> rust_key_paths::SliceKp<#name, #inner_ty> {
+                                    rust_key_paths::SliceKp::new(
+                                        |root: &#name| Some(&root.#idx_lit[..]),
+                                        |root: &mut #name| Some(&mut root.#idx_lit[..]),
+                                        range,
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::Weak, Some(_inner_ty)) => {
+                        (WrapperKind::SliceRef, Some(inner_ty)) => {
+                            let kp_range_fn = format_ident!("f{}_range", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2365,9 +4024,25 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         |_root: &mut #name| None,
                                     )
                                 }
+                                #[inline(always)]
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(index)),
+                                        rust_key_paths::__private::Box::new(move |_root: &mut #name| None),
+                                    )
+                                }
+                                #[inline(always)]
+                                pub fn #kp_range_fn(range: core::ops::Range<usize>) -> rust_key_paths::SliceKp<#name, #inner_ty> {
+                                    rust_key_paths::SliceKp::new(
+                                        |root: &#name| Some(root.#idx_lit),
+                                        |_root: &mut #name| None,
+                                        range,
+                                    )
+                                }
                             });
                         }
-                        (WrapperKind::Atomic, None | Some(_)) => {
+                        (WrapperKind::Tuple, None) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2377,19 +4052,42 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     )
                                 }
                             });
+                            for (elem_idx, elem_ty) in extract_tuple_elems(ty).iter().enumerate() {
+                                let elem_lit = syn::Index::from(elem_idx);
+                                let kp_elem_fn = format_ident!("f{}_{}", idx, elem_idx);
+                                tokens.extend(quote! {
+                                    #[inline(always)]
+                                    pub fn #kp_elem_fn() -> rust_key_paths::KpType<'static, #name, #elem_ty> {
+                                        rust_key_paths::Kp::new(
+                                            |root: &#name| Some(&root.#idx_lit.#elem_lit),
+                                            |root: &mut #name| Some(&mut root.#idx_lit.#elem_lit),
+                                        )
+                                    }
+                                });
+                            }
                         }
-                        (WrapperKind::OptionAtomic, Some(inner_ty)) => {
+                        (WrapperKind::SmallVec, Some(inner_ty)) | (WrapperKind::ArrayVec, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref(),
-                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
+                                    )
+                                }
+                                #[inline(always)]
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty> {
+                                    rust_key_paths::Kp::new(
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#idx_lit.get_mut(index)),
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::String, None) => {
+                        (WrapperKind::ImVector, Some(inner_ty)) => {
+                            // im::Vector::get_mut copies shared chunks on write, so it needs T: Clone
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2398,67 +4096,97 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
-                            });
-                        }
-                        (WrapperKind::OptionString, None) => {
-                            tokens.extend(quote! {
                                 #[inline(always)]
-                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, std::string::String> {
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, #inner_ty>
+                                where
+                                    #inner_ty: Clone,
+                                {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref(),
-                                        |root: &mut #name| root.#idx_lit.as_mut(),
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(index)),
+                                        rust_key_paths::__private::Box::new(move |root: &mut #name| root.#idx_lit.get_mut(index)),
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::OnceCell, Some(inner_ty)) => {
+                        (WrapperKind::IndexMap, Some(inner_ty)) | (WrapperKind::ImHashMap, Some(inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.get(),
-                                        |_root: &mut #name| None,
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
                             });
+                            if let Some((key_ty, _)) = extract_map_key_value(ty) {
+                                // im::HashMap::get_mut copies shared nodes on write, so it needs V: Clone
+                                let value_bound = if kind == WrapperKind::ImHashMap {
+                                    quote! { #inner_ty: Clone, }
+                                } else {
+                                    quote! {}
+                                };
+                                tokens.extend(quote! {
+                                    #[inline(always)]
+                                    pub fn #kp_at_fn(key: #key_ty) -> rust_key_paths::KpDynamic<#name, #inner_ty>
+                                    where
+                                        #key_ty: Clone + core::hash::Hash + Eq + 'static,
+                                        #value_bound
+                                    {
+                                        let key2 = key.clone();
+                                        rust_key_paths::Kp::new(
+                                            rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(&key)),
+                                            rust_key_paths::__private::Box::new(move |root: &mut #name| root.#idx_lit.get_mut(&key2)),
+                                        )
+                                    }
+                                });
+                            }
                         }
-                        (WrapperKind::Lazy, Some(inner_ty)) => {
+                        (WrapperKind::Bytes, None) => {
+                            // bytes::Bytes is immutable: _at(i) is read-only
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| Some(root.#idx_lit.get()),
-                                        |_root: &mut #name| None,
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
-                            });
-                        }
-                        (WrapperKind::OptionOnceCell, Some(inner_ty)) => {
-                            tokens.extend(quote! {
                                 #[inline(always)]
-                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                pub fn #kp_at_fn(index: usize) -> rust_key_paths::KpDynamic<#name, u8> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref().and_then(|c| c.get()),
-                                        |_root: &mut #name| None,
+                                        rust_key_paths::__private::Box::new(move |root: &#name| root.#idx_lit.get(index)),
+                                        rust_key_paths::__private::Box::new(move |_root: &mut #name| None),
                                     )
                                 }
                             });
                         }
-                        (WrapperKind::OptionLazy, Some(inner_ty)) => {
+                        (WrapperKind::DashMap, Some(_inner_ty)) => {
+                            // DashMap: _at(key) returns shard-locked guards (requires rust-key-paths `dashmap` feature)
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
-                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
+                                pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
                                     rust_key_paths::Kp::new(
-                                        |root: &#name| root.#idx_lit.as_ref().map(|c| c.get()),
-                                        |_root: &mut #name| None,
+                                        |root: &#name| Some(&root.#idx_lit),
+                                        |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
                             });
+                            if let Some((key_ty, _)) = extract_map_key_value(ty) {
+                                tokens.extend(quote! {
+                                    #[inline(always)]
+                                    pub fn #kp_at_fn(key: #key_ty) -> rust_key_paths::DashMapKp<#name, #ty, #key_ty> {
+                                        rust_key_paths::DashMapKp::new(|root: &#name| Some(&root.#idx_lit), key)
+                                    }
+                                });
+                            }
                         }
-                        (WrapperKind::Cell, Some(_inner_ty)) | (WrapperKind::RefCell, Some(_inner_ty))
-                        | (WrapperKind::PhantomData, Some(_inner_ty)) | (WrapperKind::Range, Some(_inner_ty))
-                        | (WrapperKind::OptionCell, Some(_inner_ty)) | (WrapperKind::OptionRefCell, Some(_inner_ty))
-                        | (WrapperKind::OptionPhantomData, Some(_inner_ty)) | (WrapperKind::OptionRange, Some(_inner_ty)) => {
+                        (WrapperKind::ArcSwap, Some(inner_ty)) => {
+                            // ArcSwap: _load() gives snapshot/store access (requires rust-key-paths `arc_swap` feature)
+                            let kp_load_fn = format_ident!("f{}_load", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2467,9 +4195,14 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         |root: &mut #name| Some(&mut root.#idx_lit),
                                     )
                                 }
+                                #[inline(always)]
+                                pub fn #kp_load_fn() -> rust_key_paths::ArcSwapKp<#name, #inner_ty> {
+                                    rust_key_paths::ArcSwapKp::new(|root: &#name| Some(&root.#idx_lit))
+                                }
                             });
                         }
                         (WrapperKind::Reference, Some(_inner_ty)) => {
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2483,6 +4216,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         (WrapperKind::None, None) => {
                             let get_fn = format_ident!("__kp_get_f{}", idx);
                             let set_fn = format_ident!("__kp_set_f{}", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2495,6 +4229,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                         _ => {
                             let get_fn = format_ident!("__kp_get_f{}", idx);
                             let set_fn = format_ident!("__kp_set_f{}", idx);
+                            tokens.extend(static_kp_const(name, &kp_fn, quote!(#ty), &segment));
                             tokens.extend(quote! {
                                 #[inline(always)]
                                 pub const fn #kp_fn() -> rust_key_paths::KpType<'static, #name, #ty> {
@@ -2505,6 +4240,17 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                             });
                         }
                     }
+
+                    match generate_kp_attr_accessors(name, field, quote!(#idx_lit), &format!("f{}", idx)) {
+                        Ok(extra) => tokens.extend(extra),
+                        Err(err) => tokens.extend(err.to_compile_error()),
+                    }
+                    tokens.extend(generate_each_accessor(name, field, quote!(#idx_lit), &format!("f{}", idx)));
+                    tokens.extend(generate_fill_accessors(name, field, quote!(#idx_lit), &format!("f{}", idx)));
+                    tokens.extend(generate_cow_accessors(name, field, quote!(#idx_lit), &format!("f{}", idx)));
+                    if !packed {
+                        tokens.extend(generate_offset_accessor(name, field, quote!(#idx_lit), &format!("f{}", idx)));
+                    }
                 }
 
                 tokens
@@ -2533,8 +4279,8 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                     fn(MutRoot) -> Option<MutRoot>,
                 >
                 where
-                    Root: std::borrow::Borrow<#name>,
-                    MutRoot: std::borrow::BorrowMut<#name>,
+                    Root: core::borrow::Borrow<#name>,
+                    MutRoot: core::borrow::BorrowMut<#name>,
                 {
                     rust_key_paths::Kp::new(
                         |r: Root| Some(r),
@@ -2672,15 +4418,15 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         }
                                         #[inline(always)]
                                         pub fn #snake_inner() -> rust_key_paths::KpType<'static, #name, #inner_ty>
-                                        where #inner_ty: std::marker::Unpin
+                                        where #inner_ty: core::marker::Unpin
                                         {
                                             rust_key_paths::Kp::new(
                                                 |root: &#name| match root {
-                                                    #name::#v_ident(inner) => Some(std::pin::Pin::as_ref(inner).get_ref()),
+                                                    #name::#v_ident(inner) => Some(core::pin::Pin::as_ref(inner).get_ref()),
                                                     _ => None,
                                                 },
                                                 |root: &mut #name| match root {
-                                                    #name::#v_ident(inner) => Some(std::pin::Pin::as_mut(inner).get_mut()),
+                                                    #name::#v_ident(inner) => Some(core::pin::Pin::as_mut(inner).get_mut()),
                                                     _ => None,
                                                 },
                                             )
@@ -2705,15 +4451,15 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         }
                                         #[inline(always)]
                                         pub fn #snake_inner() -> rust_key_paths::KpType<'static, #name, #inner_ty>
-                                        where #inner_ty: std::marker::Unpin
+                                        where #inner_ty: core::marker::Unpin
                                         {
                                             rust_key_paths::Kp::new(
                                                 |root: &#name| match root {
-                                                    #name::#v_ident(inner) => Some(std::pin::Pin::as_ref(inner).get_ref()),
+                                                    #name::#v_ident(inner) => Some(core::pin::Pin::as_ref(inner).get_ref()),
                                                     _ => None,
                                                 },
                                                 |root: &mut #name| match root {
-                                                    #name::#v_ident(inner) => Some(std::pin::Pin::as_mut(inner).get_mut()),
+                                                    #name::#v_ident(inner) => Some(core::pin::Pin::as_mut(inner).get_mut()),
                                                     _ => None,
                                                 },
                                             )
@@ -2730,7 +4476,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                                     _ => None,
                                                 },
                                                 |root: &mut #name| match root {
-                                                    #name::#v_ident(inner) => std::rc::Rc::get_mut(inner),
+                                                    #name::#v_ident(inner) => rust_key_paths::__private::Rc::get_mut(inner),
                                                     _ => None,
                                                 },
                                             )
@@ -2747,7 +4493,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                                     _ => None,
                                                 },
                                                 |root: &mut #name| match root {
-                                                    #name::#v_ident(inner) => std::sync::Arc::get_mut(inner),
+                                                    #name::#v_ident(inner) => rust_key_paths::__private::Arc::get_mut(inner),
                                                     _ => None,
                                                 },
                                             )
@@ -2922,6 +4668,64 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         }
                                     });
                                 }
+                                (
+                                    WrapperKind::TokioArcOnceCell
+                                    | WrapperKind::OptionTokioArcOnceCell
+                                    | WrapperKind::FuturesArcMutex
+                                    | WrapperKind::OptionFuturesArcMutex
+                                    | WrapperKind::AsyncLockArcMutex
+                                    | WrapperKind::OptionAsyncLockArcMutex
+                                    | WrapperKind::AsyncLockArcRwLock
+                                    | WrapperKind::OptionAsyncLockArcRwLock,
+                                    Some(inner_ty),
+                                ) => {
+                                    let (access, is_option, guarded) = async_lock_access(kind, &inner_ty);
+                                    let snake_async = format_ident!("{}_async", snake);
+                                    let (lock_ty, prev) = if is_option {
+                                        (
+                                            first_type_arg(field_ty).unwrap(),
+                                            quote! {
+                                                rust_key_paths::Kp::new(
+                                                    |root: &#name| match root { #name::#v_ident(inner) => inner.as_ref(), _ => None },
+                                                    |root: &mut #name| match root { #name::#v_ident(inner) => inner.as_mut(), _ => None },
+                                                )
+                                            },
+                                        )
+                                    } else {
+                                        (
+                                            field_ty.clone(),
+                                            quote! {
+                                                rust_key_paths::Kp::new(
+                                                    |root: &#name| match root { #name::#v_ident(inner) => Some(inner), _ => None },
+                                                    |root: &mut #name| match root { #name::#v_ident(inner) => Some(inner), _ => None },
+                                                )
+                                            },
+                                        )
+                                    };
+                                    let (async_ty, next) = async_lock_kp(name, &lock_ty, &inner_ty, &access, guarded);
+                                    tokens.extend(quote! {
+                                        #[inline(always)]
+                                        pub fn #snake() -> rust_key_paths::KpType<'static, #name, #field_ty> {
+                                            rust_key_paths::Kp::new(
+                                                |root: &#name| match root {
+                                                    #name::#v_ident(inner) => Some(inner),
+                                                    _ => None,
+                                                },
+                                                |root: &mut #name| match root {
+                                                    #name::#v_ident(inner) => Some(inner),
+                                                    _ => None,
+                                                },
+                                            )
+                                        }
+                                        pub fn #snake_async() -> #async_ty {
+                                            rust_key_paths::async_lock::AsyncLockKp::new(
+                                                #prev,
+                                                <#access>::new(),
+                                                #next,
+                                            )
+                                        }
+                                    });
+                                }
                                 (WrapperKind::OptionTokioArcMutex, Some(inner_ty)) => {
                                     let snake_async = format_ident!("{}_async", snake);
                                     tokens.extend(quote! {
@@ -3144,11 +4948,11 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         pub fn #snake() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                             rust_key_paths::Kp::new(
                                                 |root: &#name| match root {
-                                                    #name::#v_ident(inner) => Some(std::ops::Deref::deref(inner)),
+                                                    #name::#v_ident(inner) => Some(core::ops::Deref::deref(inner)),
                                                     _ => None,
                                                 },
                                                 |root: &mut #name| match root {
-                                                    #name::#v_ident(inner) => Some(std::ops::DerefMut::deref_mut(inner)),
+                                                    #name::#v_ident(inner) => Some(core::ops::DerefMut::deref_mut(inner)),
                                                     _ => None,
                                                 },
                                             )
@@ -3198,6 +5002,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     });
                                 }
                                 (WrapperKind::Weak, Some(_inner_ty)) => {
+                                    let snake_upgrade = format_ident!("{}_upgrade", snake);
                                     tokens.extend(quote! {
                                         #[inline(always)]
                                         pub fn #snake() -> rust_key_paths::KpType<'static, #name, #field_ty> {
@@ -3209,6 +5014,19 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                                 |_root: &mut #name| None,
                                             )
                                         }
+                                        #[inline(always)]
+                                        pub fn #snake_upgrade() -> rust_key_paths::KpOwned<'static, #name, <#field_ty as rust_key_paths::WeakUpgrade>::Strong> {
+                                            rust_key_paths::Kp::new(
+                                                |root: &#name| match root {
+                                                    #name::#v_ident(inner) => rust_key_paths::WeakUpgrade::upgrade(inner),
+                                                    _ => None,
+                                                },
+                                                |root: &mut #name| match root {
+                                                    #name::#v_ident(inner) => rust_key_paths::WeakUpgrade::upgrade(inner),
+                                                    _ => None,
+                                                },
+                                            )
+                                        }
                                     });
                                 }
                                 (WrapperKind::Cow, Some(inner_ty)) => {
@@ -3251,11 +5069,11 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         pub fn #snake() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                             rust_key_paths::Kp::new(
                                                 |root: &#name| match root {
-                                                    #name::#v_ident(inner) => inner.as_ref().map(|t| std::ops::Deref::deref(t)),
+                                                    #name::#v_ident(inner) => inner.as_ref().map(|t| core::ops::Deref::deref(t)),
                                                     _ => None,
                                                 },
                                                 |root: &mut #name| match root {
-                                                    #name::#v_ident(inner) => inner.as_mut().map(|t| std::ops::DerefMut::deref_mut(t)),
+                                                    #name::#v_ident(inner) => inner.as_mut().map(|t| core::ops::DerefMut::deref_mut(t)),
                                                     _ => None,
                                                 },
                                             )
@@ -3296,7 +5114,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                 (WrapperKind::OptionString, None) => {
                                     tokens.extend(quote! {
                                         #[inline(always)]
-                                        pub fn #snake() -> rust_key_paths::KpType<'static, #name, std::string::String> {
+                                        pub fn #snake() -> rust_key_paths::KpType<'static, #name, rust_key_paths::__private::String> {
                                             rust_key_paths::Kp::new(
                                                 |root: &#name| match root {
                                                     #name::#v_ident(inner) => inner.as_ref(),
@@ -3311,6 +5129,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                     });
                                 }
                                 (WrapperKind::OnceCell, Some(inner_ty)) => {
+                                    let snake_once = format_ident!("{}_once", snake);
                                     tokens.extend(quote! {
                                         #[inline(always)]
                                         pub fn #snake() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
@@ -3319,7 +5138,17 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                                     #name::#v_ident(inner) => inner.get(),
                                                     _ => None,
                                                 },
-                                                |_root: &mut #name| None,
+                                                |root: &mut #name| match root {
+                                                    #name::#v_ident(inner) => inner.get_mut(),
+                                                    _ => None,
+                                                },
+                                            )
+                                        }
+                                        #[inline(always)]
+                                        pub fn #snake_once() -> rust_key_paths::OnceKp<#name, #field_ty> {
+                                            rust_key_paths::OnceKp::new(
+                                                |root: &#name| match root { #name::#v_ident(inner) => Some(inner), _ => None },
+                                                |root: &mut #name| match root { #name::#v_ident(inner) => Some(inner), _ => None },
                                             )
                                         }
                                     });
@@ -3330,7 +5159,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         pub fn #snake() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                             rust_key_paths::Kp::new(
                                                 |root: &#name| match root {
-                                                    #name::#v_ident(inner) => Some(inner.get()),
+                                                    #name::#v_ident(inner) => Some(core::ops::Deref::deref(inner)),
                                                     _ => None,
                                                 },
                                                 |_root: &mut #name| None,
@@ -3347,7 +5176,10 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                                     #name::#v_ident(inner) => inner.as_ref().and_then(|c| c.get()),
                                                     _ => None,
                                                 },
-                                                |_root: &mut #name| None,
+                                                |root: &mut #name| match root {
+                                                    #name::#v_ident(inner) => inner.as_mut().and_then(|c| c.get_mut()),
+                                                    _ => None,
+                                                },
                                             )
                                         }
                                     });
@@ -3358,7 +5190,7 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         pub fn #snake() -> rust_key_paths::KpType<'static, #name, #inner_ty> {
                                             rust_key_paths::Kp::new(
                                                 |root: &#name| match root {
-                                                    #name::#v_ident(inner) => inner.as_ref().map(|c| c.get()),
+                                                    #name::#v_ident(inner) => inner.as_ref().map(|c| core::ops::Deref::deref(c)),
                                                     _ => None,
                                                 },
                                                 |_root: &mut #name| None,
@@ -3366,9 +5198,39 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
                                         }
                                     });
                                 }
-                                (WrapperKind::Cell, Some(_inner_ty)) | (WrapperKind::RefCell, Some(_inner_ty))
+                                (WrapperKind::Cell, Some(inner_ty)) | (WrapperKind::OptionCell, Some(inner_ty)) => {
+                                    let snake_cell = format_ident!("{}_cell", snake);
+                                    let (cell_get, cell_set) = if kind == WrapperKind::OptionCell {
+                                        (quote!(inner.as_ref()), quote!(inner.as_mut()))
+                                    } else {
+                                        (quote!(Some(inner)), quote!(Some(inner)))
+                                    };
+                                    tokens.extend(quote! {
+                                        #[inline(always)]
+                                        pub fn #snake() -> rust_key_paths::KpType<'static, #name, #field_ty> {
+                                            rust_key_paths::Kp::new(
+                                                |root: &#name| match root {
+                                                    #name::#v_ident(inner) => Some(inner),
+                                                    _ => None,
+                                                },
+                                                |root: &mut #name| match root {
+                                                    #name::#v_ident(inner) => Some(inner),
+                                                    _ => None,
+                                                },
+                                            )
+                                        }
+                                        #[inline(always)]
+                                        pub fn #snake_cell() -> rust_key_paths::CellKp<#name, #inner_ty> {
+                                            rust_key_paths::CellKp::new(
+                                                |root: &#name| match root { #name::#v_ident(inner) => #cell_get, _ => None },
+                                                |root: &mut #name| match root { #name::#v_ident(inner) => #cell_set, _ => None },
+                                            )
+                                        }
+                                    });
+                                }
+                                (WrapperKind::RefCell, Some(_inner_ty))
                                 | (WrapperKind::PhantomData, Some(_inner_ty)) | (WrapperKind::Range, Some(_inner_ty))
-                                | (WrapperKind::OptionCell, Some(_inner_ty)) | (WrapperKind::OptionRefCell, Some(_inner_ty))
+                                | (WrapperKind::OptionRefCell, Some(_inner_ty))
                                 | (WrapperKind::OptionPhantomData, Some(_inner_ty)) | (WrapperKind::OptionRange, Some(_inner_ty)) => {
                                     tokens.extend(quote! {
                                         #[inline(always)]
@@ -3471,14 +5333,21 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
         impl #name {
             #methods
         }
+
+        #transparent
     };
 
     TokenStream::from(expanded)
 }
 
-/// Derive macro that generates `partial_kps() -> Vec<PKp<Self>>` returning all field/variant keypaths.
+/// Derive macro that generates `partial_kps() -> Vec<PKp<Self>>` returning all field/variant keypaths,
+/// and `sync_partial_kps() -> Vec<SyncPKp<Self>>` with the same keypaths as `Send + Sync`.
 /// **Requires `#[derive(Kp)]`** so the keypath accessor methods exist.
 ///
+/// With `#[pkp(debug, display, eq, hash, clone, serialize)]` (any subset) it also generates
+/// `cap_partial_kps() -> Vec<CapPKp<Self>>`; every field type must implement the listed traits.
+/// `serialize` needs the `serde` feature of `rust-key-paths`.
+///
 /// For structs: returns keypaths for each field. For enums: returns keypaths for each variant
 /// (using the same methods Kp generates, e.g. `some_variant()`).
 ///
@@ -3496,12 +5365,33 @@ pub fn derive_keypaths(input: TokenStream) -> TokenStream {
 /// let kps = Person::partial_kps();
 /// assert_eq!(kps.len(), 2);
 /// ```
-#[proc_macro_derive(Pkp)]
+#[proc_macro_derive(Pkp, attributes(pkp))]
 pub fn derive_partial_keypaths(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
 
-    let kp_calls = match &input.data {
+    // `#[pkp(debug, display, eq, hash, clone, serialize)]`: capabilities for `cap_partial_kps()`
+    let mut caps = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("pkp")) {
+        let parsed = attr.parse_nested_meta(|meta| {
+            let cap = match meta.path.get_ident().map(|i| i.to_string()).as_deref() {
+                Some("debug") => quote!(debug),
+                Some("display") => quote!(display),
+                Some("eq") => quote!(eq),
+                Some("hash") => quote!(hash),
+                Some("clone") => quote!(clone_value),
+                Some("serialize") => quote!(serialize),
+                _ => return Err(meta.error("unsupported pkp capability")),
+            };
+            caps.push(cap);
+            Ok(())
+        });
+        if let Err(err) = parsed {
+            return err.to_compile_error().into();
+        }
+    }
+
+    let kp_fns: Vec<proc_macro2::TokenStream> = match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
             Fields::Named(fields_named) => {
                 let calls: Vec<_> = fields_named
@@ -3509,21 +5399,21 @@ pub fn derive_partial_keypaths(input: TokenStream) -> TokenStream {
                     .iter()
                     .filter_map(|f| f.ident.as_ref())
                     .map(|field_ident| {
-                        quote! { rust_key_paths::PKp::new(Self::#field_ident()) }
+                        quote! { Self::#field_ident() }
                     })
                     .collect();
-                quote! { #(#calls),* }
+                calls
             }
             Fields::Unnamed(unnamed) => {
                 let calls: Vec<_> = (0..unnamed.unnamed.len())
                     .map(|idx| {
                         let kp_fn = format_ident!("f{}", idx);
-                        quote! { rust_key_paths::PKp::new(Self::#kp_fn()) }
+                        quote! { Self::#kp_fn() }
                     })
                     .collect();
-                quote! { #(#calls),* }
+                calls
             }
-            Fields::Unit => quote! {},
+            Fields::Unit => Vec::new(),
         },
         Data::Enum(data_enum) => {
             let calls: Vec<_> = data_enum
@@ -3532,10 +5422,10 @@ pub fn derive_partial_keypaths(input: TokenStream) -> TokenStream {
                 .map(|variant| {
                     let v_ident = &variant.ident;
                     let snake = format_ident!("{}", to_snake_case(&v_ident.to_string()));
-                    quote! { rust_key_paths::PKp::new(Self::#snake()) }
+                    quote! { Self::#snake() }
                 })
                 .collect();
-            quote! { #(#calls),* }
+            calls
         }
         Data::Union(_) => {
             return syn::Error::new(
@@ -3547,20 +5437,44 @@ pub fn derive_partial_keypaths(input: TokenStream) -> TokenStream {
         }
     };
 
+    let cap_kps = if caps.is_empty() {
+        proc_macro2::TokenStream::new()
+    } else {
+        let cap_chain = quote! { #(.#caps())* };
+        quote! {
+            impl #name {
+                /// Partial keypaths that keep the leaf capabilities listed in `#[pkp(...)]`.
+                #[inline(always)]
+                pub fn cap_partial_kps() -> Vec<rust_key_paths::CapPKp<#name>> {
+                    vec![#(rust_key_paths::CapPKp::builder(#kp_fns) #cap_chain .build()),*]
+                }
+            }
+        }
+    };
+
     let expanded = quote! {
         impl #name {
             /// Returns a vec of all field keypaths as partial keypaths (type-erased).
             #[inline(always)]
             pub fn partial_kps() -> Vec<rust_key_paths::PKp<#name>> {
-                vec![#kp_calls]
+                vec![#(rust_key_paths::PKp::new(#kp_fns)),*]
+            }
+
+            /// Like `partial_kps()`, but `Send + Sync` (for registries, rayon and tokio tasks).
+            #[inline(always)]
+            pub fn sync_partial_kps() -> Vec<rust_key_paths::SyncPKp<#name>> {
+                vec![#(rust_key_paths::SyncPKp::new(#kp_fns)),*]
             }
         }
+
+        #cap_kps
     };
 
     TokenStream::from(expanded)
 }
 
-/// Derive macro that generates `any_kps() -> Vec<AKp>` returning all field/variant keypaths as any keypaths.
+/// Derive macro that generates `any_kps() -> Vec<AKp>` returning all field/variant keypaths as any keypaths,
+/// and `sync_any_kps() -> Vec<SyncAKp>` with the same keypaths as `Send + Sync`.
 /// **Requires `#[derive(Kp)]`** so the keypath accessor methods exist.
 /// AKp type-erases both Root and Value, enabling heterogeneous collections of keypaths.
 ///
@@ -3589,7 +5503,7 @@ pub fn derive_any_keypaths(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
 
-    let kp_calls = match &input.data {
+    let kp_fns: Vec<proc_macro2::TokenStream> = match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
             Fields::Named(fields_named) => {
                 let calls: Vec<_> = fields_named
@@ -3597,21 +5511,21 @@ pub fn derive_any_keypaths(input: TokenStream) -> TokenStream {
                     .iter()
                     .filter_map(|f| f.ident.as_ref())
                     .map(|field_ident| {
-                        quote! { rust_key_paths::AKp::new(Self::#field_ident()) }
+                        quote! { Self::#field_ident() }
                     })
                     .collect();
-                quote! { #(#calls),* }
+                calls
             }
             Fields::Unnamed(unnamed) => {
                 let calls: Vec<_> = (0..unnamed.unnamed.len())
                     .map(|idx| {
                         let kp_fn = format_ident!("f{}", idx);
-                        quote! { rust_key_paths::AKp::new(Self::#kp_fn()) }
+                        quote! { Self::#kp_fn() }
                     })
                     .collect();
-                quote! { #(#calls),* }
+                calls
             }
-            Fields::Unit => quote! {},
+            Fields::Unit => Vec::new(),
         },
         Data::Enum(data_enum) => {
             let calls: Vec<_> = data_enum
@@ -3620,10 +5534,10 @@ pub fn derive_any_keypaths(input: TokenStream) -> TokenStream {
                 .map(|variant| {
                     let v_ident = &variant.ident;
                     let snake = format_ident!("{}", to_snake_case(&v_ident.to_string()));
-                    quote! { rust_key_paths::AKp::new(Self::#snake()) }
+                    quote! { Self::#snake() }
                 })
                 .collect();
-            quote! { #(#calls),* }
+            calls
         }
         Data::Union(_) => {
             return syn::Error::new(
@@ -3640,7 +5554,13 @@ pub fn derive_any_keypaths(input: TokenStream) -> TokenStream {
             /// Returns a vec of all field keypaths as any keypaths (fully type-erased).
             #[inline(always)]
             pub fn any_kps() -> Vec<rust_key_paths::AKp> {
-                vec![#kp_calls]
+                vec![#(rust_key_paths::AKp::new(#kp_fns)),*]
+            }
+
+            /// Like `any_kps()`, but `Send + Sync` (for registries, rayon and tokio tasks).
+            #[inline(always)]
+            pub fn sync_any_kps() -> Vec<rust_key_paths::SyncAKp> {
+                vec![#(rust_key_paths::SyncAKp::new(#kp_fns)),*]
             }
         }
     };
@@ -0,0 +1,130 @@
+use key_paths_derive::{Akp, Kp, Pkp};
+use rust_key_paths::{KpType, SliceKp};
+
+#[derive(Kp, Pkp, Akp)]
+struct Record {
+    samples: [f64; 4],
+    history: Box<[u32]>,
+    labels: &'static [&'static str],
+    position: (i32, i32, String),
+}
+
+#[derive(Kp)]
+struct Pair([u8; 3], (bool, char));
+
+#[derive(Kp)]
+enum Shape {
+    Triangle([f32; 3]),
+    Point((i32, i32)),
+}
+
+fn record() -> Record {
+    Record {
+        samples: [1.0, 2.0, 3.0, 4.0],
+        history: vec![10, 20, 30].into_boxed_slice(),
+        labels: &["a", "b", "c"],
+        position: (1, 2, "origin".to_string()),
+    }
+}
+
+#[test]
+fn test_array_at_and_range() {
+    let mut r = record();
+
+    // Whole-field keypath is unchanged
+    let _typed: KpType<'static, Record, [f64; 4]> = Record::samples();
+
+    assert_eq!(Record::samples_at(2).get(&r), Some(&3.0));
+    assert_eq!(Record::samples_at(4).get(&r), None);
+    *Record::samples_at(0).get_mut(&mut r).unwrap() = 0.5;
+    assert_eq!(r.samples[0], 0.5);
+
+    let window: SliceKp<Record, f64> = Record::samples_range(1..3);
+    assert_eq!(window.get(&r), Some(&[2.0, 3.0][..]));
+    window.get_mut(&mut r).unwrap().fill(0.0);
+    assert_eq!(r.samples, [0.5, 0.0, 0.0, 4.0]);
+
+    // Out-of-bounds windows return None instead of panicking
+    assert_eq!(Record::samples_range(3..9).get(&r), None);
+}
+
+#[test]
+fn test_boxed_slice_at_and_range() {
+    let mut r = record();
+
+    assert_eq!(Record::history_at(1).get(&r), Some(&20));
+    *Record::history_at(1).get_mut(&mut r).unwrap() += 1;
+    assert_eq!(Record::history_range(0..2).get(&r), Some(&[10, 21][..]));
+}
+
+#[test]
+fn test_slice_ref_is_read_only() {
+    let mut r = record();
+
+    assert_eq!(Record::labels_at(2).get(&r), Some(&"c"));
+    assert!(Record::labels_at(2).get_mut(&mut r).is_none());
+    assert_eq!(Record::labels_range(0..2).get(&r), Some(&["a", "b"][..]));
+    assert!(Record::labels_range(0..2).get_mut(&mut r).is_none());
+}
+
+#[test]
+fn test_tuple_elements() {
+    let mut r = record();
+
+    let x: KpType<'static, Record, i32> = Record::position_0();
+    assert_eq!(x.get(&r), Some(&1));
+    assert_eq!(Record::position_2().get(&r).map(String::as_str), Some("origin"));
+    *Record::position_1().get_mut(&mut r).unwrap() = 5;
+    assert_eq!(r.position.1, 5);
+}
+
+#[test]
+fn test_tuple_struct_fields() {
+    let mut p = Pair([1, 2, 3], (true, 'x'));
+
+    assert_eq!(Pair::f0_at(1).get(&p), Some(&2));
+    assert_eq!(Pair::f0_range(1..3).get(&p), Some(&[2, 3][..]));
+    assert_eq!(Pair::f1_1().get(&p), Some(&'x'));
+    *Pair::f1_0().get_mut(&mut p).unwrap() = false;
+    assert!(!p.1.0);
+}
+
+#[test]
+fn test_enum_variants_keep_whole_payload() {
+    let shape = Shape::Triangle([0.0, 1.0, 2.0]);
+    assert_eq!(Shape::triangle().get(&shape), Some(&[0.0, 1.0, 2.0]));
+    assert_eq!(Shape::point().get(&shape), None);
+
+    let point = Shape::Point((3, 4));
+    assert_eq!(Shape::point().get(&point), Some(&(3, 4)));
+}
+
+#[test]
+fn test_partial_and_any_kps_include_new_kinds() {
+    assert_eq!(Record::partial_kps().len(), 4);
+    assert_eq!(Record::any_kps().len(), 4);
+}
+
+#[derive(Kp)]
+struct View<'a> {
+    data: &'a [u32],
+    name: &'a str,
+}
+
+#[test]
+fn test_borrowed_slice_with_real_lifetime() {
+    let owned = vec![5, 6, 7];
+    let mut view = View {
+        data: &owned,
+        name: "window",
+    };
+
+    assert_eq!(View::data_at(2).get(&view), Some(&7));
+    assert_eq!(View::data_at(3).get(&view), None);
+    assert_eq!(View::data_range(0..2).get(&view), Some(&[5, 6][..]));
+    assert_eq!(View::data_range(2..5).get(&view), None);
+
+    // Borrowed slices stay read-only
+    assert!(View::data_range(0..1).get_mut(&mut view).is_none());
+    assert_eq!(view.name, "window");
+}
@@ -0,0 +1,133 @@
+//! Run with `cargo test -p key-paths-derive --all-features`.
+#![cfg(all(feature = "futures", feature = "async_lock"))]
+
+use std::sync::Arc;
+
+use key_paths_derive::Kp;
+
+#[derive(Kp)]
+struct Service {
+    hits: Arc<futures_util::lock::Mutex<u32>>,
+    name: Arc<async_lock::Mutex<String>>,
+    tags: Arc<async_lock::RwLock<Vec<String>>>,
+    config: Arc<tokio::sync::OnceCell<String>>,
+    backup: Option<Arc<async_lock::RwLock<u32>>>,
+}
+
+#[derive(Kp)]
+struct Counter(
+    Arc<futures_util::lock::Mutex<i64>>,
+    Option<Arc<tokio::sync::OnceCell<u8>>>,
+);
+
+#[derive(Kp)]
+enum Slot {
+    Shared(Arc<async_lock::Mutex<i32>>),
+    Lazy(Option<Arc<tokio::sync::OnceCell<String>>>),
+    Empty,
+}
+
+fn service() -> Service {
+    Service {
+        hits: Arc::new(futures_util::lock::Mutex::new(0)),
+        name: Arc::new(async_lock::Mutex::new("api".to_string())),
+        tags: Arc::new(async_lock::RwLock::new(vec!["a".to_string()])),
+        config: Arc::new(tokio::sync::OnceCell::new()),
+        backup: None,
+    }
+}
+
+#[tokio::test]
+async fn futures_and_async_lock_fields() {
+    let mut svc = service();
+
+    *Service::hits_async().get_mut(&mut svc).await.unwrap() += 2;
+    assert_eq!(Service::hits_async().get(&svc).await.as_deref(), Some(&2));
+
+    Service::name_async()
+        .get_mut(&mut svc)
+        .await
+        .unwrap()
+        .push_str("-v2");
+    assert_eq!(
+        Service::name_async().get(&svc).await.as_deref().map(String::as_str),
+        Some("api-v2")
+    );
+
+    Service::tags_async()
+        .get_mut(&mut svc)
+        .await
+        .unwrap()
+        .push("b".to_string());
+    assert_eq!(
+        Service::tags_async().get(&svc).await.as_deref().map(Vec::len),
+        Some(2)
+    );
+
+    // The plain accessor still points at the Arc itself
+    assert!(Service::hits().get(&svc).is_some());
+}
+
+#[tokio::test]
+async fn guards_hold_the_lock() {
+    let mut svc = service();
+
+    let hits = Service::hits_async().get_mut(&mut svc).await.unwrap();
+    assert!(svc.hits.try_lock().is_none());
+    drop(hits);
+    assert!(svc.hits.try_lock().is_some());
+
+    let tags = Service::tags_async().get(&svc).await.unwrap();
+    assert!(svc.tags.try_read().is_some());
+    assert!(svc.tags.try_write().is_none());
+    drop(tags);
+
+    let tags = Service::tags_async().get_mut(&mut svc).await.unwrap();
+    assert!(svc.tags.try_read().is_none());
+    drop(tags);
+    assert!(svc.tags.try_write().is_some());
+}
+
+#[tokio::test]
+async fn optional_lock_field() {
+    let mut svc = service();
+    assert!(Service::backup_async().get(&svc).await.is_none());
+
+    svc.backup = Some(Arc::new(async_lock::RwLock::new(1)));
+    *Service::backup_async().get_mut(&mut svc).await.unwrap() = 7;
+    assert_eq!(Service::backup_async().get(&svc).await.as_deref(), Some(&7));
+}
+
+#[tokio::test]
+async fn tokio_once_cell_is_read_only() {
+    let mut svc = service();
+    assert!(Service::config_async().get(&svc).await.is_none());
+
+    svc.config.set("prod".to_string()).unwrap();
+    assert_eq!(
+        Service::config_async().get(&svc).await.map(String::as_str),
+        Some("prod")
+    );
+    assert!(Service::config_async().get_mut(&mut svc).await.is_none());
+}
+
+#[tokio::test]
+async fn tuple_struct_and_enum() {
+    let mut counter = Counter(Arc::new(futures_util::lock::Mutex::new(-1)), None);
+    *Counter::f0_async().get_mut(&mut counter).await.unwrap() -= 1;
+    assert_eq!(Counter::f0_async().get(&counter).await.as_deref(), Some(&-2));
+    assert!(Counter::f1_async().get(&counter).await.is_none());
+
+    let mut slot = Slot::Shared(Arc::new(async_lock::Mutex::new(3)));
+    *Slot::shared_async().get_mut(&mut slot).await.unwrap() *= 2;
+    assert_eq!(Slot::shared_async().get(&slot).await.as_deref(), Some(&6));
+    assert!(Slot::lazy_async().get(&slot).await.is_none());
+
+    let cell = tokio::sync::OnceCell::new_with(Some("ready".to_string()));
+    let slot = Slot::Lazy(Some(Arc::new(cell)));
+    assert_eq!(
+        Slot::lazy_async().get(&slot).await.map(String::as_str),
+        Some("ready")
+    );
+    assert!(Slot::shared_async().get(&Slot::Empty).await.is_none());
+}
@@ -0,0 +1,85 @@
+use key_paths_derive::{Kp, Pkp};
+use serde::Serialize;
+
+#[derive(Kp, Pkp)]
+#[pkp(debug, eq, hash, clone, serialize)]
+struct Profile {
+    name: String,
+    tags: Vec<String>,
+    score: u32,
+}
+
+#[derive(Kp, Pkp)]
+#[pkp(display)]
+#[pkp(eq)]
+struct Label(String);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+struct Point {
+    x: i32,
+}
+
+#[derive(Kp, Pkp)]
+#[pkp(debug, clone, serialize)]
+enum Shape {
+    Dot(Point),
+    Empty,
+}
+
+fn profile(name: &str, score: u32) -> Profile {
+    Profile {
+        name: name.to_string(),
+        tags: vec!["a".to_string()],
+        score,
+    }
+}
+
+#[test]
+fn test_cap_partial_kps_debug_eq_hash() {
+    let kps = Profile::cap_partial_kps();
+    assert_eq!(kps.len(), 3);
+
+    let a = profile("ada", 1);
+    let b = profile("bob", 1);
+    let printed: Vec<_> = kps.iter().map(|kp| kp.debug(&a).unwrap()).collect();
+    assert_eq!(printed, vec!["\"ada\"", "[\"a\"]", "1"]);
+
+    let changed: Vec<_> = kps.iter().map(|kp| kp.eq(&a, &b) == Some(false)).collect();
+    assert_eq!(changed, vec![true, false, false]);
+
+    assert_eq!(kps[2].hash_value(&a), kps[2].hash_value(&b));
+    assert_ne!(kps[0].hash_value(&a), kps[0].hash_value(&b));
+    // Not requested in #[pkp(...)]
+    assert_eq!(kps[2].display(&a), None);
+}
+
+#[test]
+fn test_cap_partial_kps_clone_and_json() {
+    let kps = Profile::cap_partial_kps();
+    let a = profile("ada", 7);
+    let mut b = profile("bob", 1);
+
+    for kp in &kps {
+        kp.set_any(&mut b, kp.clone_value(&a).unwrap()).unwrap();
+    }
+    assert_eq!(b.name, "ada");
+    assert_eq!(b.score, 7);
+
+    assert_eq!(kps[1].to_json(&a), Some(serde_json::json!(["a"])));
+    assert_eq!(kps[2].to_json(&a), Some(serde_json::json!(7)));
+}
+
+#[test]
+fn test_cap_partial_kps_tuple_and_enum() {
+    let label = Label("hi".to_string());
+    let kps = Label::cap_partial_kps();
+    assert_eq!(kps[0].display(&label).as_deref(), Some("hi"));
+    assert_eq!(kps[0].eq(&label, &Label("hi".to_string())), Some(true));
+    assert_eq!(kps[0].debug(&label), None);
+
+    let kps = Shape::cap_partial_kps();
+    let dot = Shape::Dot(Point { x: 3 });
+    assert_eq!(kps[0].debug(&dot).as_deref(), Some("Point { x: 3 }"));
+    assert_eq!(kps[0].to_json(&dot), Some(serde_json::json!({ "x": 3 })));
+    assert_eq!(kps[0].debug(&Shape::Empty), None);
+}
@@ -0,0 +1,170 @@
+use std::cell::{Cell, OnceCell};
+use std::rc::Rc;
+use std::sync::{Arc, LazyLock, OnceLock, Weak};
+
+use key_paths_derive::Kp;
+use rust_key_paths::{KpOwned, KpType};
+
+static GREETING: LazyLock<String> = LazyLock::new(|| "hello".to_string());
+
+#[derive(Kp)]
+struct Node {
+    parent: Weak<String>,
+    sibling: std::rc::Weak<i32>,
+    hits: Cell<u32>,
+    maybe_hits: Option<Cell<u32>>,
+    name: OnceLock<String>,
+    local: OnceCell<i32>,
+    table: LazyLock<Vec<u8>>,
+}
+
+#[derive(Kp)]
+struct Wrapped(Cell<i32>, OnceLock<String>, Weak<String>, Option<Cell<u8>>);
+
+#[derive(Kp)]
+enum Link {
+    Parent(Weak<String>),
+    Lazy(LazyLock<String>),
+    Hits(Cell<u32>),
+    MaybeHits(Option<Cell<u32>>),
+    Label(OnceLock<String>),
+}
+
+fn node(parent: &Arc<String>, sibling: &Rc<i32>) -> Node {
+    Node {
+        parent: Arc::downgrade(parent),
+        sibling: Rc::downgrade(sibling),
+        hits: Cell::new(1),
+        maybe_hits: None,
+        name: OnceLock::new(),
+        local: OnceCell::new(),
+        table: LazyLock::new(|| vec![1, 2, 3]),
+    }
+}
+
+#[test]
+fn test_weak_upgrade_yields_owned_strong() {
+    let parent = Arc::new("root".to_string());
+    let sibling = Rc::new(7);
+    let n = node(&parent, &sibling);
+
+    let kp: KpOwned<'static, Node, Arc<String>> = Node::parent_upgrade();
+    let upgraded = kp.get(&n).unwrap();
+    assert!(Arc::ptr_eq(&upgraded, &parent));
+
+    // std::rc::Weak upgrades to Rc
+    let kp: KpOwned<'static, Node, Rc<i32>> = Node::sibling_upgrade();
+    assert_eq!(kp.get(&n).as_deref(), Some(&7));
+
+    // Dropped target: upgrade returns None
+    drop(upgraded);
+    drop(parent);
+    assert!(Node::parent_upgrade().get(&n).is_none());
+}
+
+#[test]
+fn test_cell_get_set_through_shared_ref() {
+    let parent = Arc::new(String::new());
+    let sibling = Rc::new(0);
+    let mut n = node(&parent, &sibling);
+
+    let hits = Node::hits_cell();
+    assert_eq!(hits.get(&n), Some(1));
+    hits.set(&n, 5).unwrap();
+    assert_eq!(n.hits.get(), 5);
+    assert_eq!(hits.replace(&n, 6), Ok(5));
+
+    *hits.get_mut(&mut n).unwrap() += 1;
+    assert_eq!(hits.get(&n), Some(7));
+
+    // Container keypath is unchanged
+    let _typed: KpType<'static, Node, Cell<u32>> = Node::hits();
+
+    // Option<Cell<T>>: None until present
+    let maybe = Node::maybe_hits_cell();
+    assert_eq!(maybe.get(&n), None);
+    assert_eq!(maybe.set(&n, 3), Err(3));
+    n.maybe_hits = Some(Cell::new(0));
+    maybe.set(&n, 3).unwrap();
+    assert_eq!(maybe.get(&n), Some(3));
+}
+
+#[test]
+fn test_once_lock_get_or_init() {
+    let parent = Arc::new(String::new());
+    let sibling = Rc::new(0);
+    let mut n = node(&parent, &sibling);
+
+    // name() reads the value once initialized
+    assert_eq!(Node::name().get(&n), None);
+    let name = Node::name_once();
+    let value = name.get_or_init(&n, || "alice".to_string());
+    assert_eq!(value.map(String::as_str), Some("alice"));
+    assert_eq!(Node::name().get(&n).map(String::as_str), Some("alice"));
+    assert!(name.set(&n, "bob".to_string()).is_err());
+
+    // Setter path is the initialized value
+    Node::name().get_mut(&mut n).unwrap().push('!');
+    assert_eq!(name.get(&n).map(String::as_str), Some("alice!"));
+
+    // std::cell::OnceCell works the same way
+    let local = Node::local_once();
+    local.set(&n, 9).unwrap();
+    assert_eq!(Node::local().get(&n), Some(&9));
+}
+
+#[test]
+fn test_lazy_lock_forces_inner_value() {
+    let parent = Arc::new(String::new());
+    let sibling = Rc::new(0);
+    let n = node(&parent, &sibling);
+
+    let kp: KpType<'static, Node, Vec<u8>> = Node::table();
+    assert_eq!(kp.get(&n), Some(&vec![1, 2, 3]));
+
+    let link = Link::Lazy(LazyLock::new(|| GREETING.clone()));
+    assert_eq!(Link::lazy().get(&link).map(String::as_str), Some("hello"));
+}
+
+#[test]
+fn test_tuple_and_enum_variants() {
+    let parent = Arc::new("p".to_string());
+    let w = Wrapped(
+        Cell::new(1),
+        OnceLock::new(),
+        Arc::downgrade(&parent),
+        Some(Cell::new(0)),
+    );
+
+    Wrapped::f0_cell().set(&w, 2).unwrap();
+    assert_eq!(w.0.get(), 2);
+    Wrapped::f1_once().get_or_init(&w, || "x".to_string());
+    assert_eq!(Wrapped::f1().get(&w).map(String::as_str), Some("x"));
+    assert!(Wrapped::f2_upgrade().get(&w).is_some());
+    Wrapped::f3_cell().set(&w, 4).unwrap();
+    assert_eq!(w.3.as_ref().map(Cell::get), Some(4));
+
+    let link = Link::Parent(Arc::downgrade(&parent));
+    assert_eq!(Link::parent_upgrade().get(&link).as_deref().map(String::as_str), Some("p"));
+    let lazy = Link::Lazy(LazyLock::new(String::new));
+    assert!(Link::parent_upgrade().get(&lazy).is_none());
+}
+
+#[test]
+fn test_enum_cell_and_once_variants() {
+    let hits = Link::Hits(Cell::new(1));
+    assert_eq!(Link::hits_cell().replace(&hits, 2), Ok(1));
+    assert_eq!(Link::hits_cell().get(&hits), Some(2));
+    assert_eq!(Link::maybe_hits_cell().get(&hits), None);
+
+    let maybe = Link::MaybeHits(Some(Cell::new(5)));
+    Link::maybe_hits_cell().set(&maybe, 6).unwrap();
+    assert_eq!(Link::maybe_hits_cell().get(&maybe), Some(6));
+    assert_eq!(Link::maybe_hits_cell().get(&Link::MaybeHits(None)), None);
+
+    let label = Link::Label(OnceLock::new());
+    assert_eq!(Link::label().get(&label), None);
+    Link::label_once().get_or_init(&label, || "tag".to_string());
+    assert_eq!(Link::label().get(&label).map(String::as_str), Some("tag"));
+    assert!(Link::label_once().set(&hits, String::new()).is_err());
+}
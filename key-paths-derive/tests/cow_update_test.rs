@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use key_paths_derive::Kp;
+use rust_key_paths::{CowKp, Lens, ReadKp, WriteKp};
+
+#[derive(Kp, Clone, Debug, PartialEq)]
+struct Theme {
+    name: String,
+    accent: u32,
+}
+
+#[derive(Kp, Clone, Debug, PartialEq)]
+struct Ui {
+    theme: Arc<Theme>,
+    panels: Arc<Vec<String>>,
+    zoom: Option<f32>,
+}
+
+#[derive(Kp, Clone, Debug, PartialEq)]
+struct AppState {
+    ui: Arc<Ui>,
+    documents: Arc<HashMap<String, String>>,
+    revision: u64,
+}
+
+#[derive(Kp, Clone)]
+struct Local(Rc<Theme>, Rc<Vec<u8>>);
+
+fn state() -> Arc<AppState> {
+    Arc::new(AppState {
+        ui: Arc::new(Ui {
+            theme: Arc::new(Theme {
+                name: "light".into(),
+                accent: 1,
+            }),
+            panels: Arc::new(vec!["files".into()]),
+            zoom: None,
+        }),
+        documents: Arc::new(HashMap::from([("a".into(), "text".into())])),
+        revision: 0,
+    })
+}
+
+#[test]
+fn only_the_path_is_cloned() {
+    let v1 = state();
+    let accent = AppState::ui_cow()
+        .compose(Ui::theme_cow())
+        .compose(Theme::accent());
+
+    let v2 = accent.update_cow(&v1, |a| *a = 7);
+
+    assert_eq!(accent.read(&v1), Some(&1));
+    assert_eq!(accent.read(&v2), Some(&7));
+
+    // the path: new allocations
+    assert!(!Arc::ptr_eq(&v1, &v2));
+    assert!(!Arc::ptr_eq(&v1.ui, &v2.ui));
+    assert!(!Arc::ptr_eq(&v1.ui.theme, &v2.ui.theme));
+
+    // everything else: shared with the old snapshot
+    assert!(Arc::ptr_eq(&v1.documents, &v2.documents));
+    assert!(Arc::ptr_eq(&v1.ui.panels, &v2.ui.panels));
+    assert_eq!(v1.ui.theme.name, v2.ui.theme.name);
+}
+
+#[test]
+fn snapshots_form_a_history() {
+    let revision = AppState::revision();
+    let panels = AppState::ui_cow().compose(Ui::panels_cow());
+
+    let mut history = vec![state()];
+    for panel in ["search", "git"] {
+        let last = history.last().unwrap();
+        let next = panels.update_cow(last, |p| p.push(panel.into()));
+        let next = revision.update_cow(&next, |r| *r += 1);
+        history.push(next);
+    }
+
+    let lens: Vec<_> = history.iter().map(|s| s.ui.panels.len()).collect();
+    assert_eq!(lens, vec![1, 2, 3]);
+    assert_eq!(history[2].revision, 2);
+    assert!(history.windows(2).all(|w| Arc::ptr_eq(&w[0].ui.theme, &w[1].ui.theme)));
+    assert!(history.windows(2).all(|w| Arc::ptr_eq(&w[0].documents, &w[1].documents)));
+}
+
+#[test]
+fn missing_path_returns_the_same_snapshot() {
+    let v1 = state();
+    let zoom = AppState::ui_cow().compose(Ui::zoom_or_default());
+    let v2 = zoom.update_cow(&v1, |_| unreachable!());
+    assert!(Arc::ptr_eq(&v1, &v2));
+}
+
+#[test]
+fn plain_writes_still_need_a_unique_pointer() {
+    let v1 = state();
+    let mut copy = (*v1).clone();
+    let name = AppState::ui_cow()
+        .compose(Ui::theme_cow())
+        .compose(Theme::name());
+
+    // `copy.ui` is shared with `v1.ui`, so a plain write is refused ...
+    assert!(name.write(&mut copy).is_none());
+    // ... while `cow_mut` clones it
+    *name.cow_mut(&mut copy).unwrap() = "dark".into();
+    assert_eq!(copy.ui.theme.name, "dark");
+    assert_eq!(v1.ui.theme.name, "light");
+}
+
+#[test]
+fn rc_tuple_fields() {
+    let v1 = Rc::new(Local(
+        Rc::new(Theme {
+            name: "light".into(),
+            accent: 1,
+        }),
+        Rc::new(vec![1, 2]),
+    ));
+    let v2 = Local::f0_cow()
+        .compose(Theme::name())
+        .update_cow(&v1, |n| n.push_str("er"));
+
+    assert_eq!(v2.0.name, "lighter");
+    assert_eq!(v1.0.name, "light");
+    assert!(Rc::ptr_eq(&v1.1, &v2.1));
+}
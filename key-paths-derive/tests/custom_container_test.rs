@@ -0,0 +1,149 @@
+use std::sync::Mutex;
+
+use key_paths_derive::Kp;
+use rust_key_paths::KpContainer;
+use rust_key_paths::LockAccess;
+
+/// In-house slot map: keyed access through `KpContainer`.
+struct SlotMap<T> {
+    slots: Vec<Option<T>>,
+}
+
+impl<T> KpContainer for SlotMap<T> {
+    type Key = usize;
+    type Item = T;
+    fn kp_get(&self, key: &usize) -> Option<&T> {
+        self.slots.get(*key)?.as_ref()
+    }
+    fn kp_get_mut(&mut self, key: &usize) -> Option<&mut T> {
+        self.slots.get_mut(*key)?.as_mut()
+    }
+}
+
+/// Vec-like type with its own accessor functions returning `Option`.
+struct MyVec<T>(Vec<T>);
+
+impl<T> MyVec<T> {
+    fn get(&self, i: usize) -> Option<&T> {
+        self.0.get(i)
+    }
+    fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        self.0.get_mut(i)
+    }
+}
+
+/// Single-value wrapper whose accessors return plain references.
+struct Interned<T>(T);
+
+impl<T> Interned<T> {
+    fn value(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Custom lock type with a user `LockAccess` impl.
+struct Guarded<T>(Mutex<T>);
+
+#[derive(Default)]
+struct GuardedAccess;
+
+impl<'a, T: 'static> LockAccess<Guarded<T>, &'a T> for GuardedAccess {
+    fn lock_read(&self, lock: &Guarded<T>) -> Option<&'a T> {
+        lock.0.lock().ok().map(|guard| {
+            let ptr = &*guard as *const T;
+            unsafe { &*ptr }
+        })
+    }
+    fn lock_write(&self, lock: &Guarded<T>) -> Option<&'a T> {
+        self.lock_read(lock)
+    }
+}
+
+impl<'a, T: 'static> LockAccess<Guarded<T>, &'a mut T> for GuardedAccess {
+    fn lock_read(&self, lock: &Guarded<T>) -> Option<&'a mut T> {
+        lock.0.lock().ok().map(|mut guard| {
+            let ptr = &mut *guard as *mut T;
+            unsafe { &mut *ptr }
+        })
+    }
+    fn lock_write(&self, lock: &Guarded<T>) -> Option<&'a mut T> {
+        self.lock_read(lock)
+    }
+}
+
+#[derive(Kp)]
+struct Registry {
+    #[kp(container)]
+    users: SlotMap<String>,
+    #[kp(via = "MyVec::get", mut_via = "MyVec::get_mut", at)]
+    scores: MyVec<u32>,
+    #[kp(via = "MyVec::get", at, key = "usize", item = "i8")]
+    readonly: MyVec<i8>,
+    #[kp(via = "Interned::value")]
+    name: Interned<String>,
+    #[kp(lock = "GuardedAccess")]
+    state: Guarded<u64>,
+}
+
+#[derive(Kp)]
+struct Pair(#[kp(container)] SlotMap<i32>, #[kp(via = "Interned::value")] Interned<bool>);
+
+fn registry() -> Registry {
+    Registry {
+        users: SlotMap {
+            slots: vec![Some("alice".to_string()), None],
+        },
+        scores: MyVec(vec![10, 20]),
+        readonly: MyVec(vec![-1]),
+        name: Interned("reg".to_string()),
+        state: Guarded(Mutex::new(1)),
+    }
+}
+
+#[test]
+fn test_kp_container_at() {
+    let mut r = registry();
+
+    assert_eq!(Registry::users_at(0).get(&r).map(String::as_str), Some("alice"));
+    assert!(Registry::users_at(1).get(&r).is_none());
+    Registry::users_at(0).get_mut(&mut r).unwrap().push('!');
+    assert_eq!(r.users.slots[0].as_deref(), Some("alice!"));
+
+    // The field keypath itself is unchanged
+    assert!(Registry::users().get(&r).is_some());
+}
+
+#[test]
+fn test_via_at() {
+    let mut r = registry();
+
+    assert_eq!(Registry::scores_at(1).get(&r), Some(&20));
+    *Registry::scores_at(0).get_mut(&mut r).unwrap() += 5;
+    assert_eq!(r.scores.0[0], 15);
+    assert!(Registry::scores_at(2).get(&r).is_none());
+
+    // No `mut_via`: read-only
+    assert_eq!(Registry::readonly_at(0).get(&r), Some(&-1));
+    assert!(Registry::readonly_at(0).get_mut(&mut r).is_none());
+}
+
+#[test]
+fn test_via_inner() {
+    let r = registry();
+    assert_eq!(Registry::name_inner().get(&r).map(String::as_str), Some("reg"));
+}
+
+#[test]
+fn test_custom_lock_access() {
+    let r = registry();
+    let state = Registry::state_lock();
+    assert_eq!(state.get(&r), Some(&1));
+}
+
+#[test]
+fn test_tuple_struct_attrs() {
+    let mut p = Pair(SlotMap { slots: vec![Some(3)] }, Interned(true));
+    *Pair::f0_at(0).get_mut(&mut p).unwrap() = 4;
+    assert_eq!(Pair::f0_at(0).get(&p), Some(&4));
+    assert_eq!(Pair::f1_inner().get(&p), Some(&true));
+}
@@ -161,3 +161,55 @@ fn test_any_kps() {
     let age_val = kps[1].get_as::<Person, i32>(&person);
     assert_eq!(age_val, Some(Some(&28)));
 }
+
+#[test]
+fn test_partial_and_any_kps_write() {
+    let mut person = Person {
+        name: "Frank".to_string(),
+        age: 50,
+        email: "frank@example.com".to_string(),
+    };
+
+    // Generic editor: apply erased updates through the derived keypaths
+    let pkps = Person::partial_kps();
+    pkps[0].set_any(&mut person, Box::new("Grace".to_string())).unwrap();
+    *pkps[1].get_mut_as::<i32>(&mut person).unwrap() += 1;
+    assert!(pkps[2].set_any(&mut person, Box::new(1)).is_err());
+    assert_eq!(person.name, "Grace");
+    assert_eq!(person.age, 51);
+    assert_eq!(person.email, "frank@example.com");
+
+    let akps = Person::any_kps();
+    akps[2].set_any(&mut person, Box::new("grace@example.com".to_string())).unwrap();
+    assert_eq!(person.email, "grace@example.com");
+}
+
+#[test]
+fn test_sync_partial_and_any_kps() {
+    use std::sync::OnceLock;
+
+    static REGISTRY: OnceLock<Vec<rust_key_paths::SyncPKp<Person>>> = OnceLock::new();
+    let kps = REGISTRY.get_or_init(Person::sync_partial_kps);
+    assert_eq!(kps.len(), 3);
+
+    let person = Person {
+        name: "Heidi".to_string(),
+        age: 33,
+        email: "heidi@example.com".to_string(),
+    };
+    std::thread::scope(|scope| {
+        let age = scope.spawn(|| kps[1].get_as::<i32>(&person).copied());
+        assert_eq!(age.join().unwrap(), Some(33));
+    });
+
+    let akps = Person::sync_any_kps();
+    let name = std::thread::spawn(move || {
+        let person = Person {
+            name: "Ivan".to_string(),
+            age: 1,
+            email: String::new(),
+        };
+        akps[0].get_as::<Person, String>(&person).flatten().cloned()
+    });
+    assert_eq!(name.join().unwrap(), Some("Ivan".to_string()));
+}
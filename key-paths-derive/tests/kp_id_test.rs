@@ -0,0 +1,196 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use key_paths_derive::Kp;
+use rust_key_paths::{AKp, IdKp, KpId, KpStatic, KpType, PKp};
+
+#[derive(Kp)]
+struct Address {
+    city: String,
+    zip: Option<String>,
+}
+
+#[derive(Kp)]
+struct User {
+    name: String,
+    address: Address,
+    backup: Address,
+}
+
+#[derive(Kp)]
+struct Point(i32, i32);
+
+#[derive(Kp)]
+struct Line {
+    from: Point,
+}
+
+#[derive(Kp)]
+struct Shape {
+    outline: Line,
+}
+
+#[derive(Kp)]
+struct Layer {
+    shape: Shape,
+}
+
+#[derive(Kp)]
+struct Scene {
+    layer: Layer,
+}
+
+#[derive(Kp)]
+struct Shared {
+    state: Arc<std::sync::Mutex<Address>>,
+}
+
+// Derived accessors are plain keypaths; their `{FIELD}_KP` constants give the identified form.
+fn id_kp<R: 'static, V: 'static>(kp: KpStatic<R, V>) -> IdKp<KpType<'static, R, V>> {
+    kp.to_id_kp().expect("derived constants are named")
+}
+
+#[test]
+fn test_derived_ids_are_stable_and_compose() {
+    let city = id_kp(User::ADDRESS_KP).then(id_kp(Address::CITY_KP));
+    assert_eq!(city, id_kp(User::ADDRESS_KP).then(id_kp(Address::CITY_KP)));
+    assert_ne!(city, id_kp(User::BACKUP_KP).then(id_kp(Address::CITY_KP)));
+    assert_eq!(city.id().to_string(), "User.address.city");
+    assert_eq!(city.id().len(), 2);
+
+    // Option fields are identified too (value type is the unwrapped one)
+    let zip = id_kp(User::ADDRESS_KP).then(id_kp(Address::ZIP_KP));
+    assert_eq!(zip.id().value_type_id(), std::any::TypeId::of::<String>());
+
+    assert_eq!(id_kp(Point::F1_KP).id().to_string(), "Point.1");
+    assert_ne!(id_kp(Point::F0_KP), id_kp(Point::F1_KP));
+
+    // Closure-built keypaths opt in with their field name
+    let name = KpType::<User, String>::new(|u| Some(&u.name), |u| Some(&mut u.name)).with_segment("name");
+    assert_eq!(name, id_kp(User::NAME_KP));
+}
+
+#[test]
+fn test_plain_keypaths_carry_no_identity() {
+    let word = std::mem::size_of::<usize>();
+    assert_eq!(std::mem::size_of::<KpType<'static, User, String>>(), 2 * word);
+
+    let (address, city) = (User::address(), Address::city());
+    let parts = std::mem::size_of_val(&address) + std::mem::size_of_val(&city);
+    assert_eq!(std::mem::size_of_val(&address.then(city)), parts);
+}
+
+#[test]
+fn test_prefix_queries_and_map_keys() {
+    let address = id_kp(User::ADDRESS_KP).id().clone();
+    let city = id_kp(User::ADDRESS_KP).then(id_kp(Address::CITY_KP)).id().clone();
+    let zip = id_kp(User::ADDRESS_KP).then(id_kp(Address::ZIP_KP)).id().clone();
+    let name = id_kp(User::NAME_KP).id().clone();
+
+    assert!(city.starts_with(&address));
+    assert!(!name.starts_with(&address));
+    assert!(!address.starts_with(&city));
+    assert_eq!(city.strip_prefix(&address).as_ref(), Some(id_kp(Address::CITY_KP).id()));
+    assert!(address.strip_prefix(&address).unwrap().is_empty());
+    assert_eq!(city.common_prefix(&zip), Some(address.clone()));
+    assert!(city.common_prefix(&name).unwrap().is_empty());
+    assert_eq!(city.common_prefix(id_kp(Address::CITY_KP).id()), None);
+
+    // Subscriptions keyed by path
+    let mut subscribers: HashMap<KpId, Vec<&str>> = HashMap::new();
+    subscribers
+        .entry(city.clone())
+        .or_default()
+        .push("map-widget");
+    subscribers
+        .entry(id_kp(User::ADDRESS_KP).then(id_kp(Address::CITY_KP)).id().clone())
+        .or_default()
+        .push("header");
+    assert_eq!(subscribers[&city], ["map-widget", "header"]);
+
+    // Ordered: a path sorts right before everything under it
+    let dirty: BTreeMap<KpId, ()> = [zip.clone(), name.clone(), address.clone(), city.clone()]
+        .into_iter()
+        .map(|k| (k, ()))
+        .collect();
+    let under_address: Vec<_> = dirty
+        .keys()
+        .skip_while(|k| **k != address)
+        .take_while(|k| k.starts_with(&address))
+        .collect();
+    assert_eq!(under_address.len(), 3);
+}
+
+#[test]
+fn test_ids_survive_erasure_and_locks() {
+    let address = id_kp(User::ADDRESS_KP).id().clone();
+    let city = id_kp(Address::CITY_KP).id().clone();
+    let user_city = address.then(&city);
+
+    let pkp = PKp::new(User::address())
+        .with_id(address.clone())
+        .then_pkp(&PKp::new(Address::city()).with_id(city.clone()))
+        .unwrap();
+    assert_eq!(pkp.id(), Some(&user_city));
+    assert!(PKp::new(User::name()).id().is_none());
+    let akp = AKp::new(User::address())
+        .with_id(address.clone())
+        .then(&AKp::new(Address::city()).with_id(city.clone()))
+        .unwrap();
+    assert_eq!(akp.id(), Some(&user_city));
+    assert!(AKp::new(User::address()).with_id(address).for_arc::<User>().id().is_none());
+
+    let state = id_kp(Shared::STATE_KP).id().clone();
+    let locked = Shared::state_lock().with_ids(&state, &KpId::identity::<Address>());
+    assert_eq!(locked.id().len(), 2);
+    let locked_city = locked.then_with(id_kp(Address::CITY_KP), |lock, city| lock.then(city));
+    assert_eq!(locked_city.id().to_string(), "Shared.state.lock.city");
+    assert!(locked_city.id().starts_with(&state));
+
+    let shared = Shared {
+        state: Arc::new(std::sync::Mutex::new(Address {
+            city: "Oslo".to_string(),
+            zip: None,
+        })),
+    };
+    assert_eq!(locked_city.get(&shared).map(String::as_str), Some("Oslo"));
+}
+
+#[test]
+fn test_long_paths_match_short_ones() {
+    // Five steps: past the inline storage
+    let x = id_kp(Scene::LAYER_KP)
+        .then(id_kp(Layer::SHAPE_KP))
+        .then(id_kp(Shape::OUTLINE_KP))
+        .then(id_kp(Line::FROM_KP))
+        .then(id_kp(Point::F0_KP));
+    let x_id = x.id().clone();
+    assert_eq!(x_id.len(), 5);
+    assert_eq!(x_id.to_string(), "Scene.layer.shape.outline.from.0");
+
+    let layer = id_kp(Scene::LAYER_KP).id().clone();
+    let rest = id_kp(Layer::SHAPE_KP)
+        .then(id_kp(Shape::OUTLINE_KP))
+        .then(id_kp(Line::FROM_KP))
+        .then(id_kp(Point::F0_KP));
+    assert_eq!(x_id.strip_prefix(&layer).as_ref(), Some(rest.id()));
+
+    let y_id = id_kp(Scene::LAYER_KP)
+        .then(id_kp(Layer::SHAPE_KP))
+        .then(id_kp(Shape::OUTLINE_KP))
+        .then(id_kp(Line::FROM_KP))
+        .then(id_kp(Point::F1_KP))
+        .id()
+        .clone();
+    let from = id_kp(Scene::LAYER_KP)
+        .then(id_kp(Layer::SHAPE_KP))
+        .then(id_kp(Shape::OUTLINE_KP))
+        .then(id_kp(Line::FROM_KP))
+        .id()
+        .clone();
+    assert_eq!(x_id.common_prefix(&y_id), Some(from.clone()));
+
+    let mut seen = std::collections::HashSet::new();
+    seen.insert(from);
+    assert!(seen.contains(&x_id.common_prefix(&y_id).unwrap()));
+}
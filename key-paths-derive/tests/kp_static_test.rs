@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+
+use key_paths_derive::Kp;
+use rust_key_paths::{KpStatic, KpType, ReadKp, Then, WriteKp};
+
+#[derive(Kp, Debug, Default)]
+struct Address {
+    city: String,
+    zip: Option<u32>,
+}
+
+#[derive(Kp, Debug, Default)]
+struct User {
+    name: String,
+    age: u8,
+    address: Address,
+    nickname: Option<String>,
+    backup: Option<Box<Address>>,
+    tags: Vec<String>,
+    scores: HashMap<String, u32>,
+    avatar: String,
+    home: Box<Address>,
+}
+
+#[derive(Kp, Default)]
+struct Company {
+    owner: User,
+}
+
+#[derive(Kp)]
+struct Pair(u8, Option<String>);
+
+// Same value types as the accessors: options and boxes unwrap, containers stay whole.
+const NAME: KpStatic<User, String> = User::NAME_KP;
+const NICKNAME: KpStatic<User, String> = User::NICKNAME_KP;
+const BACKUP: KpStatic<User, Option<Box<Address>>> = User::BACKUP_KP;
+const AVATAR: KpStatic<User, String> = User::AVATAR_KP;
+const HOME: KpStatic<User, Address> = User::HOME_KP;
+const TAGS: KpStatic<User, Vec<String>> = User::TAGS_KP;
+const SCORES: KpStatic<User, HashMap<String, u32>> = User::SCORES_KP;
+
+const USER_CITY: Then<KpStatic<User, Address>, KpStatic<Address, String>> =
+    User::ADDRESS_KP.then(Address::CITY_KP);
+const ADDRESS_ZIP: Then<KpStatic<User, Address>, KpStatic<Address, u32>> =
+    User::ADDRESS_KP.then(Address::ZIP_KP);
+type OwnerAddress = Then<KpStatic<Company, User>, KpStatic<User, Address>>;
+const OWNER_CITY: Then<OwnerAddress, KpStatic<Address, String>> =
+    Company::OWNER_KP.then(User::ADDRESS_KP).then(Address::CITY_KP);
+
+static TEXT_COLUMNS: [KpStatic<User, String>; 3] = [NAME, NICKNAME, AVATAR];
+
+fn user() -> User {
+    User {
+        name: "Ada".into(),
+        age: 36,
+        address: Address {
+            city: "London".into(),
+            zip: None,
+        },
+        nickname: Some("countess".into()),
+        backup: Some(Box::new(Address {
+            city: "Paris".into(),
+            zip: Some(75001),
+        })),
+        avatar: "ada.png".into(),
+        home: Box::new(Address {
+            city: "Bath".into(),
+            zip: None,
+        }),
+        ..User::default()
+    }
+}
+
+#[test]
+fn consts_read_and_write_like_the_accessors() {
+    let mut u = user();
+    assert_eq!(NAME.get(&u), User::name().get(&u));
+    assert_eq!(NICKNAME.get(&u).map(String::as_str), Some("countess"));
+    assert_eq!(BACKUP.get(&u).unwrap().as_ref().map(|a| a.city.as_str()), Some("Paris"));
+    assert_eq!(AVATAR.get(&u).map(String::as_str), Some("ada.png"));
+    assert_eq!(HOME.get(&u).map(|a| a.city.as_str()), Some("Bath"));
+    assert!(TAGS.get(&u).unwrap().is_empty());
+    assert!(SCORES.get(&u).unwrap().is_empty());
+
+    *User::AGE_KP.get_mut(&mut u).unwrap() += 1;
+    assert_eq!(u.age, 37);
+
+    u.nickname = None;
+    assert!(NICKNAME.get(&u).is_none());
+
+    *Pair::F1_KP.get_mut(&mut Pair(1, Some("a".into()))).unwrap() = "b".into();
+    assert_eq!(Pair::F0_KP.get(&Pair(4, None)), Some(&4));
+}
+
+#[test]
+fn const_composition() {
+    let mut u = user();
+    assert_eq!(USER_CITY.read(&u).map(String::as_str), Some("London"));
+    USER_CITY.write(&mut u).unwrap().push_str(" W1");
+    assert_eq!(u.address.city, "London W1");
+
+    assert!(ADDRESS_ZIP.read(&u).is_none());
+    u.address.zip = Some(1000);
+    assert_eq!(ADDRESS_ZIP.read(&u), Some(&1000));
+
+    let company = Company { owner: u };
+    assert_eq!(OWNER_CITY.read(&company).map(String::as_str), Some("London W1"));
+}
+
+#[test]
+fn static_tables_compare_and_hash_by_field() {
+    let u = user();
+    let texts: Vec<_> = TEXT_COLUMNS.iter().filter_map(|kp| kp.get(&u)).collect();
+    assert_eq!(texts, ["Ada", "countess", "ada.png"]);
+
+    assert_eq!(User::NAME_KP, NAME);
+    assert_ne!(NAME, NICKNAME);
+    assert_eq!(NAME.name(), Some("name"));
+    assert_eq!(Pair::F1_KP.name(), Some("1"));
+
+    let hidden: HashSet<_> = [NICKNAME].into();
+    let visible: Vec<_> = TEXT_COLUMNS.iter().filter(|kp| !hidden.contains(kp)).collect();
+    assert_eq!(visible, [&NAME, &AVATAR]);
+
+    let label = |kp: KpStatic<User, String>| match kp.name() {
+        Some("name") => "Name",
+        Some("nickname") => "Nickname",
+        _ => "?",
+    };
+    assert_eq!(label(NICKNAME), "Nickname");
+}
+
+#[test]
+fn converts_into_kp_type_with_identity() {
+    let u = user();
+    let kp: KpType<'static, User, String> = NAME.into();
+    assert_eq!(kp.get(&u).map(String::as_str), Some("Ada"));
+    let named = NAME.to_id_kp().unwrap();
+    assert_eq!(named.get(&u).map(String::as_str), Some("Ada"));
+    assert_eq!(NAME.id().as_ref(), Some(named.id()));
+
+    let city = User::ADDRESS_KP
+        .to_id_kp()
+        .unwrap()
+        .then(Address::CITY_KP.to_id_kp().unwrap());
+    assert_eq!(city.read(&u).map(String::as_str), Some("London"));
+    assert_eq!(city.id().to_string(), "User.address.city");
+}
@@ -0,0 +1,121 @@
+use key_paths_derive::Kp;
+use rust_key_paths::{KpDynamic, Lens, OffsetKp, ReadKp, WriteKp, offset_kp};
+
+#[derive(Kp, Clone, Copy, Debug, PartialEq, Default)]
+#[repr(C)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[derive(Kp, Clone, Debug, PartialEq, Default)]
+#[repr(C)]
+struct Particle {
+    id: u32,
+    pos: Vec3,
+    vel: Vec3,
+    label: Option<String>,
+}
+
+#[derive(Kp, Clone, Copy, Default)]
+struct Pair(u8, Vec3);
+
+const POS_Y: OffsetKp<Particle, f32> = Particle::pos_offset().then(Vec3::y_offset());
+const VEL_Z: OffsetKp<Particle, f32> = offset_kp!(Particle, vel.z);
+
+fn particles(n: u32) -> Vec<Particle> {
+    (0..n)
+        .map(|i| Particle {
+            id: i,
+            pos: Vec3 {
+                x: 0.0,
+                y: i as f32,
+                z: 0.0,
+            },
+            vel: Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: -(i as f32),
+            },
+            label: None,
+        })
+        .collect()
+}
+
+#[test]
+fn offsets_match_offset_of() {
+    assert_eq!(
+        POS_Y.offset(),
+        core::mem::offset_of!(Particle, pos) + core::mem::offset_of!(Vec3, y)
+    );
+    assert_eq!(VEL_Z.offset(), core::mem::offset_of!(Particle, vel.z));
+    assert_eq!(Pair::f1_offset().then(Vec3::x_offset()).offset(), core::mem::offset_of!(Pair, 1.x));
+    assert_eq!(Vec3::z_offset().offset(), 8);
+}
+
+#[test]
+fn get_and_get_mut() {
+    let mut p = particles(3).remove(2);
+    assert_eq!(*POS_Y.get(&p), 2.0);
+    *VEL_Z.get_mut(&mut p) += 10.0;
+    assert_eq!(p.vel.z, 8.0);
+
+    let label = Particle::label_offset();
+    *label.get_mut(&mut p) = Some("spark".into());
+    assert_eq!(p.label.as_deref(), Some("spark"));
+
+    let mut pair = Pair::default();
+    *Pair::f0_offset().get_mut(&mut pair) = 4;
+    assert_eq!(pair.0, 4);
+}
+
+#[test]
+fn bulk_gather_and_scatter() {
+    let mut ps = particles(5);
+
+    let mut ys = [0.0; 5];
+    POS_Y.gather(&ps, &mut ys);
+    assert_eq!(ys, [0.0, 1.0, 2.0, 3.0, 4.0]);
+
+    let mut zs = Vec::with_capacity(ps.len());
+    // SAFETY: capacity reserved for `ps.len()` values.
+    unsafe {
+        VEL_Z.gather_unchecked(&ps, zs.as_mut_ptr());
+        zs.set_len(ps.len());
+    }
+    assert_eq!(zs, vec![0.0, -1.0, -2.0, -3.0, -4.0]);
+
+    POS_Y.scatter(&mut ps, &zs);
+    assert_eq!(POS_Y.iter(&ps).copied().collect::<Vec<_>>(), zs);
+
+    Particle::id_offset().iter_mut(&mut ps).for_each(|id| *id *= 10);
+    assert_eq!(ps.iter().map(|p| p.id).collect::<Vec<_>>(), vec![0, 10, 20, 30, 40]);
+}
+
+#[test]
+#[should_panic(expected = "length mismatch")]
+fn gather_checks_lengths() {
+    let mut out = [0.0; 2];
+    POS_Y.gather(&particles(3), &mut out);
+}
+
+#[test]
+fn converts_to_closure_keypaths() {
+    let mut p = particles(2).remove(1);
+
+    let kp = POS_Y.to_kp();
+    assert_eq!(kp.get(&p), Some(&1.0));
+    *kp.get_mut(&mut p).unwrap() = 5.0;
+    assert_eq!(p.pos.y, 5.0);
+
+    let dynamic: KpDynamic<Particle, f32> = VEL_Z.into();
+    assert_eq!(dynamic.get(&p), Some(&-1.0));
+
+    // offsets up to the container, closures past it
+    let label = Particle::label_offset().compose(rust_key_paths::enum_some::<String>());
+    assert!(label.read(&p).is_none());
+    p.label = Some("x".into());
+    label.write(&mut p).unwrap().push('y');
+    assert_eq!(p.label.as_deref(), Some("xy"));
+}
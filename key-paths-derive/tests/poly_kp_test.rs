@@ -0,0 +1,73 @@
+use key_paths_derive::Kp;
+
+#[derive(Kp, Debug, PartialEq)]
+struct Config<U> {
+    name: String,
+    retries: u32,
+    url: U,
+}
+
+#[derive(Kp, Debug, PartialEq)]
+struct Pair<A, B: Clone>(A, B);
+
+// `T` is used by two fields: no `_poly` for either, plain keypaths still work.
+#[derive(Kp, Debug, PartialEq)]
+struct Range<T> {
+    start: T,
+    end: T,
+}
+
+#[derive(Debug, PartialEq)]
+struct Url {
+    host: String,
+    port: u16,
+}
+
+fn parse(raw: String) -> Url {
+    let (host, port) = raw.split_once(':').unwrap();
+    Url {
+        host: host.to_string(),
+        port: port.parse().unwrap(),
+    }
+}
+
+#[test]
+fn test_poly_field_changes_type() {
+    let raw = Config {
+        name: "api".to_string(),
+        retries: 3,
+        url: "example.com:8443".to_string(),
+    };
+    assert_eq!(Config::<String>::url_poly::<Url>().get(&raw), "example.com:8443");
+
+    let mut parsed: Config<Url> = Config::url_poly().over(raw, parse);
+    assert_eq!(parsed.name, "api");
+    assert_eq!(parsed.retries, 3);
+    assert_eq!(parsed.url.port, 8443);
+
+    // Plain keypaths keep working on the new type
+    *Config::<Url>::retries().get_mut(&mut parsed).unwrap() += 1;
+    assert_eq!(Config::<Url>::retries().get(&parsed), Some(&4));
+    assert_eq!(parsed.url.host, "example.com");
+}
+
+#[test]
+fn test_poly_compose_and_tuple_structs() {
+    let cfg = Config {
+        name: "db".to_string(),
+        retries: 1,
+        url: Pair("localhost".to_string(), 5432u16),
+    };
+
+    // config.url.1: u16 -> String, rebuilding both levels
+    let port = Config::<Pair<String, u16>>::url_poly::<Pair<String, String>>().then(Pair::f1_poly());
+    assert_eq!(*port.get(&cfg), 5432);
+    let rendered = port.over(cfg, |p| format!(":{}", p));
+    assert_eq!(rendered.url, Pair("localhost".to_string(), ":5432".to_string()));
+
+    let swapped: Pair<Vec<u8>, String> = Pair::f0_poly().set(rendered.url, vec![1, 2]);
+    assert_eq!(swapped.0, [1, 2]);
+
+    let r = Range { start: 1, end: 5 };
+    assert_eq!(Range::<i32>::end().get(&r), Some(&5));
+}
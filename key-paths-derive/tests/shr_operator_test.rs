@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use key_paths_derive::Kp;
+use rust_key_paths::{
+    ArcMutexAccess, CowKp, EnumKpType, FillKp, KpType, LockKp, OffsetKp, ReadKp, Then, WriteKp,
+    enum_variant,
+};
+
+#[derive(Kp, Clone, Default, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: Option<String>,
+}
+
+#[derive(Kp, Clone, Default)]
+struct Person {
+    name: String,
+    address: Address,
+}
+
+#[derive(Kp)]
+struct Company {
+    ceo: Person,
+    contact: Contact,
+    branches: HashMap<String, Address>,
+}
+
+enum Contact {
+    Email(String),
+    Office(Address),
+}
+
+struct Shared {
+    people: Arc<Mutex<Person>>,
+}
+
+#[derive(Kp, Clone)]
+struct Snapshot {
+    ceo: Arc<Person>,
+}
+
+type CeoAddress = Then<KpType<'static, Company, Person>, KpType<'static, Person, Address>>;
+
+fn office() -> EnumKpType<'static, Contact, Address> {
+    enum_variant(
+        |c: &Contact| match c {
+            Contact::Office(a) => Some(a),
+            _ => None,
+        },
+        |c: &mut Contact| match c {
+            Contact::Office(a) => Some(a),
+            _ => None,
+        },
+        Contact::Office,
+    )
+}
+
+fn company() -> Company {
+    Company {
+        ceo: Person {
+            name: "Ada".into(),
+            address: Address {
+                city: "London".into(),
+                zip: None,
+            },
+        },
+        contact: Contact::Office(Address {
+            city: "Paris".into(),
+            zip: None,
+        }),
+        branches: HashMap::new(),
+    }
+}
+
+#[test]
+fn kp_chains() {
+    let mut c = company();
+    let city: Then<CeoAddress, _> =
+        Company::ceo() >> Person::address() >> Address::city();
+
+    assert_eq!(city.read(&c).map(String::as_str), Some("London"));
+    city.write(&mut c).unwrap().push_str(" W1");
+    assert_eq!(c.ceo.address.city, "London W1");
+
+    let zip = Company::ceo() >> Person::address() >> Address::zip();
+    assert!(zip.read(&c).is_none());
+}
+
+#[test]
+fn kp_into_enum() {
+    let mut c = company();
+    let office_city = Company::contact() >> office() >> Address::city();
+    assert_eq!(office_city.read(&c).map(String::as_str), Some("Paris"));
+
+    c.contact = Contact::Email("hq@acme.test".into());
+    assert!(office_city.read(&c).is_none());
+    assert!(matches!(c.contact, Contact::Email(ref e) if e == "hq@acme.test"));
+}
+
+#[test]
+fn lock_then_kp() {
+    let shared = Shared {
+        people: Arc::new(Mutex::new(company().ceo)),
+    };
+    let lock = LockKp::new(
+        KpType::new(|s: &Shared| Some(&s.people), |s: &mut Shared| Some(&mut s.people)),
+        ArcMutexAccess::new(),
+        KpType::<'static, Person, Person>::identity(),
+    );
+    let city = lock >> Person::address() >> Address::city();
+    assert_eq!(city.read(&shared).map(String::as_str), Some("London"));
+}
+
+#[test]
+fn other_keypath_kinds() {
+    let mut c = company();
+
+    let ceo_city = Company::CEO_KP >> Person::ADDRESS_KP >> Address::CITY_KP;
+    assert_eq!(ceo_city.read(&c).map(String::as_str), Some("London"));
+
+    let offset: OffsetKp<Company, String> =
+        Company::ceo_offset() >> Person::address_offset() >> Address::city_offset();
+    assert_eq!(offset.get(&c), "London");
+
+    let branch_city = Company::branches_entry("north".into()) >> Address::city();
+    *branch_city.get_mut_or_default(&mut c).unwrap() = "Leeds".into();
+    assert_eq!(c.branches["north"].city, "Leeds");
+
+    let v1 = Arc::new(Snapshot {
+        ceo: Arc::new(company().ceo),
+    });
+    let name = Snapshot::ceo_cow() >> Person::name();
+    let v2 = name.update_cow(&v1, |n| *n = "Grace".into());
+    assert_eq!((v1.ceo.name.as_str(), v2.ceo.name.as_str()), ("Ada", "Grace"));
+}
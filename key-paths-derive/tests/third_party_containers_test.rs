@@ -0,0 +1,134 @@
+//! Run with `cargo test -p key-paths-derive --all-features`.
+#![cfg(all(
+    feature = "indexmap",
+    feature = "smallvec",
+    feature = "arrayvec",
+    feature = "im",
+    feature = "dashmap",
+    feature = "bytes",
+    feature = "arc_swap"
+))]
+
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use arrayvec::ArrayVec;
+use bytes::Bytes;
+use dashmap::DashMap;
+use indexmap::IndexMap;
+use key_paths_derive::Kp;
+use rust_key_paths::KpType;
+use smallvec::SmallVec;
+
+// Third-party containers are only recognised through their crate path
+#[derive(Kp)]
+struct Containers {
+    ordered: indexmap::IndexMap<String, i32>,
+    small: smallvec::SmallVec<[u16; 4]>,
+    fixed: arrayvec::ArrayVec<u8, 8>,
+    persistent: im::Vector<String>,
+    persistent_map: im::HashMap<u32, String>,
+    shared: dashmap::DashMap<String, u64>,
+    payload: bytes::Bytes,
+    config: arc_swap::ArcSwap<String>,
+}
+
+#[derive(Kp)]
+struct Wrapped(smallvec::SmallVec<[i32; 2]>, arc_swap::ArcSwap<u32>);
+
+/// A user type that shares its name with `im::Vector`.
+struct Vector3<T>(T, T, T);
+type Vector<T> = Vector3<T>;
+
+#[derive(Kp)]
+struct LookAlike {
+    point: Vector<f32>,
+}
+
+fn containers() -> Containers {
+    let mut ordered = IndexMap::new();
+    ordered.insert("a".to_string(), 1);
+    let shared = DashMap::new();
+    shared.insert("hits".to_string(), 10);
+    let mut fixed = ArrayVec::new();
+    fixed.push(7);
+    Containers {
+        ordered,
+        small: SmallVec::from_slice(&[1, 2, 3]),
+        fixed,
+        persistent: im::Vector::from(vec!["x".to_string()]),
+        persistent_map: im::HashMap::unit(1, "one".to_string()),
+        shared,
+        payload: Bytes::from_static(b"abc"),
+        config: ArcSwap::from_pointee("v1".to_string()),
+    }
+}
+
+#[test]
+fn test_indexed_containers_at() {
+    let mut c = containers();
+
+    assert_eq!(Containers::small_at(2).get(&c), Some(&3));
+    *Containers::small_at(0).get_mut(&mut c).unwrap() = 9;
+    assert_eq!(c.small[0], 9);
+
+    assert_eq!(Containers::fixed_at(0).get(&c), Some(&7));
+    assert_eq!(Containers::fixed_at(1).get(&c), None);
+
+    Containers::persistent_at(0).get_mut(&mut c).unwrap().push('!');
+    assert_eq!(Containers::persistent_at(0).get(&c).map(String::as_str), Some("x!"));
+
+    // Bytes is immutable: read-only element access
+    assert_eq!(Containers::payload_at(1).get(&c), Some(&b'b'));
+    assert!(Containers::payload_at(1).get_mut(&mut c).is_none());
+
+    // Container keypaths are unchanged
+    let _typed: KpType<'static, Containers, Bytes> = Containers::payload();
+}
+
+#[test]
+fn test_keyed_containers_at() {
+    let mut c = containers();
+
+    *Containers::ordered_at("a".to_string()).get_mut(&mut c).unwrap() += 1;
+    assert_eq!(Containers::ordered_at("a".to_string()).get(&c), Some(&2));
+    assert_eq!(Containers::ordered_at("b".to_string()).get(&c), None);
+
+    assert_eq!(Containers::persistent_map_at(1).get(&c).map(String::as_str), Some("one"));
+}
+
+#[test]
+fn test_dashmap_shard_guards() {
+    let c = containers();
+
+    let hits = Containers::shared_at("hits".to_string());
+    *hits.get_mut(&c).unwrap() += 1;
+    hits.set(&c, |v| *v *= 2).unwrap();
+    assert_eq!(hits.get(&c).map(|v| *v), Some(22));
+    assert!(Containers::shared_at("missing".to_string()).get(&c).is_none());
+}
+
+#[test]
+fn test_arc_swap_load_and_store() {
+    let c = containers();
+
+    let config = Containers::config_load();
+    assert_eq!(config.load(&c).map(|g| g.as_str().to_owned()), Some("v1".to_string()));
+    config.store(&c, Arc::new("v2".to_string())).unwrap();
+    assert_eq!(config.load_full(&c).as_deref().map(String::as_str), Some("v2"));
+
+    let w = Wrapped(SmallVec::from_slice(&[4, 5]), ArcSwap::from_pointee(1));
+    assert_eq!(Wrapped::f0_at(1).get(&w), Some(&5));
+    Wrapped::f1_load().rcu(&w, |old| Arc::new(**old + 1));
+    assert_eq!(**Wrapped::f1_load().load(&w).unwrap(), 2);
+}
+
+#[test]
+fn test_bare_name_is_not_a_third_party_container() {
+    let l = LookAlike {
+        point: Vector3(1.0, 2.0, 3.0),
+    };
+    // A plain field keypath, no `point_at`
+    let kp: KpType<'static, LookAlike, Vector<f32>> = LookAlike::point();
+    assert_eq!(kp.get(&l).map(|v| v.1), Some(2.0));
+}
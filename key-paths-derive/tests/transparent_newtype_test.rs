@@ -0,0 +1,108 @@
+use key_paths_derive::Kp;
+use rust_key_paths::{IsoKp, KpType, Transparent};
+
+#[derive(Kp, Debug, PartialEq)]
+#[kp(transparent)]
+struct UserId(u128);
+
+#[derive(Kp, Debug, PartialEq)]
+#[kp(transparent)]
+struct Meters {
+    value: f64,
+}
+
+#[derive(Kp)]
+struct User {
+    #[kp(transparent)]
+    id: UserId,
+    #[kp(transparent)]
+    height: Meters,
+    name: String,
+}
+
+#[derive(Kp)]
+struct Tagged(#[kp(transparent)] UserId, String);
+
+#[derive(Kp, Debug, PartialEq)]
+#[kp(transparent)]
+struct Wrapper<T>(T);
+
+#[derive(Kp, Debug, PartialEq)]
+#[kp(transparent)]
+struct Label<'a> {
+    text: &'a str,
+}
+
+fn user() -> User {
+    User {
+        id: UserId(7),
+        height: Meters { value: 1.8 },
+        name: "ada".to_string(),
+    }
+}
+
+#[test]
+fn test_transparent_field_inner() {
+    let mut u = user();
+
+    assert_eq!(User::id_inner().get(&u), Some(&7));
+    *User::height_inner().get_mut(&mut u).unwrap() += 0.1;
+    assert!((u.height.value - 1.9).abs() < 1e-9);
+
+    // Field keypaths are unchanged
+    let _typed: KpType<'static, User, UserId> = User::id();
+    assert_eq!(User::name().get(&u).map(String::as_str), Some("ada"));
+
+    let t = Tagged(UserId(3), "x".to_string());
+    assert_eq!(Tagged::f0_inner().get(&t), Some(&3));
+    assert_eq!(Tagged::f1().get(&t).map(String::as_str), Some("x"));
+}
+
+#[test]
+fn test_transparent_trait_and_from() {
+    let mut id = UserId(1);
+    *id.kp_inner_mut() = 2;
+    assert_eq!(*id.kp_inner(), 2);
+
+    assert_eq!(UserId::from(5), UserId(5));
+    assert_eq!(u128::from(UserId(6)), 6);
+    let m: Meters = 2.0.into();
+    assert_eq!(m, Meters { value: 2.0 });
+}
+
+#[test]
+fn test_iso_keypath() {
+    const ISO: IsoKp<UserId, u128> = UserId::iso();
+
+    assert_eq!(ISO.wrap(9), UserId(9));
+    assert_eq!(ISO.unwrap(UserId(9)), 9);
+    assert_eq!(ISO.get(&UserId(4)), Some(&4));
+
+    // Composes with `then`
+    let mut u = user();
+    *User::id().then(ISO.to_kp()).get_mut(&mut u).unwrap() = 42;
+    assert_eq!(User::id().then(ISO.to_kp()).get(&u), Some(&42));
+    assert_eq!(u.id, UserId(42));
+
+    let from_into = IsoKp::<Meters, f64>::from_into();
+    assert_eq!(from_into.get(&Meters { value: 3.0 }), Some(&3.0));
+    assert_eq!(Meters::iso().unwrap(Meters { value: 4.0 }), 4.0);
+}
+
+#[test]
+fn test_generic_transparent_newtype() {
+    let mut w = Wrapper(vec![1, 2]);
+    w.kp_inner_mut().push(3);
+    assert_eq!(w.kp_inner(), &vec![1, 2, 3]);
+    assert_eq!(Wrapper::from(5u8), Wrapper(5u8));
+
+    let iso: IsoKp<Wrapper<String>, String> = Wrapper::iso();
+    assert_eq!(iso.wrap("a".to_string()), Wrapper("a".to_string()));
+    assert_eq!(iso.unwrap(Wrapper("b".to_string())), "b");
+    assert_eq!(iso.get(&Wrapper("c".to_string())).map(String::as_str), Some("c"));
+
+    let owned = String::from("borrowed");
+    let label: Label<'_> = owned.as_str().into();
+    assert_eq!(*label.kp_inner(), "borrowed");
+    assert_eq!(Label::iso().unwrap(label), "borrowed");
+}
@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use key_paths_derive::Kp;
+use rust_key_paths::Traversal;
+
+#[derive(Kp)]
+struct Address {
+    city: String,
+}
+
+#[derive(Kp)]
+struct Employee {
+    name: String,
+    address: Address,
+    skills: Vec<String>,
+}
+
+#[derive(Kp)]
+struct Company {
+    employees: Vec<Employee>,
+    queue: VecDeque<u32>,
+    budgets: HashMap<String, f64>,
+    ledger: BTreeMap<u32, i64>,
+    tags: BTreeSet<String>,
+}
+
+#[derive(Kp)]
+struct Pair(Vec<i32>, String);
+
+fn employee(name: &str, city: &str, skills: &[&str]) -> Employee {
+    Employee {
+        name: name.to_string(),
+        address: Address {
+            city: city.to_string(),
+        },
+        skills: skills.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+fn company() -> Company {
+    Company {
+        employees: vec![
+            employee("ada", "london", &["math", "engines"]),
+            employee("grace", "new york", &["cobol"]),
+        ],
+        queue: VecDeque::from([1, 2, 3]),
+        budgets: HashMap::from([("ops".to_string(), 10.0), ("r&d".to_string(), 20.0)]),
+        ledger: BTreeMap::from([(1, -5), (2, 7), (3, 11), (9, 1)]),
+        tags: BTreeSet::from(["b".to_string(), "a".to_string()]),
+    }
+}
+
+#[test]
+fn test_field_each_then_kp() {
+    let mut c = company();
+
+    // company.employees[*].address.city
+    let cities = Company::employees_each()
+        .then(Employee::address())
+        .then(Address::city());
+    assert_eq!(cities.count(&c), 2);
+    assert_eq!(
+        cities.iter(&c).map(String::as_str).collect::<Vec<_>>(),
+        ["london", "new york"]
+    );
+    for city in cities.iter_mut(&mut c) {
+        city.insert_str(0, "greater ");
+    }
+    assert_eq!(c.employees[1].address.city, "greater new york");
+
+    // company.employees[*].skills[*]
+    let skills = Company::employees_each().then_each(Employee::skills_each());
+    assert_eq!(skills.count(&c), 3);
+    assert_eq!(skills.modify_all(&mut c, |s| s.make_ascii_uppercase()), 3);
+    assert_eq!(c.employees[0].skills, ["MATH", "ENGINES"]);
+
+    let mut names = Vec::new();
+    Company::employees_each()
+        .then(Employee::name())
+        .for_each(&c, |n| names.push(n.clone()));
+    assert_eq!(names, ["ada", "grace"]);
+}
+
+#[test]
+fn test_field_each_maps_and_sets() {
+    let mut c = company();
+
+    assert_eq!(Company::queue_each().modify_all(&mut c, |n| *n *= 10), 3);
+    assert_eq!(c.queue, [10, 20, 30]);
+
+    Company::budgets_each().modify_all(&mut c, |b| *b += 1.0);
+    let mut budgets: Vec<f64> = Company::budgets_each().iter(&c).copied().collect();
+    budgets.sort_by(f64::total_cmp);
+    assert_eq!(budgets, [11.0, 21.0]);
+
+    // Set elements are read-only
+    assert_eq!(Company::tags_each().iter(&c).collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(Company::tags_each().modify_all(&mut c, |t| t.clear()), 0);
+
+    assert_eq!(Pair::f0_each().count(&Pair(vec![1, 2, 3], String::new())), 3);
+}
+
+#[test]
+fn test_btree_range_traversal() {
+    let mut c = company();
+
+    let middle = Company::ledger().then_each(Traversal::btree_range(2..=3));
+    assert_eq!(middle.iter(&c).copied().collect::<Vec<_>>(), [7, 11]);
+    assert_eq!(middle.modify_all(&mut c, |v| *v = 0), 2);
+    assert_eq!(Company::ledger_each().iter(&c).copied().collect::<Vec<_>>(), [-5, 0, 0, 1]);
+}
+
+#[test]
+fn test_btree_range_empty_bounds_visit_nothing() {
+    use std::ops::Bound::Excluded;
+
+    let mut c = company();
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let inverted = Company::ledger().then_each(Traversal::btree_range(5..2));
+    assert_eq!(inverted.count(&c), 0);
+    assert_eq!(inverted.modify_all(&mut c, |v| *v = 0), 0);
+
+    let excluded = Company::ledger().then_each(Traversal::btree_range((Excluded(2), Excluded(2))));
+    assert_eq!(excluded.count(&c), 0);
+    assert_eq!(excluded.modify_all(&mut c, |v| *v = 0), 0);
+
+    let touching = Company::ledger().then_each(Traversal::btree_range((Excluded(2), Excluded(3))));
+    assert_eq!(touching.count(&c), 0);
+    assert_eq!(Company::ledger_each().count(&c), 4);
+}
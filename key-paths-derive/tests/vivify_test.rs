@@ -0,0 +1,112 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use key_paths_derive::Kp;
+use rust_key_paths::{FillKp, Lens, ReadKp, WriteKp};
+
+#[derive(Kp, Default, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: Option<u32>,
+}
+
+#[derive(Kp, Default, Debug, PartialEq)]
+struct Settings {
+    theme: String,
+}
+
+#[derive(Kp, Default)]
+struct User {
+    address: Option<Box<Address>>,
+    backup: Option<Address>,
+    settings: HashMap<String, Settings>,
+    history: BTreeMap<u32, Settings>,
+    scores: Vec<u32>,
+    recent: VecDeque<String>,
+}
+
+#[derive(Kp, Default)]
+struct Pair(Option<Settings>, Vec<u8>);
+
+#[test]
+fn option_box_is_created_on_demand() {
+    let mut user = User::default();
+    let city = User::address_or_default().compose(Address::city());
+
+    assert!(city.read(&user).is_none());
+    assert!(city.write(&mut user).is_none());
+    assert!(user.address.is_none());
+
+    *city.get_mut_or_default(&mut user).unwrap() = "Pune".into();
+    assert_eq!(user.address.as_deref().map(|a| a.city.as_str()), Some("Pune"));
+
+    // an existing value is kept
+    let zip = User::address_or_default().compose(Address::zip_or_default());
+    *zip.get_mut_or_default(&mut user).unwrap() = 411001;
+    assert_eq!(
+        user.address.as_deref(),
+        Some(&Address {
+            city: "Pune".into(),
+            zip: Some(411001)
+        })
+    );
+}
+
+#[test]
+fn option_is_filled_with_default() {
+    let mut user = User::default();
+    let backup = User::backup_or_default();
+
+    assert!(backup.read(&user).is_none());
+    assert_eq!(backup.get_mut_or_default(&mut user), Some(&mut Address::default()));
+    assert_eq!(user.backup, Some(Address::default()));
+}
+
+#[test]
+fn map_entries_are_inserted() {
+    let mut user = User::default();
+    let theme = User::settings_entry("dark".into()).compose(Settings::theme());
+
+    assert!(theme.read(&user).is_none());
+    assert!(theme.write(&mut user).is_none());
+    assert!(user.settings.is_empty());
+
+    theme.get_mut_or_default(&mut user).unwrap().push_str("#000");
+    assert_eq!(theme.read(&user).map(String::as_str), Some("#000"));
+    assert_eq!(user.settings.len(), 1);
+
+    let old = User::history_entry(7).compose(Settings::theme());
+    *old.get_mut_or_default(&mut user).unwrap() = "light".into();
+    assert_eq!(user.history[&7].theme, "light");
+    assert_eq!(User::history_entry(7).key(), &7);
+}
+
+#[test]
+fn vectors_grow_to_the_index() {
+    let mut user = User {
+        scores: vec![5],
+        ..User::default()
+    };
+
+    assert!(User::scores_entry(3).read(&user).is_none());
+    *User::scores_entry(3).get_mut_or_default(&mut user).unwrap() = 9;
+    assert_eq!(user.scores, vec![5, 0, 0, 9]);
+
+    // in range: nothing grows
+    *User::scores_entry(0).get_mut_or_default(&mut user).unwrap() += 1;
+    assert_eq!(user.scores, vec![6, 0, 0, 9]);
+
+    *User::recent_entry(1).get_mut_or_default(&mut user).unwrap() = "b".into();
+    assert_eq!(user.recent, VecDeque::from(["".to_string(), "b".to_string()]));
+}
+
+#[test]
+fn tuple_fields() {
+    let mut pair = Pair::default();
+
+    let theme = Pair::f0_or_default().compose(Settings::theme());
+    *theme.get_mut_or_default(&mut pair).unwrap() = "blue".into();
+    assert_eq!(pair.0, Some(Settings { theme: "blue".into() }));
+
+    *Pair::f1_entry(2).get_mut_or_default(&mut pair).unwrap() = 1;
+    assert_eq!(pair.1, vec![0, 0, 1]);
+}
@@ -0,0 +1,105 @@
+//! Parallel fan-out over type-erased keypaths using [Rayon].
+//!
+//! Works with [SyncPKp] (e.g. from `#[derive(Pkp)]`'s `sync_partial_kps()`), which is
+//! `Send + Sync`, unlike [rust_key_paths::PKp].
+//!
+//! [Rayon]: https://docs.rs/rayon
+
+use std::any::Any;
+
+use rayon::prelude::*;
+use rust_key_paths::{KpError, SyncPKp};
+
+/// Runs `f` for every keypath against the same root, in parallel. Results keep the keypath order.
+pub fn par_map_kps<Root, F, U>(kps: &[SyncPKp<Root>], root: &Root, f: F) -> Vec<U>
+where
+    Root: Sync + 'static,
+    F: Fn(&SyncPKp<Root>, &Root) -> U + Sync + Send,
+    U: Send,
+{
+    kps.par_iter().map(|kp| f(kp, root)).collect()
+}
+
+/// Reads every keypath whose value type is `V` from the same root, in parallel.
+/// Keypaths with another value type (or that do not resolve) yield `None`.
+pub fn par_get_all_as<'a, Root, V>(kps: &[SyncPKp<Root>], root: &'a Root) -> Vec<Option<&'a V>>
+where
+    Root: Sync + 'static,
+    V: Any + Sync,
+{
+    kps.par_iter().map(|kp| kp.get_as::<V>(root)).collect()
+}
+
+/// Reads one keypath from every root, in parallel.
+pub fn par_project_as<'a, Root, V>(kp: &SyncPKp<Root>, roots: &'a [Root]) -> Vec<Option<&'a V>>
+where
+    Root: Sync + 'static,
+    V: Any + Sync,
+{
+    roots.par_iter().map(|root| kp.get_as::<V>(root)).collect()
+}
+
+/// Writes `make(index)` through one keypath into every root, in parallel.
+pub fn par_set_any<Root, F>(kp: &SyncPKp<Root>, roots: &mut [Root], make: F) -> Vec<Result<(), KpError>>
+where
+    Root: Send + 'static,
+    F: Fn(usize) -> Box<dyn Any> + Sync + Send,
+{
+    roots
+        .par_iter_mut()
+        .enumerate()
+        .map(|(i, root)| kp.set_any(root, make(i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_key_paths::KpType;
+
+    struct Reading {
+        sensor: String,
+        value: f64,
+        min: f64,
+    }
+
+    fn kps() -> Vec<SyncPKp<Reading>> {
+        vec![
+            SyncPKp::new(KpType::new(|r: &Reading| Some(&r.sensor), |r: &mut Reading| Some(&mut r.sensor))),
+            SyncPKp::new(KpType::new(|r: &Reading| Some(&r.value), |r: &mut Reading| Some(&mut r.value))),
+            SyncPKp::new(KpType::new(|r: &Reading| Some(&r.min), |r: &mut Reading| Some(&mut r.min))),
+        ]
+    }
+
+    #[test]
+    fn test_par_over_erased_keypaths() {
+        let kps = kps();
+        let reading = Reading {
+            sensor: "t1".to_string(),
+            value: 21.5,
+            min: -3.0,
+        };
+
+        assert_eq!(
+            par_get_all_as::<_, f64>(&kps, &reading),
+            vec![None, Some(&21.5), Some(&-3.0)]
+        );
+        let kinds = par_map_kps(&kps, &reading, |kp, root| kp.get(root).is_some());
+        assert_eq!(kinds, vec![true, true, true]);
+
+        let mut readings: Vec<Reading> = (0..8)
+            .map(|i| Reading {
+                sensor: format!("s{}", i),
+                value: 0.0,
+                min: 0.0,
+            })
+            .collect();
+        let results = par_set_any(&kps[1], &mut readings, |i| Box::new(i as f64));
+        assert!(results.iter().all(Result::is_ok));
+        let values = par_project_as::<_, f64>(&kps[1], &readings);
+        assert_eq!(values[7], Some(&7.0));
+        assert!(par_set_any(&kps[0], &mut readings, |_| Box::new(1u8))
+            .iter()
+            .all(Result::is_err));
+    }
+}
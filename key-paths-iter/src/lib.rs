@@ -1,6 +1,7 @@
 //! Query builder for collection keypaths over [rust_key_paths::KpType] when the value type is `Vec<Item>`.
 //!
-//! Enable the `rayon` feature for parallel collection operations ([`query_par`]).
+//! Enable the `rayon` feature for parallel collection operations ([`query_par`]) and parallel
+//! fan-out over `Send + Sync` type-erased keypaths ([`erased_par`]).
 
 use rust_key_paths::KpType;
 
@@ -10,6 +11,8 @@ pub mod query_par;
 pub mod rayon_optimizations;
 #[cfg(feature = "rayon")]
 pub mod scale_par;
+#[cfg(feature = "rayon")]
+pub mod erased_par;
 
 #[cfg(feature = "gpu")]
 pub mod wgpu;
@@ -552,6 +552,6 @@ mod tests {
     fn test_efficient_sum() {
         let data: Vec<u32> = (0..1000).collect();
         let sum = RayonPatterns::efficient_sum(&data);
-        assert_eq!(sum, (0..1000).sum());
+        assert_eq!(sum, (0..1000).sum::<u32>());
     }
 }
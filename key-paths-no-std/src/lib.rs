@@ -0,0 +1,206 @@
+//! Build check for `rust-key-paths` with `default-features = false`.
+//!
+//! CI builds this crate for a bare-metal target (`thumbv7em-none-eabihf`), so anything here that
+//! pulls in `std` breaks the build. Enable `alloc`, `spin` or `critical-section` to check the
+//! matching parts of the API.
+
+#![no_std]
+
+use key_paths_derive::Kp;
+use rust_key_paths::{EnumKp, EnumKpType, KpType, enum_some, enum_variant};
+
+#[derive(Kp)]
+pub struct Sensor {
+    pub id: u16,
+    pub reading: Reading,
+    pub calibration: Option<Calibration>,
+}
+
+#[derive(Kp)]
+pub struct Reading {
+    pub raw: u32,
+    pub scale: i8,
+}
+
+#[derive(Kp)]
+pub struct Calibration {
+    pub offset: i32,
+}
+
+#[derive(Kp)]
+pub struct Pair(pub u8, pub u8);
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Alloc-backed fields: the derive must not name `std` for these.
+#[cfg(feature = "alloc")]
+#[derive(Kp)]
+pub struct Log {
+    pub samples: alloc::vec::Vec<u8>,
+    pub label: alloc::string::String,
+    pub note: Option<alloc::string::String>,
+    pub last: alloc::boxed::Box<Reading>,
+    pub previous: Option<alloc::boxed::Box<Reading>>,
+    pub shared: alloc::sync::Arc<u8>,
+    pub local: alloc::rc::Rc<u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Kp)]
+pub struct Packet(pub alloc::vec::Vec<u8>, pub Option<alloc::string::String>, pub alloc::rc::Rc<u8>);
+
+#[cfg(feature = "alloc")]
+#[derive(Kp)]
+pub enum Payload {
+    Bytes(alloc::vec::Vec<u8>),
+    Text(alloc::string::String),
+    Boxed(alloc::boxed::Box<Reading>),
+    Shared(alloc::sync::Arc<u8>),
+    Local(alloc::rc::Rc<u8>),
+}
+
+pub enum Frame {
+    Ack(u8),
+    Data(Reading),
+}
+
+fn frame_data<'a>() -> EnumKpType<'a, Frame, Reading> {
+    enum_variant(
+        |f: &Frame| match f {
+            Frame::Data(r) => Some(r),
+            _ => None,
+        },
+        |f: &mut Frame| match f {
+            Frame::Data(r) => Some(r),
+            _ => None,
+        },
+        Frame::Data,
+    )
+}
+
+/// Derived accessors, `then`, `map`, `filter` and `identity`.
+pub fn compose(sensor: &mut Sensor) -> Option<u32> {
+    {
+        let raw = Sensor::reading().then(Reading::raw());
+        *raw.get_mut(sensor)? += 1;
+    }
+    let sensor = &*sensor;
+
+    let reading = Sensor::reading();
+    let scaled = reading.map(|r: &Reading| r.raw << r.scale.max(0));
+    let calibration = Sensor::calibration();
+    let calibrated = calibration.filter(|c: &Calibration| c.offset != 0);
+    let offset = calibrated.get(sensor).map_or(0, |c| c.offset);
+
+    let identity = KpType::<'_, Sensor, Sensor>::identity();
+    let id = identity.then(Sensor::id()).get(sensor).copied()?;
+
+    Some(scaled.get(sensor)?.wrapping_add(offset as u32).wrapping_add(u32::from(id)))
+}
+
+/// `EnumKp` extraction and embedding.
+pub fn frames(frame: &Frame) -> Option<u32> {
+    let data = frame_data();
+    let ack = Frame::Ack(0);
+    let _ = data.get(&ack);
+    let rebuilt: Frame = data.embed(Reading { raw: 1, scale: 0 });
+    let some: EnumKpType<'_, Option<u8>, u8> = enum_some();
+    let _ = some.get(&Some(Pair::f0().get(&Pair(1, 2)).copied()?));
+    let _: &dyn Fn(&Frame) -> Option<&Reading> = &|f| EnumKp::get(&data, f);
+    Some(data.get(frame)?.raw + data.get(&rebuilt)?.raw)
+}
+
+/// Derived accessors on `Vec`, `String`, `Box`, `Arc` and `Rc` fields.
+#[cfg(feature = "alloc")]
+pub fn log_summary(log: &mut Log) -> Option<u32> {
+    *Log::samples_at(0).get_mut(log)? += 1;
+    let samples: u32 = Log::samples_each().iter(log).map(|s| u32::from(*s)).sum();
+    let label = Log::label().get(log)?.len() + Log::note().get(log).map_or(0, |n| n.len());
+    let raw = Log::last().then(Reading::raw()).get(log)?;
+    let previous = rust_key_paths::ReadKp::read(&Log::previous_or_default(), log).map_or(0, |r| r.raw);
+    let shared = Log::shared().get(log)?;
+    let local = Log::local().get(log)?;
+    let packet = Packet(alloc::vec![1], None, alloc::rc::Rc::new(2));
+    let tail = Packet::f0_at(0).get(&packet)? + Packet::f2().get(&packet)?;
+    let text = Payload::text().get(&Payload::Text(alloc::string::String::from("ok")))?.len();
+    Some(samples + label as u32 + raw + previous + u32::from(shared + local + tail) + text as u32)
+}
+
+/// Bump then read a `spin::Mutex` field, holding the lock only inside each callback.
+#[cfg(feature = "spin")]
+pub fn spin_lock(root: &Board) -> Option<u32> {
+    use rust_key_paths::{ScopedLockKp, SpinMutexAccess};
+
+    let kp = ScopedLockKp::new(
+        KpType::new(|b: &Board| Some(&b.state), |b: &mut Board| Some(&mut b.state)),
+        SpinMutexAccess::new(),
+        Reading::raw(),
+    );
+    kp.with_mut(root, |raw| *raw += 1)?;
+    kp.with(root, |raw| *raw)
+}
+
+#[cfg(feature = "spin")]
+pub struct Board {
+    pub state: spin::Mutex<Reading>,
+}
+
+/// Lock step through a `critical_section::Mutex<RefCell<_>>` field.
+#[cfg(feature = "critical-section")]
+pub fn critical_section_lock(root: &Shared) -> Option<u32> {
+    use rust_key_paths::{CriticalSectionAccess, ScopedLockKp};
+
+    let kp = ScopedLockKp::new(
+        KpType::new(|s: &Shared| Some(&s.state), |s: &mut Shared| Some(&mut s.state)),
+        CriticalSectionAccess::new(),
+        Reading::raw(),
+    );
+    kp.with(root, |raw| *raw)
+}
+
+#[cfg(feature = "critical-section")]
+pub struct Shared {
+    pub state: critical_section::Mutex<core::cell::RefCell<Reading>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keypaths_work_without_std() {
+        let mut sensor = Sensor {
+            id: 3,
+            reading: Reading { raw: 4, scale: 1 },
+            calibration: Some(Calibration { offset: 2 }),
+        };
+        assert_eq!(compose(&mut sensor), Some(15));
+        assert_eq!(frames(&Frame::Data(Reading { raw: 5, scale: 0 })), Some(6));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn alloc_backed_fields() {
+        let mut log = Log {
+            samples: alloc::vec![1, 2],
+            label: alloc::string::String::from("temp"),
+            note: Some(alloc::string::String::from("c")),
+            last: alloc::boxed::Box::new(Reading { raw: 10, scale: 0 }),
+            previous: Some(alloc::boxed::Box::new(Reading { raw: 9, scale: 0 })),
+            shared: alloc::sync::Arc::new(3),
+            local: alloc::rc::Rc::new(4),
+        };
+        assert_eq!(log_summary(&mut log), Some(4 + 5 + 10 + 9 + 7 + 3 + 2));
+    }
+
+    #[cfg(feature = "spin")]
+    #[test]
+    fn spin_mutex_lock_step() {
+        let board = Board {
+            state: spin::Mutex::new(Reading { raw: 7, scale: 0 }),
+        };
+        assert_eq!(spin_lock(&board), Some(8));
+        assert_eq!(board.state.lock().raw, 8);
+    }
+}
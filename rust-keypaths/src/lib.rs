@@ -246,4801 +246,1190 @@ impl<T> Kp<Arc<Mutex<T>>, Arc<Mutex<T>>> {
     }
 }
 
-/// A composed keypath chain through Arc<Mutex<T>> - functional style
-/// Build the chain first, then apply container at get() time
+// ========== GENERIC LOCK CHAINS (compose first, lock at get time) ==========
+
+/// Whether one part of a lock chain (outer keypath, lock, inner keypath) can come up empty.
 ///
-/// # Example
-/// ```rust
-/// // Functional style: compose first, then apply container at get()
-/// ContainerTest::mutex_data_r()
-///     .chain_arc_mutex_at_kp(SomeStruct::data_r())
-///     .get(&container, |value| println!("Value: {}", value));
-/// ```
-pub struct ArcMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: KeyPath<Root, MutexValue, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
+/// A chain whose parts are all [Always] returns the callback's result directly from `get` /
+/// `get_mut`; any [Maybe] part makes it an `Option`.
+pub trait ChainMode {
+    /// What a chain in this mode returns for a callback result `R`
+    type Output<R>;
+
+    /// `value` is always `Some` for [Always]
+    fn from_option<R>(value: Option<R>) -> Self::Output<R>;
 }
 
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    ArcMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-    MutexValue: std::borrow::Borrow<Arc<Mutex<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container, executing callback with the value (read)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container);
-        arc_mutex_ref.borrow().lock().ok().map(|guard| {
-            let value = self.inner_keypath.get(&*guard);
-            callback(value)
-        })
-    }
+/// [ChainMode] of a part that always succeeds (a [KeyPath], a `parking_lot` or tokio lock)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Always;
 
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcMutexKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// [ChainMode] of a part that may fail (an optional keypath, a poisonable `std` lock)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Maybe;
 
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
+impl ChainMode for Always {
+    type Output<R> = R;
 
-        ArcMutexKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
+    fn from_option<R>(value: Option<R>) -> R {
+        value.expect("an infallible lock chain produced no value")
     }
+}
 
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
+impl ChainMode for Maybe {
+    type Output<R> = Option<R>;
 
-        ArcMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
+    fn from_option<R>(value: Option<R>) -> Option<R> {
+        value
     }
 }
 
-// ========== WRITABLE MUTEX KEYPATH CHAINS ==========
+/// The [ChainMode] of two parts in sequence: [Always] only if both are.
+pub trait JoinMode<Next: ChainMode>: ChainMode {
+    type Joined: ChainMode;
+}
 
-/// A composed writable keypath chain through Arc<Mutex<T>> - functional style
-/// Build the chain first, then apply container at get_mut() time
-pub struct ArcMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: KeyPath<Root, MutexValue, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
+impl<Next: ChainMode> JoinMode<Next> for Always {
+    type Joined = Next;
 }
 
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    ArcMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-    MutexValue: std::borrow::Borrow<Arc<Mutex<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container with mutable access
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container);
-        arc_mutex_ref.borrow().lock().ok().map(|mut guard| {
-            let value_ref = self.inner_keypath.get_mut(&mut *guard);
-            callback(value_ref)
-        })
-    }
+impl<Next: ChainMode> JoinMode<Next> for Maybe {
+    type Joined = Maybe;
+}
 
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcMutexWritableKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// The [ChainMode] of a whole chain with these part modes.
+pub type JoinedMode<OuterMode, AccessMode, InnerMode> =
+    <<OuterMode as JoinMode<AccessMode>>::Joined as JoinMode<InnerMode>>::Joined;
 
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
+/// What `get` / `get_mut` of a chain with these part modes return for a callback result `R`.
+pub type ChainOutput<OuterMode, AccessMode, InnerMode, R> =
+    <JoinedMode<OuterMode, AccessMode, InnerMode> as ChainMode>::Output<R>;
 
-        ArcMutexWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
+/// How a [LockChain] reaches the value behind a lock, e.g. [ArcMutexAccess] for `Arc<Mutex<T>>`.
+///
+/// Every chain shape (readable or writable inner keypath, optional or not, with a [KeyPath] or an
+/// [OptionalKeyPath] to the lock) works with any implementation, so a new lock type needs only
+/// this one impl.
+pub trait LockAccess<Inner> {
+    /// The lock the outer keypath points at
+    type Lock;
+
+    /// [Always] if `with_read` / `with_write` never return `None`, else [Maybe]
+    type Mode: ChainMode;
+
+    /// Run `f` under a read (shared) lock; `None` if the lock can't be taken
+    fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&Inner) -> R) -> Option<R>;
+
+    /// Run `f` under a write (exclusive) lock; `None` if the lock can't be taken
+    fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut Inner) -> R) -> Option<R>;
+}
+
+/// Async counterpart of [LockAccess], used by [AsyncLockChain].
+#[allow(async_fn_in_trait)]
+pub trait AsyncLockAccess<Inner> {
+    /// The lock the outer keypath points at
+    type Lock;
+
+    /// [Always] if `with_read` / `with_write` never return `None`, else [Maybe]
+    type Mode: ChainMode;
+
+    /// Run `f` under a read (shared) lock; `None` if the lock can't be taken
+    async fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&Inner) -> R) -> Option<R>;
+
+    /// Run `f` under a write (exclusive) lock; `None` if the lock can't be taken
+    async fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut Inner) -> R) -> Option<R>;
+}
+
+/// [LockAccess] for `Arc<std::sync::Mutex<T>>`; a poisoned mutex gives `None`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArcMutexAccess;
+
+impl<T> LockAccess<T> for ArcMutexAccess {
+    type Lock = Arc<Mutex<T>>;
+    type Mode = Maybe;
+
+    fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&T) -> R) -> Option<R> {
+        lock.lock().ok().map(|guard| f(&guard))
     }
 
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+    fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        lock.lock().ok().map(|mut guard| f(&mut guard))
+    }
+}
 
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
+/// [LockAccess] for `Arc<std::sync::RwLock<T>>`; a poisoned lock gives `None`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArcRwLockAccess;
 
-        ArcMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
+impl<T> LockAccess<T> for ArcRwLockAccess {
+    type Lock = Arc<RwLock<T>>;
+    type Mode = Maybe;
+
+    fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&T) -> R) -> Option<R> {
+        lock.read().ok().map(|guard| f(&guard))
+    }
+
+    fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        lock.write().ok().map(|mut guard| f(&mut guard))
     }
 }
 
-/// A composed writable optional keypath chain through Arc<Mutex<T>> - functional style
-pub struct ArcMutexWritableOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: KeyPath<Root, MutexValue, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
+/// [LockAccess] for `Arc<parking_lot::Mutex<T>>`
+#[cfg(feature = "parking_lot")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArcParkingMutexAccess;
+
+#[cfg(feature = "parking_lot")]
+impl<T> LockAccess<T> for ArcParkingMutexAccess {
+    type Lock = Arc<parking_lot::Mutex<T>>;
+    type Mode = Always;
+
+    fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&T) -> R) -> Option<R> {
+        Some(f(&lock.lock()))
+    }
+
+    fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        Some(f(&mut lock.lock()))
+    }
 }
 
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    ArcMutexWritableOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-    MutexValue: std::borrow::Borrow<Arc<Mutex<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (if value exists)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container);
-        arc_mutex_ref.borrow().lock().ok().and_then(|mut guard| {
-            self.inner_keypath
-                .get_mut(&mut *guard)
-                .map(|value_ref| callback(value_ref))
-        })
+/// [LockAccess] for `Arc<parking_lot::RwLock<T>>`
+#[cfg(feature = "parking_lot")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArcParkingRwLockAccess;
+
+#[cfg(feature = "parking_lot")]
+impl<T> LockAccess<T> for ArcParkingRwLockAccess {
+    type Lock = Arc<parking_lot::RwLock<T>>;
+    type Mode = Always;
+
+    fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&T) -> R) -> Option<R> {
+        Some(f(&lock.read()))
     }
 
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+    fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        Some(f(&mut lock.write()))
+    }
+}
 
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
+/// [AsyncLockAccess] for `Arc<tokio::sync::Mutex<T>>`
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArcTokioMutexAccess;
 
-        ArcMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
+#[cfg(feature = "tokio")]
+impl<T> AsyncLockAccess<T> for ArcTokioMutexAccess {
+    type Lock = Arc<tokio::sync::Mutex<T>>;
+    type Mode = Always;
+
+    async fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&T) -> R) -> Option<R> {
+        Some(f(&*lock.lock().await))
     }
 
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+    async fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        Some(f(&mut *lock.lock().await))
+    }
+}
 
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
+/// [AsyncLockAccess] for `Arc<tokio::sync::RwLock<T>>`
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArcTokioRwLockAccess;
 
-        ArcMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
+#[cfg(feature = "tokio")]
+impl<T> AsyncLockAccess<T> for ArcTokioRwLockAccess {
+    type Lock = Arc<tokio::sync::RwLock<T>>;
+    type Mode = Always;
+
+    async fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&T) -> R) -> Option<R> {
+        Some(f(&*lock.read().await))
+    }
+
+    async fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        Some(f(&mut *lock.write().await))
     }
 }
 
-/// A composed optional keypath chain through Arc<Mutex<T>> - functional style
-pub struct ArcMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
+/// The outer half of a lock chain: a [KeyPath], or an [OptionalKeyPath] when the lock may be absent.
+pub trait LockChainOuter {
+    type Root;
+    /// The value holding the lock; must `Borrow` the access's lock type
+    type Value;
+    type Mode: ChainMode;
+
+    fn get_lock<'r>(&self, root: &'r Self::Root) -> Option<&'r Self::Value>;
+}
+
+impl<Root, Value, F> LockChainOuter for KeyPath<Root, Value, F>
 where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
+    F: for<'r> Fn(&'r Root) -> &'r Value,
 {
-    outer_keypath: KeyPath<Root, MutexValue, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
+    type Root = Root;
+    type Value = Value;
+    type Mode = Always;
+
+    fn get_lock<'r>(&self, root: &'r Root) -> Option<&'r Value> {
+        Some(self.get(root))
+    }
 }
 
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    ArcMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
+impl<Root, Value, F> LockChainOuter for OptionalKeyPath<Root, Value, F>
 where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-    MutexValue: std::borrow::Borrow<Arc<Mutex<InnerValue>>>,
+    F: for<'r> Fn(&'r Root) -> Option<&'r Value>,
 {
-    /// Apply the composed keypath chain to a container, executing callback with the value
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container);
-        arc_mutex_ref
-            .borrow()
-            .lock()
-            .ok()
-            .and_then(|guard| self.inner_keypath.get(&*guard).map(|value| callback(value)))
+    type Root = Root;
+    type Value = Value;
+    type Mode = Maybe;
+
+    fn get_lock<'r>(&self, root: &'r Root) -> Option<&'r Value> {
+        self.get(root)
     }
+}
 
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A readable inner half of a lock chain: a [KeyPath] or an [OptionalKeyPath].
+pub trait LockChainRead {
+    type Inner;
+    type Value;
+    type Mode: ChainMode;
 
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
+    fn read<'r>(&self, inner: &'r Self::Inner) -> Option<&'r Self::Value>;
+}
 
-        ArcMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
+impl<Inner, Value, G> LockChainRead for KeyPath<Inner, Value, G>
+where
+    G: for<'r> Fn(&'r Inner) -> &'r Value,
+{
+    type Inner = Inner;
+    type Value = Value;
+    type Mode = Always;
 
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+    fn read<'r>(&self, inner: &'r Inner) -> Option<&'r Value> {
+        Some(self.get(inner))
+    }
+}
 
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
+impl<Inner, Value, G> LockChainRead for OptionalKeyPath<Inner, Value, G>
+where
+    G: for<'r> Fn(&'r Inner) -> Option<&'r Value>,
+{
+    type Inner = Inner;
+    type Value = Value;
+    type Mode = Maybe;
 
-        ArcMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
+    fn read<'r>(&self, inner: &'r Inner) -> Option<&'r Value> {
+        self.get(inner)
     }
 }
 
-/// A composed keypath chain from OptionalKeyPath through Arc<Mutex<T>> - functional style
-pub struct OptionalArcMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
+/// A writable inner half of a lock chain: a [WritableKeyPath] or a [WritableOptionalKeyPath].
+pub trait LockChainWrite {
+    type Inner;
+    type Value;
+    type Mode: ChainMode;
+
+    fn write<'r>(&self, inner: &'r mut Self::Inner) -> Option<&'r mut Self::Value>;
+}
+
+impl<Inner, Value, G> LockChainWrite for WritableKeyPath<Inner, Value, G>
 where
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
+    G: for<'r> Fn(&'r mut Inner) -> &'r mut Value,
 {
-    outer_keypath: OptionalKeyPath<Root, MutexValue, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
+    type Inner = Inner;
+    type Value = Value;
+    type Mode = Always;
+
+    fn write<'r>(&self, inner: &'r mut Inner) -> Option<&'r mut Value> {
+        Some(self.get_mut(inner))
+    }
 }
 
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    OptionalArcMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
+impl<Inner, Value, G> LockChainWrite for WritableOptionalKeyPath<Inner, Value, G>
 where
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-    MutexValue: std::borrow::Borrow<Arc<Mutex<InnerValue>>>,
+    G: for<'r> Fn(&'r mut Inner) -> Option<&'r mut Value>,
 {
-    /// Apply the composed keypath chain to a container, executing callback with the value
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        self.outer_keypath.get(container).and_then(|arc_mutex_ref| {
-            arc_mutex_ref.borrow().lock().ok().map(|guard| {
-                let value = self.inner_keypath.get(&*guard);
-                callback(value)
-            })
-        })
+    type Inner = Inner;
+    type Value = Value;
+    type Mode = Maybe;
+
+    fn write<'r>(&self, inner: &'r mut Inner) -> Option<&'r mut Value> {
+        self.get_mut(inner)
     }
+}
 
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcMutexKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A keypath chain through a lock - functional style.
+/// Build the chain first, then apply the container at `get()` / `get_mut()` time.
+///
+/// - `Access`: the lock, a [LockAccess] such as [ArcMutexAccess] or [ArcRwLockAccess]
+/// - `Outer`: [KeyPath] (or [OptionalKeyPath]) from the root to the lock
+/// - `Inner`: keypath from the locked value to the target; a [KeyPath] or [OptionalKeyPath] gives
+///   a readable chain, a [WritableKeyPath] or [WritableOptionalKeyPath] a writable one
+///
+/// The named chains (`ArcMutexKeyPathChain`, `OptionalArcParkingRwLockWritableKeyPathChain`, ...)
+/// are aliases of this type.
+///
+/// # Example
+/// ```rust
+/// use rust_keypaths::{ArcMutexAccess, KeyPath, LockChain};
+/// use std::sync::{Arc, Mutex};
+///
+/// struct Inner { data: String }
+/// struct Container { inner: Arc<Mutex<Inner>> }
+///
+/// let container = Container { inner: Arc::new(Mutex::new(Inner { data: "test".into() })) };
+/// let chain = LockChain::new(
+///     KeyPath::new(|c: &Container| &c.inner),
+///     ArcMutexAccess,
+///     KeyPath::new(|i: &Inner| &i.data),
+/// );
+/// assert_eq!(chain.get(&container, |data| assert_eq!(data, "test")), Some(()));
+/// ```
+pub struct LockChain<Access, Outer, Inner> {
+    outer_keypath: Outer,
+    access: Access,
+    inner_keypath: Inner,
+}
 
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
+/// A keypath chain through an async lock; the async counterpart of [LockChain] over an
+/// [AsyncLockAccess] such as `ArcTokioMutexAccess`.
+pub struct AsyncLockChain<Access, Outer, Inner> {
+    outer_keypath: Outer,
+    access: Access,
+    inner_keypath: Inner,
+}
 
-        OptionalArcMutexKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
+impl<Access, Outer, Inner> LockChain<Access, Outer, Inner> {
+    pub fn new(outer_keypath: Outer, access: Access, inner_keypath: Inner) -> Self {
+        Self {
+            outer_keypath,
+            access,
+            inner_keypath,
         }
     }
+}
 
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
+impl<Access, Outer, Inner> AsyncLockChain<Access, Outer, Inner> {
+    pub fn new(outer_keypath: Outer, access: Access, inner_keypath: Inner) -> Self {
+        Self {
+            outer_keypath,
+            access,
+            inner_keypath,
+        }
+    }
+}
+
+impl<Access, Outer, Inner> LockChain<Access, Outer, Inner>
+where
+    Outer: LockChainOuter,
+    Inner: LockChainRead,
+    Access: LockAccess<Inner::Inner>,
+    Outer::Value: std::borrow::Borrow<Access::Lock>,
+    Outer::Mode: JoinMode<Access::Mode>,
+    <Outer::Mode as JoinMode<Access::Mode>>::Joined: JoinMode<Inner::Mode>,
+{
+    /// Apply the composed keypath chain to a container, executing callback with the value (read)
+    /// Consumes self - functional style (compose once, apply once)
+    ///
+    /// Returns `()` when every part of the chain is infallible, `Option<()>` otherwise.
+    pub fn get<Callback>(self, container: &Outer::Root, callback: Callback) -> ChainOutput<Outer::Mode, Access::Mode, Inner::Mode, ()>
     where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
+        Callback: FnOnce(&Inner::Value),
     {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
+        let value = self.outer_keypath.get_lock(container).and_then(|lock| {
+            self.access
+                .with_read(std::borrow::Borrow::borrow(lock), |inner| {
+                    self.inner_keypath.read(inner).map(callback)
+                })
+                .flatten()
         });
-
-        OptionalArcMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
+        JoinedMode::<Outer::Mode, Access::Mode, Inner::Mode>::from_option(value)
     }
 }
 
-/// A composed optional keypath chain from OptionalKeyPath through Arc<Mutex<T>> - functional style
-pub struct OptionalArcMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
+impl<Access, Outer, Inner> LockChain<Access, Outer, Inner>
 where
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
+    Outer: LockChainOuter,
+    Inner: LockChainWrite,
+    Access: LockAccess<Inner::Inner>,
+    Outer::Value: std::borrow::Borrow<Access::Lock>,
+    Outer::Mode: JoinMode<Access::Mode>,
+    <Outer::Mode as JoinMode<Access::Mode>>::Joined: JoinMode<Inner::Mode>,
 {
-    outer_keypath: OptionalKeyPath<Root, MutexValue, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
+    /// Apply the composed keypath chain to a container with mutable access (write lock)
+    /// Consumes self - functional style (compose once, apply once)
+    ///
+    /// Returns `R` when every part of the chain is infallible, `Option<R>` otherwise.
+    pub fn get_mut<Callback, R>(self, container: &Outer::Root, callback: Callback) -> ChainOutput<Outer::Mode, Access::Mode, Inner::Mode, R>
+    where
+        Callback: FnOnce(&mut Inner::Value) -> R,
+    {
+        let value = self.outer_keypath.get_lock(container).and_then(|lock| {
+            self.access
+                .with_write(std::borrow::Borrow::borrow(lock), |inner| {
+                    self.inner_keypath.write(inner).map(callback)
+                })
+                .flatten()
+        });
+        JoinedMode::<Outer::Mode, Access::Mode, Inner::Mode>::from_option(value)
+    }
 }
 
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    OptionalArcMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
+impl<Access, Outer, Inner> AsyncLockChain<Access, Outer, Inner>
 where
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-    MutexValue: std::borrow::Borrow<Arc<Mutex<InnerValue>>>,
+    Outer: LockChainOuter,
+    Inner: LockChainRead,
+    Access: AsyncLockAccess<Inner::Inner>,
+    Outer::Value: std::borrow::Borrow<Access::Lock>,
+    Outer::Mode: JoinMode<Access::Mode>,
+    <Outer::Mode as JoinMode<Access::Mode>>::Joined: JoinMode<Inner::Mode>,
 {
-    /// Apply the composed keypath chain to a container, executing callback with the value
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
+    /// Apply the composed keypath chain to a container (read, async)
+    ///
+    /// Returns `()` when every part of the chain is infallible, `Option<()>` otherwise.
+    pub async fn get<Callback>(self, container: &Outer::Root, callback: Callback) -> ChainOutput<Outer::Mode, Access::Mode, Inner::Mode, ()>
     where
-        Callback: FnOnce(&SubValue) -> (),
+        Callback: FnOnce(&Inner::Value),
     {
-        self.outer_keypath.get(container).and_then(|arc_mutex_ref| {
-            arc_mutex_ref
-                .borrow()
-                .lock()
-                .ok()
-                .and_then(|guard| self.inner_keypath.get(&*guard).map(|value| callback(value)))
-        })
+        let value = match self.outer_keypath.get_lock(container) {
+            Some(lock) => self
+                .access
+                .with_read(std::borrow::Borrow::borrow(lock), |inner| {
+                    self.inner_keypath.read(inner).map(callback)
+                })
+                .await
+                .flatten(),
+            None => None,
+        };
+        JoinedMode::<Outer::Mode, Access::Mode, Inner::Mode>::from_option(value)
     }
+}
 
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
+impl<Access, Outer, Inner> AsyncLockChain<Access, Outer, Inner>
+where
+    Outer: LockChainOuter,
+    Inner: LockChainWrite,
+    Access: AsyncLockAccess<Inner::Inner>,
+    Outer::Value: std::borrow::Borrow<Access::Lock>,
+    Outer::Mode: JoinMode<Access::Mode>,
+    <Outer::Mode as JoinMode<Access::Mode>>::Joined: JoinMode<Inner::Mode>,
+{
+    /// Apply the composed keypath chain to a container with mutable access (write lock, async)
+    ///
+    /// Returns `R` when every part of the chain is infallible, `Option<R>` otherwise.
+    pub async fn get_mut<Callback, R>(
         self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
+        container: &Outer::Root,
+        callback: Callback,
+    ) -> ChainOutput<Outer::Mode, Access::Mode, Inner::Mode, R>
     where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
+        Callback: FnOnce(&mut Inner::Value) -> R,
     {
-        let first = self.inner_keypath;
-        let second = next;
+        let value = match self.outer_keypath.get_lock(container) {
+            Some(lock) => self
+                .access
+                .with_write(std::borrow::Borrow::borrow(lock), |inner| {
+                    self.inner_keypath.write(inner).map(callback)
+                })
+                .await
+                .flatten(),
+            None => None,
+        };
+        JoinedMode::<Outer::Mode, Access::Mode, Inner::Mode>::from_option(value)
+    }
+}
 
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
+// `then` / `chain_optional` compose the inner keypath and are the same for sync and async chains.
+macro_rules! impl_lock_chain_composition {
+    ($chain:ident) => {
+        impl<Access, Outer, InnerValue, SubValue, G>
+            $chain<Access, Outer, KeyPath<InnerValue, SubValue, G>>
+        where
+            G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
+        {
+            /// Chain with another readable keypath through another level
+            pub fn then<NextValue, H>(
+                self,
+                next: KeyPath<SubValue, NextValue, H>,
+            ) -> $chain<
+                Access,
+                Outer,
+                KeyPath<
+                    InnerValue,
+                    NextValue,
+                    impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
+                >,
+            >
+            where
+                H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
+                G: 'static,
+                H: 'static,
+                InnerValue: 'static,
+                SubValue: 'static,
+                NextValue: 'static,
+            {
+                let first = self.inner_keypath;
+                let composed = KeyPath::new(move |inner: &InnerValue| next.get(first.get(inner)));
+                $chain::new(self.outer_keypath, self.access, composed)
+            }
 
-        OptionalArcMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
+            /// Chain with an optional readable keypath through another level
+            pub fn chain_optional<NextValue, H>(
+                self,
+                next: OptionalKeyPath<SubValue, NextValue, H>,
+            ) -> $chain<
+                Access,
+                Outer,
+                OptionalKeyPath<
+                    InnerValue,
+                    NextValue,
+                    impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
+                >,
+            >
+            where
+                H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
+                G: 'static,
+                H: 'static,
+                InnerValue: 'static,
+                SubValue: 'static,
+                NextValue: 'static,
+            {
+                let first = self.inner_keypath;
+                let composed =
+                    OptionalKeyPath::new(move |inner: &InnerValue| next.get(first.get(inner)));
+                $chain::new(self.outer_keypath, self.access, composed)
+            }
         }
-    }
 
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+        impl<Access, Outer, InnerValue, SubValue, G>
+            $chain<Access, Outer, OptionalKeyPath<InnerValue, SubValue, G>>
+        where
+            G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
+        {
+            /// Chain with another readable keypath through another level
+            pub fn then<NextValue, H>(
+                self,
+                next: KeyPath<SubValue, NextValue, H>,
+            ) -> $chain<
+                Access,
+                Outer,
+                OptionalKeyPath<
+                    InnerValue,
+                    NextValue,
+                    impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
+                >,
+            >
+            where
+                H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
+                G: 'static,
+                H: 'static,
+                InnerValue: 'static,
+                SubValue: 'static,
+                NextValue: 'static,
+            {
+                let first = self.inner_keypath;
+                let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
+                    first.get(inner).map(|sub| next.get(sub))
+                });
+                $chain::new(self.outer_keypath, self.access, composed)
+            }
 
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
+            /// Chain with an optional readable keypath through another level
+            pub fn chain_optional<NextValue, H>(
+                self,
+                next: OptionalKeyPath<SubValue, NextValue, H>,
+            ) -> $chain<
+                Access,
+                Outer,
+                OptionalKeyPath<
+                    InnerValue,
+                    NextValue,
+                    impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
+                >,
+            >
+            where
+                H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
+                G: 'static,
+                H: 'static,
+                InnerValue: 'static,
+                SubValue: 'static,
+                NextValue: 'static,
+            {
+                let first = self.inner_keypath;
+                let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
+                    first.get(inner).and_then(|sub| next.get(sub))
+                });
+                $chain::new(self.outer_keypath, self.access, composed)
+            }
+        }
+
+        impl<Access, Outer, InnerValue, SubValue, G>
+            $chain<Access, Outer, WritableKeyPath<InnerValue, SubValue, G>>
+        where
+            G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
+        {
+            /// Chain with another writable keypath through another level
+            pub fn then<NextValue, H>(
+                self,
+                next: WritableKeyPath<SubValue, NextValue, H>,
+            ) -> $chain<
+                Access,
+                Outer,
+                WritableKeyPath<
+                    InnerValue,
+                    NextValue,
+                    impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
+                >,
+            >
+            where
+                H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
+                G: 'static,
+                H: 'static,
+                InnerValue: 'static,
+                SubValue: 'static,
+                NextValue: 'static,
+            {
+                let first = self.inner_keypath;
+                let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
+                    next.get_mut(first.get_mut(inner))
+                });
+                $chain::new(self.outer_keypath, self.access, composed)
+            }
 
-        OptionalArcMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
+            /// Chain with an optional writable keypath through another level
+            pub fn chain_optional<NextValue, H>(
+                self,
+                next: WritableOptionalKeyPath<SubValue, NextValue, H>,
+            ) -> $chain<
+                Access,
+                Outer,
+                WritableOptionalKeyPath<
+                    InnerValue,
+                    NextValue,
+                    impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
+                >,
+            >
+            where
+                H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
+                G: 'static,
+                H: 'static,
+                InnerValue: 'static,
+                SubValue: 'static,
+                NextValue: 'static,
+            {
+                let first = self.inner_keypath;
+                let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
+                    next.get_mut(first.get_mut(inner))
+                });
+                $chain::new(self.outer_keypath, self.access, composed)
+            }
         }
-    }
+
+        impl<Access, Outer, InnerValue, SubValue, G>
+            $chain<Access, Outer, WritableOptionalKeyPath<InnerValue, SubValue, G>>
+        where
+            G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
+        {
+            /// Chain with another writable keypath through another level
+            pub fn then<NextValue, H>(
+                self,
+                next: WritableKeyPath<SubValue, NextValue, H>,
+            ) -> $chain<
+                Access,
+                Outer,
+                WritableOptionalKeyPath<
+                    InnerValue,
+                    NextValue,
+                    impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
+                >,
+            >
+            where
+                H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
+                G: 'static,
+                H: 'static,
+                InnerValue: 'static,
+                SubValue: 'static,
+                NextValue: 'static,
+            {
+                let first = self.inner_keypath;
+                let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
+                    first.get_mut(inner).map(|sub| next.get_mut(sub))
+                });
+                $chain::new(self.outer_keypath, self.access, composed)
+            }
+
+            /// Chain with an optional writable keypath through another level
+            pub fn chain_optional<NextValue, H>(
+                self,
+                next: WritableOptionalKeyPath<SubValue, NextValue, H>,
+            ) -> $chain<
+                Access,
+                Outer,
+                WritableOptionalKeyPath<
+                    InnerValue,
+                    NextValue,
+                    impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
+                >,
+            >
+            where
+                H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
+                G: 'static,
+                H: 'static,
+                InnerValue: 'static,
+                SubValue: 'static,
+                NextValue: 'static,
+            {
+                let first = self.inner_keypath;
+                let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
+                    first.get_mut(inner).and_then(|sub| next.get_mut(sub))
+                });
+                $chain::new(self.outer_keypath, self.access, composed)
+            }
+        }
+    };
 }
 
-// ========== FUNCTIONAL RWLOCK KEYPATH CHAINS ==========
+impl_lock_chain_composition!(LockChain);
+impl_lock_chain_composition!(AsyncLockChain);
 
-/// A composed keypath chain through Arc<RwLock<T>> - functional style
+// ========== LOCK CHAIN ALIASES ==========
+
+/// A composed keypath chain through Arc<Mutex<T>> - functional style
 /// Build the chain first, then apply container at get() time
 ///
 /// # Example
 /// ```rust
 /// // Functional style: compose first, then apply container at get()
-/// ContainerTest::rwlock_data_r()
-///     .chain_arc_rwlock_at_kp(SomeStruct::data_r())
+/// ContainerTest::mutex_data_r()
+///     .chain_arc_mutex_at_kp(SomeStruct::data_r())
 ///     .get(&container, |value| println!("Value: {}", value));
 /// ```
-pub struct ArcRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: KeyPath<Root, RwLockValue, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
-
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    ArcRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-    RwLockValue: std::borrow::Borrow<Arc<RwLock<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container, executing callback with the value (read lock)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container);
-        arc_rwlock_ref.borrow().read().ok().map(|guard| {
-            let value = self.inner_keypath.get(&*guard);
-            callback(value)
-        })
-    }
+pub type ArcMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    LockChain<ArcMutexAccess, KeyPath<Root, MutexValue, F>, KeyPath<InnerValue, SubValue, G>>;
 
-    /// Chain with another readable keypath through another level
-    /// This allows composing deeper keypaths through the same Arc<RwLock<T>> structure
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcRwLockKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed writable keypath chain through Arc<Mutex<T>> - functional style
+/// Build the chain first, then apply container at get_mut() time
+pub type ArcMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> = LockChain<
+    ArcMutexAccess,
+    KeyPath<Root, MutexValue, F>,
+    WritableKeyPath<InnerValue, SubValue, G>,
+>;
 
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
+/// A composed writable optional keypath chain through Arc<Mutex<T>> - functional style
+pub type ArcMutexWritableOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    LockChain<
+        ArcMutexAccess,
+        KeyPath<Root, MutexValue, F>,
+        WritableOptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-        ArcRwLockKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
+/// A composed optional keypath chain through Arc<Mutex<T>> - functional style
+pub type ArcMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> = LockChain<
+    ArcMutexAccess,
+    KeyPath<Root, MutexValue, F>,
+    OptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed keypath chain from OptionalKeyPath through Arc<Mutex<T>> - functional style
+pub type OptionalArcMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> = LockChain<
+    ArcMutexAccess,
+    OptionalKeyPath<Root, MutexValue, F>,
+    KeyPath<InnerValue, SubValue, G>,
+>;
 
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
+/// A composed optional keypath chain from OptionalKeyPath through Arc<Mutex<T>> - functional style
+pub type OptionalArcMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    LockChain<
+        ArcMutexAccess,
+        OptionalKeyPath<Root, MutexValue, F>,
+        OptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-        ArcRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
+/// A composed keypath chain through Arc<RwLock<T>> - functional style
+/// Build the chain first, then apply container at get() time
+///
+/// # Example
+/// ```rust
+/// // Functional style: compose first, then apply container at get()
+/// ContainerTest::rwlock_data_r()
+///     .chain_arc_rwlock_at_kp(SomeStruct::data_r())
+///     .get(&container, |value| println!("Value: {}", value));
+/// ```
+pub type ArcRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    LockChain<ArcRwLockAccess, KeyPath<Root, RwLockValue, F>, KeyPath<InnerValue, SubValue, G>>;
 
 /// A composed optional keypath chain through Arc<RwLock<T>> - functional style
-pub struct ArcRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: KeyPath<Root, RwLockValue, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    ArcRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-    RwLockValue: std::borrow::Borrow<Arc<RwLock<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container, executing callback with the value (read lock)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container);
-        arc_rwlock_ref
-            .borrow()
-            .read()
-            .ok()
-            .and_then(|guard| self.inner_keypath.get(&*guard).map(|value| callback(value)))
-    }
-}
+pub type ArcRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> = LockChain<
+    ArcRwLockAccess,
+    KeyPath<Root, RwLockValue, F>,
+    OptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
 /// A composed keypath chain from OptionalKeyPath through Arc<RwLock<T>> - functional style
-pub struct OptionalArcRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, RwLockValue, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
+pub type OptionalArcRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> = LockChain<
+    ArcRwLockAccess,
+    OptionalKeyPath<Root, RwLockValue, F>,
+    KeyPath<InnerValue, SubValue, G>,
+>;
 
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    OptionalArcRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-    RwLockValue: std::borrow::Borrow<Arc<RwLock<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container, executing callback with the value (read lock)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        self.outer_keypath
-            .get(container)
-            .and_then(|arc_rwlock_ref| {
-                arc_rwlock_ref.borrow().read().ok().map(|guard| {
-                    let value = self.inner_keypath.get(&*guard);
-                    callback(value)
-                })
-            })
-    }
+/// A composed optional keypath chain from OptionalKeyPath through Arc<RwLock<T>> - functional style
+pub type OptionalArcRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    LockChain<
+        ArcRwLockAccess,
+        OptionalKeyPath<Root, RwLockValue, F>,
+        OptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcRwLockKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed writable keypath chain from optional keypath through Arc<Mutex<T>> - functional style
+/// Build the chain first, then apply container at get_mut() time
+pub type OptionalArcMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    LockChain<
+        ArcMutexAccess,
+        OptionalKeyPath<Root, MutexValue, F>,
+        WritableKeyPath<InnerValue, SubValue, G>,
+    >;
 
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
+/// A composed writable optional keypath chain from optional keypath through Arc<Mutex<T>> - functional style
+/// Build the chain first, then apply container at get_mut() time
+pub type OptionalArcMutexWritableOptionalKeyPathChain<
+    Root,
+    MutexValue,
+    InnerValue,
+    SubValue,
+    F,
+    G,
+> = LockChain<
+    ArcMutexAccess,
+    OptionalKeyPath<Root, MutexValue, F>,
+    WritableOptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-        OptionalArcRwLockKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
+/// A composed writable keypath chain from optional keypath through Arc<RwLock<T>> - functional style
+/// Build the chain first, then apply container at get_mut() time (uses write lock)
+pub type OptionalArcRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    LockChain<
+        ArcRwLockAccess,
+        OptionalKeyPath<Root, RwLockValue, F>,
+        WritableKeyPath<InnerValue, SubValue, G>,
+    >;
 
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        OptionalArcRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed optional keypath chain from OptionalKeyPath through Arc<RwLock<T>> - functional style
-pub struct OptionalArcRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, RwLockValue, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    OptionalArcRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-    RwLockValue: std::borrow::Borrow<Arc<RwLock<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container, executing callback with the value (read lock)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        self.outer_keypath
-            .get(container)
-            .and_then(|arc_rwlock_ref| {
-                arc_rwlock_ref
-                    .borrow()
-                    .read()
-                    .ok()
-                    .and_then(|guard| self.inner_keypath.get(&*guard).map(|value| callback(value)))
-            })
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
-
-        OptionalArcRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
-
-        OptionalArcRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== WRITABLE MUTEX KEYPATH CHAINS FROM OPTIONAL KEYPATHS ==========
-
-/// A composed writable keypath chain from optional keypath through Arc<Mutex<T>> - functional style
-/// Build the chain first, then apply container at get_mut() time
-pub struct OptionalArcMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, MutexValue, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    OptionalArcMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-    MutexValue: std::borrow::Borrow<Arc<Mutex<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container with mutable access
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        self.outer_keypath.get(container).and_then(|arc_mutex_ref| {
-            arc_mutex_ref.borrow().lock().ok().map(|mut guard| {
-                let value_ref = self.inner_keypath.get_mut(&mut *guard);
-                callback(value_ref)
-            })
-        })
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcMutexWritableKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcMutexWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional keypath chain from optional keypath through Arc<Mutex<T>> - functional style
-/// Build the chain first, then apply container at get_mut() time
-pub struct OptionalArcMutexWritableOptionalKeyPathChain<
+/// A composed writable optional keypath chain from optional keypath through Arc<RwLock<T>> - functional style
+/// Build the chain first, then apply container at get_mut() time (uses write lock)
+pub type OptionalArcRwLockWritableOptionalKeyPathChain<
     Root,
-    MutexValue,
+    RwLockValue,
     InnerValue,
     SubValue,
     F,
     G,
-> where
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, MutexValue, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    OptionalArcMutexWritableOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-    MutexValue: std::borrow::Borrow<Arc<Mutex<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (if value exists)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        self.outer_keypath.get(container).and_then(|arc_mutex_ref| {
-            arc_mutex_ref.borrow().lock().ok().and_then(|mut guard| {
-                self.inner_keypath
-                    .get_mut(&mut *guard)
-                    .map(|value_ref| callback(value_ref))
-            })
-        })
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== WRITABLE RWLOCK KEYPATH CHAINS FROM OPTIONAL KEYPATHS ==========
+> = LockChain<
+    ArcRwLockAccess,
+    OptionalKeyPath<Root, RwLockValue, F>,
+    WritableOptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-/// A composed writable keypath chain from optional keypath through Arc<RwLock<T>> - functional style
+/// A composed writable keypath chain through Arc<RwLock<T>> - functional style
 /// Build the chain first, then apply container at get_mut() time (uses write lock)
-pub struct OptionalArcRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, RwLockValue, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    OptionalArcRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-    RwLockValue: std::borrow::Borrow<Arc<RwLock<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        self.outer_keypath
-            .get(container)
-            .and_then(|arc_rwlock_ref| {
-                arc_rwlock_ref.borrow().write().ok().map(|mut guard| {
-                    let value_ref = self.inner_keypath.get_mut(&mut *guard);
-                    callback(value_ref)
-                })
-            })
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcRwLockWritableKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcRwLockWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
+pub type ArcRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> = LockChain<
+    ArcRwLockAccess,
+    KeyPath<Root, RwLockValue, F>,
+    WritableKeyPath<InnerValue, SubValue, G>,
+>;
 
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed writable optional keypath chain through Arc<RwLock<T>> - functional style
+/// Build the chain first, then apply container at get_mut() time (uses write lock)
+pub type ArcRwLockWritableOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    LockChain<
+        ArcRwLockAccess,
+        KeyPath<Root, RwLockValue, F>,
+        WritableOptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
+// ========== PARKING_LOT MUTEX/RWLOCK CHAIN TYPES ==========
 
-        OptionalArcRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
+/// A composed keypath chain through Arc<parking_lot::Mutex<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type ArcParkingMutexKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingMutexAccess,
+    KeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
+    KeyPath<InnerValue, SubValue, G>,
+>;
 
-/// A composed writable optional keypath chain from optional keypath through Arc<RwLock<T>> - functional style
-/// Build the chain first, then apply container at get_mut() time (uses write lock)
-pub struct OptionalArcRwLockWritableOptionalKeyPathChain<
-    Root,
-    RwLockValue,
-    InnerValue,
-    SubValue,
-    F,
-    G,
-> where
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, RwLockValue, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
+/// A composed optional keypath chain through Arc<parking_lot::Mutex<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type ArcParkingMutexOptionalKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingMutexAccess,
+    KeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
+    OptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    OptionalArcRwLockWritableOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-    RwLockValue: std::borrow::Borrow<Arc<RwLock<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock, if value exists)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        self.outer_keypath
-            .get(container)
-            .and_then(|arc_rwlock_ref| {
-                arc_rwlock_ref.borrow().write().ok().and_then(|mut guard| {
-                    self.inner_keypath
-                        .get_mut(&mut *guard)
-                        .map(|value_ref| callback(value_ref))
-                })
-            })
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== WRITABLE RWLOCK KEYPATH CHAINS ==========
-
-/// A composed writable keypath chain through Arc<RwLock<T>> - functional style
-/// Build the chain first, then apply container at get_mut() time (uses write lock)
-pub struct ArcRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: KeyPath<Root, RwLockValue, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    ArcRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-    RwLockValue: std::borrow::Borrow<Arc<RwLock<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container);
-        arc_rwlock_ref.borrow().write().ok().map(|mut guard| {
-            let value_ref = self.inner_keypath.get_mut(&mut *guard);
-            callback(value_ref)
-        })
-    }
-
-    /// Monadic composition: chain with another writable keypath
-    /// This allows composing deeper keypaths through the same Arc<RwLock<T>> structure
-    ///
-    /// # Example
-    /// ```rust,ignore
-    /// // Compose: Root -> Arc<RwLock<InnerValue>> -> InnerValue -> SubValue -> NextValue
-    /// let chain = root_keypath
-    ///     .chain_arc_rwlock_writable_at_kp(inner_keypath)
-    ///     .then(next_keypath);
-    /// ```
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcRwLockWritableKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        // Create a new composed writable keypath
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcRwLockWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Monadic composition: chain with a writable optional keypath (for Option fields)
-    /// This allows composing through Option types within the same Arc<RwLock<T>> structure
-    ///
-    /// # Example
-    /// ```rust,ignore
-    /// // Compose: Root -> Arc<RwLock<InnerValue>> -> InnerValue -> Option<SubValue> -> NextValue
-    /// let chain = root_keypath
-    ///     .chain_arc_rwlock_writable_at_kp(inner_keypath)
-    ///     .chain_optional(optional_keypath);
-    /// ```
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        // Create a new composed writable optional keypath
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional keypath chain through Arc<RwLock<T>> - functional style
-/// Build the chain first, then apply container at get_mut() time (uses write lock)
-pub struct ArcRwLockWritableOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: KeyPath<Root, RwLockValue, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    ArcRwLockWritableOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-    RwLockValue: std::borrow::Borrow<Arc<RwLock<InnerValue>>>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock, if value exists)
-    /// Consumes self - functional style (compose once, apply once)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container);
-        arc_rwlock_ref.borrow().write().ok().and_then(|mut guard| {
-            self.inner_keypath
-                .get_mut(&mut *guard)
-                .map(|value_ref| callback(value_ref))
-        })
-    }
-
-    /// Monadic composition: chain with another writable keypath
-    /// This allows composing deeper keypaths through the same Arc<RwLock<T>> structure
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        // Create a new composed writable optional keypath
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            if let Some(sub) = first.get_mut(inner) {
-                Some(second.get_mut(sub))
-            } else {
-                None
-            }
-        });
-
-        ArcRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Monadic composition: chain with another writable optional keypath (for Option fields)
-    /// This allows composing through Option types within the same Arc<RwLock<T>> structure
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        // Create a new composed writable optional keypath
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        ArcRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== PARKING_LOT MUTEX/RWLOCK CHAIN TYPES ==========
-
-#[cfg(feature = "parking_lot")]
-use parking_lot::{Mutex as ParkingMutex, RwLock as ParkingRwLock};
-
-/// A composed keypath chain through Arc<parking_lot::Mutex<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct ArcParkingMutexKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::Mutex<InnerValue>>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: KeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G> ArcParkingMutexKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::Mutex<InnerValue>>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    /// Apply the composed keypath chain to a container (read)
-    pub fn get<Callback>(self, container: &Root, callback: Callback)
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container);
-        let guard = arc_mutex_ref.lock();
-        let value = self.inner_keypath.get(&*guard);
-        callback(value);
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingMutexKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        ArcParkingMutexKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingMutexOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        ArcParkingMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed optional keypath chain through Arc<parking_lot::Mutex<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct ArcParkingMutexOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::Mutex<InnerValue>>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: KeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
+/// A composed writable keypath chain through Arc<parking_lot::Mutex<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type ArcParkingMutexWritableKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingMutexAccess,
+    KeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
+    WritableKeyPath<InnerValue, SubValue, G>,
+>;
 
+/// A composed writable optional keypath chain through Arc<parking_lot::Mutex<T>> - functional style
 #[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    ArcParkingMutexOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::Mutex<InnerValue>>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    /// Apply the composed keypath chain to a container (read, if value exists)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container);
-        let guard = arc_mutex_ref.lock();
-        self.inner_keypath.get(&*guard).map(|value| callback(value))
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingMutexOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
-
-        ArcParkingMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingMutexOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
-
-        ArcParkingMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable keypath chain through Arc<parking_lot::Mutex<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct ArcParkingMutexWritableKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::Mutex<InnerValue>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: KeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    ArcParkingMutexWritableKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::Mutex<InnerValue>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    /// Apply the composed keypath chain to a container with mutable access
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> R
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container);
-        let mut guard = arc_mutex_ref.lock();
-        let value_ref = self.inner_keypath.get_mut(&mut *guard);
-        callback(value_ref)
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingMutexWritableKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcParkingMutexWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingMutexWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcParkingMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional keypath chain through Arc<parking_lot::Mutex<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct ArcParkingMutexWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::Mutex<InnerValue>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: KeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    ArcParkingMutexWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::Mutex<InnerValue>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (if value exists)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container);
-        let mut guard = arc_mutex_ref.lock();
-        self.inner_keypath
-            .get_mut(&mut *guard)
-            .map(|value_ref| callback(value_ref))
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingMutexWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
-
-        ArcParkingMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingMutexWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        ArcParkingMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed keypath chain through Arc<parking_lot::RwLock<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct ArcParkingRwLockKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::RwLock<InnerValue>>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: KeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    ArcParkingRwLockKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::RwLock<InnerValue>>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    /// Apply the composed keypath chain to a container (read lock)
-    pub fn get<Callback>(self, container: &Root, callback: Callback)
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container);
-        let guard = arc_rwlock_ref.read();
-        let value = self.inner_keypath.get(&*guard);
-        callback(value);
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingRwLockKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        ArcParkingRwLockKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingRwLockOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        ArcParkingRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed optional keypath chain through Arc<parking_lot::RwLock<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct ArcParkingRwLockOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::RwLock<InnerValue>>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: KeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    ArcParkingRwLockOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::RwLock<InnerValue>>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    /// Apply the composed keypath chain to a container (read lock, if value exists)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container);
-        let guard = arc_rwlock_ref.read();
-        self.inner_keypath.get(&*guard).map(|value| callback(value))
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingRwLockOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
-
-        ArcParkingRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingRwLockOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
-
-        ArcParkingRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable keypath chain through Arc<parking_lot::RwLock<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct ArcParkingRwLockWritableKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::RwLock<InnerValue>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: KeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    ArcParkingRwLockWritableKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::RwLock<InnerValue>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> R
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container);
-        let mut guard = arc_rwlock_ref.write();
-        let value_ref = self.inner_keypath.get_mut(&mut *guard);
-        callback(value_ref)
-    }
-
-    /// Monadic composition: chain with another writable keypath
-    /// This allows composing deeper keypaths through the same Arc<parking_lot::RwLock<T>> structure
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingRwLockWritableKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        // Create a new composed writable keypath
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcParkingRwLockWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Monadic composition: chain with a writable optional keypath (for Option fields)
-    /// This allows composing through Option types within the same Arc<parking_lot::RwLock<T>> structure
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingRwLockWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        // Create a new composed writable optional keypath
-        // first.get_mut returns &mut SubValue (not Option), second.get_mut returns Option<&mut NextValue>
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcParkingRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional keypath chain through Arc<parking_lot::RwLock<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct ArcParkingRwLockWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::RwLock<InnerValue>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: KeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    ArcParkingRwLockWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r Arc<parking_lot::RwLock<InnerValue>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock, if value exists)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container);
-        let mut guard = arc_rwlock_ref.write();
-        self.inner_keypath
-            .get_mut(&mut *guard)
-            .map(|value_ref| callback(value_ref))
-    }
-
-    /// Monadic composition: chain with another writable keypath
-    /// This allows composing deeper keypaths through the same Arc<parking_lot::RwLock<T>> structure
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingRwLockWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first_keypath = self.inner_keypath;
-        let second_keypath = next;
-
-        // Create a new composed writable optional keypath
-        // first_keypath.get_mut returns Option<&mut SubValue>, second_keypath.get_mut returns &mut NextValue
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first_keypath
-                .get_mut(inner)
-                .map(|sub| second_keypath.get_mut(sub))
-        });
-
-        ArcParkingRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Monadic composition: chain with another writable optional keypath (for Option fields)
-    /// This allows composing through Option types within the same Arc<parking_lot::RwLock<T>> structure
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcParkingRwLockWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        // Create a new composed writable optional keypath
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        ArcParkingRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== OPTIONAL PARKING_LOT MUTEX KEYPATH CHAINS (from OptionalKeyPath) ==========
-
-/// A composed keypath chain from optional keypath through Arc<parking_lot::Mutex<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct OptionalArcParkingMutexKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::Mutex<InnerValue>>>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    OptionalArcParkingMutexKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::Mutex<InnerValue>>>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    /// Apply the composed keypath chain to a container
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        self.outer_keypath.get(container).map(|arc_mutex_ref| {
-            let guard = arc_mutex_ref.lock();
-            let value = self.inner_keypath.get(&*guard);
-            callback(value)
-        })
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingMutexKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        OptionalArcParkingMutexKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingMutexOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        OptionalArcParkingMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed optional keypath chain from optional keypath through Arc<parking_lot::Mutex<T>>
-#[cfg(feature = "parking_lot")]
-pub struct OptionalArcParkingMutexOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::Mutex<InnerValue>>>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    OptionalArcParkingMutexOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::Mutex<InnerValue>>>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    /// Apply the composed keypath chain to a container
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        self.outer_keypath.get(container).and_then(|arc_mutex_ref| {
-            let guard = arc_mutex_ref.lock();
-            self.inner_keypath.get(&*guard).map(|value| callback(value))
-        })
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingMutexOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
-
-        OptionalArcParkingMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingMutexOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
-
-        OptionalArcParkingMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable keypath chain from optional keypath through Arc<parking_lot::Mutex<T>>
-#[cfg(feature = "parking_lot")]
-pub struct OptionalArcParkingMutexWritableKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::Mutex<InnerValue>>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    OptionalArcParkingMutexWritableKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::Mutex<InnerValue>>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    /// Apply the composed keypath chain to a container with mutable access
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        self.outer_keypath.get(container).map(|arc_mutex_ref| {
-            let mut guard = arc_mutex_ref.lock();
-            let value_ref = self.inner_keypath.get_mut(&mut *guard);
-            callback(value_ref)
-        })
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingMutexWritableKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcParkingMutexWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingMutexWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcParkingMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional keypath chain from optional keypath through Arc<parking_lot::Mutex<T>>
-#[cfg(feature = "parking_lot")]
-pub struct OptionalArcParkingMutexWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::Mutex<InnerValue>>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    OptionalArcParkingMutexWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::Mutex<InnerValue>>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (if value exists)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        self.outer_keypath.get(container).and_then(|arc_mutex_ref| {
-            let mut guard = arc_mutex_ref.lock();
-            self.inner_keypath
-                .get_mut(&mut *guard)
-                .map(|value_ref| callback(value_ref))
-        })
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingMutexWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcParkingMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingMutexWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcParkingMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== OPTIONAL PARKING_LOT RWLOCK KEYPATH CHAINS (from OptionalKeyPath) ==========
-
-/// A composed keypath chain from optional keypath through Arc<parking_lot::RwLock<T>> - functional style
-#[cfg(feature = "parking_lot")]
-pub struct OptionalArcParkingRwLockKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::RwLock<InnerValue>>>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    OptionalArcParkingRwLockKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::RwLock<InnerValue>>>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    /// Apply the composed keypath chain to a container (read lock)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        self.outer_keypath.get(container).map(|arc_rwlock_ref| {
-            let guard = arc_rwlock_ref.read();
-            let value = self.inner_keypath.get(&*guard);
-            callback(value)
-        })
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingRwLockKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        OptionalArcParkingRwLockKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingRwLockOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        OptionalArcParkingRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed optional keypath chain from optional keypath through Arc<parking_lot::RwLock<T>>
-#[cfg(feature = "parking_lot")]
-pub struct OptionalArcParkingRwLockOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::RwLock<InnerValue>>>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    OptionalArcParkingRwLockOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::RwLock<InnerValue>>>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    /// Apply the composed keypath chain to a container (read lock)
-    pub fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        self.outer_keypath
-            .get(container)
-            .and_then(|arc_rwlock_ref| {
-                let guard = arc_rwlock_ref.read();
-                self.inner_keypath.get(&*guard).map(|value| callback(value))
-            })
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingRwLockOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
-
-        OptionalArcParkingRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingRwLockOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
-
-        OptionalArcParkingRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable keypath chain from optional keypath through Arc<parking_lot::RwLock<T>>
-#[cfg(feature = "parking_lot")]
-pub struct OptionalArcParkingRwLockWritableKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::RwLock<InnerValue>>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    OptionalArcParkingRwLockWritableKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::RwLock<InnerValue>>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        self.outer_keypath.get(container).map(|arc_rwlock_ref| {
-            let mut guard = arc_rwlock_ref.write();
-            let value_ref = self.inner_keypath.get_mut(&mut *guard);
-            callback(value_ref)
-        })
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingRwLockWritableKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcParkingRwLockWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingRwLockWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcParkingRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional keypath chain from optional keypath through Arc<parking_lot::RwLock<T>>
-#[cfg(feature = "parking_lot")]
-pub struct OptionalArcParkingRwLockWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::RwLock<InnerValue>>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "parking_lot")]
-impl<Root, InnerValue, SubValue, F, G>
-    OptionalArcParkingRwLockWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> Option<&'r Arc<parking_lot::RwLock<InnerValue>>>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock, if value exists)
-    pub fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        self.outer_keypath
-            .get(container)
-            .and_then(|arc_rwlock_ref| {
-                let mut guard = arc_rwlock_ref.write();
-                self.inner_keypath
-                    .get_mut(&mut *guard)
-                    .map(|value_ref| callback(value_ref))
-            })
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingRwLockWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcParkingRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcParkingRwLockWritableOptionalKeyPathChain<
-        Root,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcParkingRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== TOKIO MUTEX/RWLOCK CHAIN TYPES ==========
-
-#[cfg(feature = "tokio")]
-use tokio::sync::{Mutex as TokioMutex, RwLock as TokioRwLock};
-
-/// A composed async keypath chain through Arc<tokio::sync::Mutex<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct ArcTokioMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: KeyPath<Root, MutexValue, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    ArcTokioMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    /// Apply the composed keypath chain to a container (read, async)
-    pub async fn get<Callback>(self, container: &Root, callback: Callback)
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container).borrow();
-        let guard = arc_mutex_ref.lock().await;
-        let value = self.inner_keypath.get(&*guard);
-        callback(value);
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioMutexKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        ArcTokioMutexKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        ArcTokioMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed optional async keypath chain through Arc<tokio::sync::Mutex<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct ArcTokioMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: KeyPath<Root, MutexValue, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    ArcTokioMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    /// Apply the composed keypath chain to a container (read, if value exists, async)
-    pub async fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container).borrow();
-        let guard = arc_mutex_ref.lock().await;
-        self.inner_keypath.get(&*guard).map(|value| callback(value))
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
-
-        ArcTokioMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
-
-        ArcTokioMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable async keypath chain through Arc<tokio::sync::Mutex<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct ArcTokioMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: KeyPath<Root, MutexValue, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    ArcTokioMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    /// Apply the composed keypath chain to a container with mutable access (async)
-    pub async fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> R
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container).borrow();
-        let mut guard = arc_mutex_ref.lock().await;
-        let value_ref = self.inner_keypath.get_mut(&mut *guard);
-        callback(value_ref)
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioMutexWritableKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcTokioMutexWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcTokioMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional async keypath chain through Arc<tokio::sync::Mutex<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct ArcTokioMutexWritableOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: KeyPath<Root, MutexValue, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    ArcTokioMutexWritableOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r MutexValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (if value exists, async)
-    pub async fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_mutex_ref = self.outer_keypath.get(container).borrow();
-        let mut guard = arc_mutex_ref.lock().await;
-        self.inner_keypath
-            .get_mut(&mut *guard)
-            .map(|value_ref| callback(value_ref))
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
-
-        ArcTokioMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        ArcTokioMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed async keypath chain through Arc<tokio::sync::RwLock<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct ArcTokioRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: KeyPath<Root, RwLockValue, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    ArcTokioRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    /// Apply the composed keypath chain to a container (read lock, async)
-    pub async fn get<Callback>(self, container: &Root, callback: Callback)
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container).borrow();
-        let guard = arc_rwlock_ref.read().await;
-        let value = self.inner_keypath.get(&*guard);
-        callback(value);
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioRwLockKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        ArcTokioRwLockKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        ArcTokioRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed optional async keypath chain through Arc<tokio::sync::RwLock<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct ArcTokioRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: KeyPath<Root, RwLockValue, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    ArcTokioRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    /// Apply the composed keypath chain to a container (read lock, if value exists, async)
-    pub async fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container).borrow();
-        let guard = arc_rwlock_ref.read().await;
-        self.inner_keypath.get(&*guard).map(|value| callback(value))
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
-
-        ArcTokioRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
-
-        ArcTokioRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable async keypath chain through Arc<tokio::sync::RwLock<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct ArcTokioRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: KeyPath<Root, RwLockValue, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    ArcTokioRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock, async)
-    pub async fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> R
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container).borrow();
-        let mut guard = arc_rwlock_ref.write().await;
-        let value_ref = self.inner_keypath.get_mut(&mut *guard);
-        callback(value_ref)
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioRwLockWritableKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcTokioRwLockWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        ArcTokioRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional async keypath chain through Arc<tokio::sync::RwLock<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct ArcTokioRwLockWritableOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: KeyPath<Root, RwLockValue, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    ArcTokioRwLockWritableOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> &'r RwLockValue,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock, if value exists, async)
-    pub async fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        let arc_rwlock_ref = self.outer_keypath.get(container).borrow();
-        let mut guard = arc_rwlock_ref.write().await;
-        self.inner_keypath
-            .get_mut(&mut *guard)
-            .map(|value_ref| callback(value_ref))
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
-
-        ArcTokioRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> ArcTokioRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        ArcTokioRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== OPTIONAL TOKIO MUTEX KEYPATH CHAINS (from OptionalKeyPath) ==========
-
-/// A composed async keypath chain from optional keypath through Arc<tokio::sync::Mutex<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct OptionalArcTokioMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, MutexValue, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    OptionalArcTokioMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    /// Apply the composed keypath chain to a container (async)
-    pub async fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        if let Some(arc_mutex_ref) = self.outer_keypath.get(container) {
-            let arc_mutex_ref = arc_mutex_ref.borrow();
-            let guard = arc_mutex_ref.lock().await;
-            let value = self.inner_keypath.get(&*guard);
-            callback(value);
-            Some(())
-        } else {
-            None
-        }
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioMutexKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        OptionalArcTokioMutexKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
-
-        OptionalArcTokioMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed optional async keypath chain from optional keypath through Arc<tokio::sync::Mutex<T>>
-#[cfg(feature = "tokio")]
-pub struct OptionalArcTokioMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, MutexValue, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    OptionalArcTokioMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    /// Apply the composed keypath chain to a container (async)
-    pub async fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        if let Some(arc_mutex_ref) = self.outer_keypath.get(container) {
-            let arc_mutex_ref = arc_mutex_ref.borrow();
-            let guard = arc_mutex_ref.lock().await;
-            self.inner_keypath.get(&*guard).map(|value| callback(value))
-        } else {
-            None
-        }
-    }
-
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
-
-        OptionalArcTokioMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioMutexOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
-
-        OptionalArcTokioMutexOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable async keypath chain from optional keypath through Arc<tokio::sync::Mutex<T>>
-#[cfg(feature = "tokio")]
-pub struct OptionalArcTokioMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, MutexValue, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    OptionalArcTokioMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    /// Apply the composed keypath chain to a container with mutable access (async)
-    pub async fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        if let Some(arc_mutex_ref) = self.outer_keypath.get(container) {
-            let arc_mutex_ref = arc_mutex_ref.borrow();
-            let mut guard = arc_mutex_ref.lock().await;
-            let value_ref = self.inner_keypath.get_mut(&mut *guard);
-            Some(callback(value_ref))
-        } else {
-            None
-        }
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioMutexWritableKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcTokioMutexWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
-
-        OptionalArcTokioMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-/// A composed writable optional async keypath chain from optional keypath through Arc<tokio::sync::Mutex<T>>
-#[cfg(feature = "tokio")]
-pub struct OptionalArcTokioMutexWritableOptionalKeyPathChain<
-    Root,
-    MutexValue,
-    InnerValue,
-    SubValue,
-    F,
-    G,
-> where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, MutexValue, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
-
-#[cfg(feature = "tokio")]
-impl<Root, MutexValue, InnerValue, SubValue, F, G>
-    OptionalArcTokioMutexWritableOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G>
-where
-    MutexValue: std::borrow::Borrow<Arc<TokioMutex<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r MutexValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (if value exists, async)
-    pub async fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        if let Some(arc_mutex_ref) = self.outer_keypath.get(container) {
-            let arc_mutex_ref = arc_mutex_ref.borrow();
-            let mut guard = arc_mutex_ref.lock().await;
-            self.inner_keypath
-                .get_mut(&mut *guard)
-                .map(|value_ref| callback(value_ref))
-        } else {
-            None
-        }
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcTokioMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioMutexWritableOptionalKeyPathChain<
-        Root,
-        MutexValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
-
-        OptionalArcTokioMutexWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
-
-// ========== OPTIONAL TOKIO RWLOCK KEYPATH CHAINS (from OptionalKeyPath) ==========
-
-/// A composed async keypath chain from optional keypath through Arc<tokio::sync::RwLock<T>> - functional style
-#[cfg(feature = "tokio")]
-pub struct OptionalArcTokioRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, RwLockValue, F>,
-    inner_keypath: KeyPath<InnerValue, SubValue, G>,
-}
+pub type ArcParkingMutexWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingMutexAccess,
+    KeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
+    WritableOptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-#[cfg(feature = "tokio")]
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    OptionalArcTokioRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
-{
-    /// Apply the composed keypath chain to a container (read lock, async)
-    pub async fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        if let Some(arc_rwlock_ref) = self.outer_keypath.get(container) {
-            let arc_rwlock_ref = arc_rwlock_ref.borrow();
-            let guard = arc_rwlock_ref.read().await;
-            let value = self.inner_keypath.get(&*guard);
-            callback(value);
-            Some(())
-        } else {
-            None
-        }
-    }
+/// A composed keypath chain through Arc<parking_lot::RwLock<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type ArcParkingRwLockKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingRwLockAccess,
+    KeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
+    KeyPath<InnerValue, SubValue, G>,
+>;
 
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioRwLockKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> &'r NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed optional keypath chain through Arc<parking_lot::RwLock<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type ArcParkingRwLockOptionalKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingRwLockAccess,
+    KeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
+    OptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-        let composed = KeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
+/// A composed writable keypath chain through Arc<parking_lot::RwLock<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type ArcParkingRwLockWritableKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingRwLockAccess,
+    KeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
+    WritableKeyPath<InnerValue, SubValue, G>,
+>;
 
-        OptionalArcTokioRwLockKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
+/// A composed writable optional keypath chain through Arc<parking_lot::RwLock<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type ArcParkingRwLockWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingRwLockAccess,
+    KeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
+    WritableOptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed keypath chain from optional keypath through Arc<parking_lot::Mutex<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type OptionalArcParkingMutexKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingMutexAccess,
+    OptionalKeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
+    KeyPath<InnerValue, SubValue, G>,
+>;
 
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            let sub = first.get(inner);
-            second.get(sub)
-        });
+/// A composed optional keypath chain from optional keypath through Arc<parking_lot::Mutex<T>>
+#[cfg(feature = "parking_lot")]
+pub type OptionalArcParkingMutexOptionalKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingMutexAccess,
+    OptionalKeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
+    OptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-        OptionalArcTokioRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
+/// A composed writable keypath chain from optional keypath through Arc<parking_lot::Mutex<T>>
+#[cfg(feature = "parking_lot")]
+pub type OptionalArcParkingMutexWritableKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingMutexAccess,
+    OptionalKeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
+    WritableKeyPath<InnerValue, SubValue, G>,
+>;
 
-/// A composed optional async keypath chain from optional keypath through Arc<tokio::sync::RwLock<T>>
-#[cfg(feature = "tokio")]
-pub struct OptionalArcTokioRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, RwLockValue, F>,
-    inner_keypath: OptionalKeyPath<InnerValue, SubValue, G>,
-}
+/// A composed writable optional keypath chain from optional keypath through Arc<parking_lot::Mutex<T>>
+#[cfg(feature = "parking_lot")]
+pub type OptionalArcParkingMutexWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G> =
+    LockChain<
+        ArcParkingMutexAccess,
+        OptionalKeyPath<Root, Arc<parking_lot::Mutex<InnerValue>>, F>,
+        WritableOptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-#[cfg(feature = "tokio")]
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    OptionalArcTokioRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
-{
-    /// Apply the composed keypath chain to a container (read lock, async)
-    pub async fn get<Callback>(self, container: &Root, callback: Callback) -> Option<()>
-    where
-        Callback: FnOnce(&SubValue) -> (),
-    {
-        if let Some(arc_rwlock_ref) = self.outer_keypath.get(container) {
-            let arc_rwlock_ref = arc_rwlock_ref.borrow();
-            let guard = arc_rwlock_ref.read().await;
-            self.inner_keypath.get(&*guard).map(|value| callback(value))
-        } else {
-            None
-        }
-    }
+/// A composed keypath chain from optional keypath through Arc<parking_lot::RwLock<T>> - functional style
+#[cfg(feature = "parking_lot")]
+pub type OptionalArcParkingRwLockKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingRwLockAccess,
+    OptionalKeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
+    KeyPath<InnerValue, SubValue, G>,
+>;
 
-    /// Chain with another readable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: KeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> &'r NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed optional keypath chain from optional keypath through Arc<parking_lot::RwLock<T>>
+#[cfg(feature = "parking_lot")]
+pub type OptionalArcParkingRwLockOptionalKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingRwLockAccess,
+    OptionalKeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
+    OptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).map(|sub| second.get(sub))
-        });
+/// A composed writable keypath chain from optional keypath through Arc<parking_lot::RwLock<T>>
+#[cfg(feature = "parking_lot")]
+pub type OptionalArcParkingRwLockWritableKeyPathChain<Root, InnerValue, SubValue, F, G> = LockChain<
+    ArcParkingRwLockAccess,
+    OptionalKeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
+    WritableKeyPath<InnerValue, SubValue, G>,
+>;
 
-        OptionalArcTokioRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
+/// A composed writable optional keypath chain from optional keypath through Arc<parking_lot::RwLock<T>>
+#[cfg(feature = "parking_lot")]
+pub type OptionalArcParkingRwLockWritableOptionalKeyPathChain<Root, InnerValue, SubValue, F, G> =
+    LockChain<
+        ArcParkingRwLockAccess,
+        OptionalKeyPath<Root, Arc<parking_lot::RwLock<InnerValue>>, F>,
+        WritableOptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-    /// Chain with an optional readable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: OptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioRwLockOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r InnerValue) -> Option<&'r NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r SubValue) -> Option<&'r NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+// ========== TOKIO MUTEX/RWLOCK CHAIN TYPES ==========
 
-        let composed = OptionalKeyPath::new(move |inner: &InnerValue| {
-            first.get(inner).and_then(|sub| second.get(sub))
-        });
+/// A composed async keypath chain through Arc<tokio::sync::Mutex<T>> - functional style
+#[cfg(feature = "tokio")]
+pub type ArcTokioMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> = AsyncLockChain<
+    ArcTokioMutexAccess,
+    KeyPath<Root, MutexValue, F>,
+    KeyPath<InnerValue, SubValue, G>,
+>;
 
-        OptionalArcTokioRwLockOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
+/// A composed optional async keypath chain through Arc<tokio::sync::Mutex<T>> - functional style
+#[cfg(feature = "tokio")]
+pub type ArcTokioMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioMutexAccess,
+        KeyPath<Root, MutexValue, F>,
+        OptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-/// A composed writable async keypath chain from optional keypath through Arc<tokio::sync::RwLock<T>>
+/// A composed writable async keypath chain through Arc<tokio::sync::Mutex<T>> - functional style
 #[cfg(feature = "tokio")]
-pub struct OptionalArcTokioRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    outer_keypath: OptionalKeyPath<Root, RwLockValue, F>,
-    inner_keypath: WritableKeyPath<InnerValue, SubValue, G>,
-}
+pub type ArcTokioMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioMutexAccess,
+        KeyPath<Root, MutexValue, F>,
+        WritableKeyPath<InnerValue, SubValue, G>,
+    >;
 
+/// A composed writable optional async keypath chain through Arc<tokio::sync::Mutex<T>> - functional style
 #[cfg(feature = "tokio")]
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    OptionalArcTokioRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G>
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock, async)
-    pub async fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        if let Some(arc_rwlock_ref) = self.outer_keypath.get(container) {
-            let arc_rwlock_ref = arc_rwlock_ref.borrow();
-            let mut guard = arc_rwlock_ref.write().await;
-            let value_ref = self.inner_keypath.get_mut(&mut *guard);
-            Some(callback(value_ref))
-        } else {
-            None
-        }
-    }
+pub type ArcTokioMutexWritableOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioMutexAccess,
+        KeyPath<Root, MutexValue, F>,
+        WritableOptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioRwLockWritableKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> &'r mut NextValue + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed async keypath chain through Arc<tokio::sync::RwLock<T>> - functional style
+#[cfg(feature = "tokio")]
+pub type ArcTokioRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> = AsyncLockChain<
+    ArcTokioRwLockAccess,
+    KeyPath<Root, RwLockValue, F>,
+    KeyPath<InnerValue, SubValue, G>,
+>;
 
-        let composed = WritableKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
+/// A composed optional async keypath chain through Arc<tokio::sync::RwLock<T>> - functional style
+#[cfg(feature = "tokio")]
+pub type ArcTokioRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioRwLockAccess,
+        KeyPath<Root, RwLockValue, F>,
+        OptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-        OptionalArcTokioRwLockWritableKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
+/// A composed writable async keypath chain through Arc<tokio::sync::RwLock<T>> - functional style
+#[cfg(feature = "tokio")]
+pub type ArcTokioRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioRwLockAccess,
+        KeyPath<Root, RwLockValue, F>,
+        WritableKeyPath<InnerValue, SubValue, G>,
+    >;
 
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed writable optional async keypath chain through Arc<tokio::sync::RwLock<T>> - functional style
+#[cfg(feature = "tokio")]
+pub type ArcTokioRwLockWritableOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioRwLockAccess,
+        KeyPath<Root, RwLockValue, F>,
+        WritableOptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            let sub = first.get_mut(inner);
-            second.get_mut(sub)
-        });
+/// A composed async keypath chain from optional keypath through Arc<tokio::sync::Mutex<T>> - functional style
+#[cfg(feature = "tokio")]
+pub type OptionalArcTokioMutexKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioMutexAccess,
+        OptionalKeyPath<Root, MutexValue, F>,
+        KeyPath<InnerValue, SubValue, G>,
+    >;
 
-        OptionalArcTokioRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
+/// A composed optional async keypath chain from optional keypath through Arc<tokio::sync::Mutex<T>>
+#[cfg(feature = "tokio")]
+pub type OptionalArcTokioMutexOptionalKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioMutexAccess,
+        OptionalKeyPath<Root, MutexValue, F>,
+        OptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-/// A composed writable optional async keypath chain from optional keypath through Arc<tokio::sync::RwLock<T>>
+/// A composed writable async keypath chain from optional keypath through Arc<tokio::sync::Mutex<T>>
+#[cfg(feature = "tokio")]
+pub type OptionalArcTokioMutexWritableKeyPathChain<Root, MutexValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioMutexAccess,
+        OptionalKeyPath<Root, MutexValue, F>,
+        WritableKeyPath<InnerValue, SubValue, G>,
+    >;
+
+/// A composed writable optional async keypath chain from optional keypath through Arc<tokio::sync::Mutex<T>>
 #[cfg(feature = "tokio")]
-pub struct OptionalArcTokioRwLockWritableOptionalKeyPathChain<
+pub type OptionalArcTokioMutexWritableOptionalKeyPathChain<
     Root,
-    RwLockValue,
+    MutexValue,
     InnerValue,
     SubValue,
     F,
     G,
-> where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    outer_keypath: OptionalKeyPath<Root, RwLockValue, F>,
-    inner_keypath: WritableOptionalKeyPath<InnerValue, SubValue, G>,
-}
+> = AsyncLockChain<
+    ArcTokioMutexAccess,
+    OptionalKeyPath<Root, MutexValue, F>,
+    WritableOptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
+/// A composed async keypath chain from optional keypath through Arc<tokio::sync::RwLock<T>> - functional style
 #[cfg(feature = "tokio")]
-impl<Root, RwLockValue, InnerValue, SubValue, F, G>
-    OptionalArcTokioRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        SubValue,
-        F,
-        G,
-    >
-where
-    RwLockValue: std::borrow::Borrow<Arc<TokioRwLock<InnerValue>>>,
-    F: for<'r> Fn(&'r Root) -> Option<&'r RwLockValue>,
-    G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
-{
-    /// Apply the composed keypath chain to a container with mutable access (write lock, if value exists, async)
-    pub async fn get_mut<Callback, R>(self, container: &Root, callback: Callback) -> Option<R>
-    where
-        Callback: FnOnce(&mut SubValue) -> R,
-    {
-        if let Some(arc_rwlock_ref) = self.outer_keypath.get(container) {
-            let arc_rwlock_ref = arc_rwlock_ref.borrow();
-            let mut guard = arc_rwlock_ref.write().await;
-            self.inner_keypath
-                .get_mut(&mut *guard)
-                .map(|value_ref| callback(value_ref))
-        } else {
-            None
-        }
-    }
-
-    /// Chain with another writable keypath through another level
-    pub fn then<NextValue, H>(
-        self,
-        next: WritableKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> &'r mut NextValue,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
-
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).map(|sub| second.get_mut(sub))
-        });
+pub type OptionalArcTokioRwLockKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioRwLockAccess,
+        OptionalKeyPath<Root, RwLockValue, F>,
+        KeyPath<InnerValue, SubValue, G>,
+    >;
 
-        OptionalArcTokioRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-
-    /// Chain with an optional writable keypath through another level
-    pub fn chain_optional<NextValue, H>(
-        self,
-        next: WritableOptionalKeyPath<SubValue, NextValue, H>,
-    ) -> OptionalArcTokioRwLockWritableOptionalKeyPathChain<
-        Root,
-        RwLockValue,
-        InnerValue,
-        NextValue,
-        F,
-        impl for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut NextValue> + 'static,
-    >
-    where
-        H: for<'r> Fn(&'r mut SubValue) -> Option<&'r mut NextValue>,
-        G: 'static,
-        H: 'static,
-        InnerValue: 'static,
-        SubValue: 'static,
-        NextValue: 'static,
-    {
-        let first = self.inner_keypath;
-        let second = next;
+/// A composed optional async keypath chain from optional keypath through Arc<tokio::sync::RwLock<T>>
+#[cfg(feature = "tokio")]
+pub type OptionalArcTokioRwLockOptionalKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioRwLockAccess,
+        OptionalKeyPath<Root, RwLockValue, F>,
+        OptionalKeyPath<InnerValue, SubValue, G>,
+    >;
 
-        let composed = WritableOptionalKeyPath::new(move |inner: &mut InnerValue| {
-            first.get_mut(inner).and_then(|sub| second.get_mut(sub))
-        });
+/// A composed writable async keypath chain from optional keypath through Arc<tokio::sync::RwLock<T>>
+#[cfg(feature = "tokio")]
+pub type OptionalArcTokioRwLockWritableKeyPathChain<Root, RwLockValue, InnerValue, SubValue, F, G> =
+    AsyncLockChain<
+        ArcTokioRwLockAccess,
+        OptionalKeyPath<Root, RwLockValue, F>,
+        WritableKeyPath<InnerValue, SubValue, G>,
+    >;
 
-        OptionalArcTokioRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self.outer_keypath,
-            inner_keypath: composed,
-        }
-    }
-}
+/// A composed writable optional async keypath chain from optional keypath through Arc<tokio::sync::RwLock<T>>
+#[cfg(feature = "tokio")]
+pub type OptionalArcTokioRwLockWritableOptionalKeyPathChain<
+    Root,
+    RwLockValue,
+    InnerValue,
+    SubValue,
+    F,
+    G,
+> = AsyncLockChain<
+    ArcTokioRwLockAccess,
+    OptionalKeyPath<Root, RwLockValue, F>,
+    WritableOptionalKeyPath<InnerValue, SubValue, G>,
+>;
 
 #[cfg(feature = "tagged")]
 use tagged_core::Tagged;
@@ -5317,10 +1706,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        ArcMutexKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcMutexAccess, inner_keypath)
     }
 
     /// Chain this keypath with an optional inner keypath through Arc<Mutex<T>> - functional style
@@ -5342,10 +1728,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        ArcMutexOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcMutexAccess, inner_keypath)
     }
 
     /// Chain this keypath with an inner keypath through Arc<RwLock<T>> - functional style
@@ -5367,10 +1750,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        ArcRwLockKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcRwLockAccess, inner_keypath)
     }
 
     /// Chain this keypath with an optional inner keypath through Arc<RwLock<T>> - functional style
@@ -5392,10 +1772,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        ArcRwLockOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcRwLockAccess, inner_keypath)
     }
 
     /// Chain this keypath with a writable inner keypath through Arc<Mutex<T>> - functional style
@@ -5414,10 +1791,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        ArcMutexWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcMutexAccess, inner_keypath)
     }
 
     /// Chain this keypath with a writable optional inner keypath through Arc<Mutex<T>> - functional style
@@ -5436,10 +1810,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        ArcMutexWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcMutexAccess, inner_keypath)
     }
 
     /// Chain this keypath with a writable inner keypath through Arc<RwLock<T>> - functional style
@@ -5458,10 +1829,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        ArcRwLockWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcRwLockAccess, inner_keypath)
     }
 
     /// Chain this keypath with a writable optional inner keypath through Arc<RwLock<T>> - functional style
@@ -5480,10 +1848,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        ArcRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcRwLockAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -5496,10 +1861,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        ArcTokioMutexKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioMutexAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -5512,10 +1874,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::Mutex<InnerValue>>>,
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        ArcTokioMutexOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioMutexAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -5528,10 +1887,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::Mutex<InnerValue>>>,
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        ArcTokioMutexWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioMutexAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -5544,10 +1900,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::Mutex<InnerValue>>>,
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        ArcTokioMutexWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioMutexAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -5560,10 +1913,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::RwLock<InnerValue>>>,
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        ArcTokioRwLockKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioRwLockAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -5576,10 +1926,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::RwLock<InnerValue>>>,
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        ArcTokioRwLockOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioRwLockAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -5592,10 +1939,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::RwLock<InnerValue>>>,
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        ArcTokioRwLockWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioRwLockAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -5608,10 +1952,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::RwLock<InnerValue>>>,
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        ArcTokioRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioRwLockAccess, inner_keypath)
     }
 
     /// Monadic helper: shorthand for chain_arc_rwlock_writable_at_kp when Value is Arc<RwLock<T>>
@@ -5725,10 +2066,7 @@ where
         InnerValue: 'static,
     {
         let identity = KeyPath::new(|inner: &InnerValue| inner);
-        ArcRwLockKeyPathChain {
-            outer_keypath: self,
-            inner_keypath: identity,
-        }
+        LockChain::new(self, ArcRwLockAccess, identity)
     }
 
     /// Convert this keypath to an Arc<Mutex> chain keypath
@@ -5750,10 +2088,7 @@ where
         InnerValue: 'static,
     {
         let identity = KeyPath::new(|inner: &InnerValue| inner);
-        ArcMutexKeyPathChain {
-            outer_keypath: self,
-            inner_keypath: identity,
-        }
+        LockChain::new(self, ArcMutexAccess, identity)
     }
 
     // #[cfg(feature = "parking_lot")]
@@ -6606,10 +2941,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        ArcParkingMutexKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingMutexAccess, inner_keypath)
     }
 
     /// Chain this keypath with an optional inner keypath through Arc<parking_lot::Mutex<T>>
@@ -6620,10 +2952,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        ArcParkingMutexOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingMutexAccess, inner_keypath)
     }
 
     /// Chain this keypath with a writable inner keypath through Arc<parking_lot::Mutex<T>>
@@ -6634,10 +2963,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        ArcParkingMutexWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingMutexAccess, inner_keypath)
     }
 
     /// Chain this keypath with a writable optional inner keypath through Arc<parking_lot::Mutex<T>>
@@ -6648,10 +2974,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        ArcParkingMutexWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingMutexAccess, inner_keypath)
     }
 }
 
@@ -6668,10 +2991,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        ArcParkingRwLockKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingRwLockAccess, inner_keypath)
     }
 
     /// Chain this keypath with an optional inner keypath through Arc<parking_lot::RwLock<T>>
@@ -6682,10 +3002,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        ArcParkingRwLockOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingRwLockAccess, inner_keypath)
     }
 
     /// Chain this keypath with a writable inner keypath through Arc<parking_lot::RwLock<T>>
@@ -6696,10 +3013,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        ArcParkingRwLockWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingRwLockAccess, inner_keypath)
     }
 
     /// Chain this keypath with a writable optional inner keypath through Arc<parking_lot::RwLock<T>>
@@ -6710,10 +3024,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        ArcParkingRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingRwLockAccess, inner_keypath)
     }
 }
 
@@ -6860,10 +3171,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        OptionalArcMutexKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcMutexAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with an optional inner keypath through Arc<Mutex<T>> - functional style
@@ -6885,10 +3193,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        OptionalArcMutexOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcMutexAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with an inner keypath through Arc<RwLock<T>> - functional style
@@ -6910,10 +3215,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        OptionalArcRwLockKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcRwLockAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with an optional inner keypath through Arc<RwLock<T>> - functional style
@@ -6935,10 +3237,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        OptionalArcRwLockOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcRwLockAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with a writable inner keypath through Arc<Mutex<T>> - functional style
@@ -6960,10 +3259,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        OptionalArcMutexWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcMutexAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with a writable optional inner keypath through Arc<Mutex<T>> - functional style
@@ -6985,10 +3281,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        OptionalArcMutexWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcMutexAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with a writable inner keypath through Arc<RwLock<T>> - functional style
@@ -7010,10 +3303,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        OptionalArcRwLockWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcRwLockAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with a writable optional inner keypath through Arc<RwLock<T>> - functional style
@@ -7035,10 +3325,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        OptionalArcRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcRwLockAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -7051,10 +3338,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::Mutex<InnerValue>>>,
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        OptionalArcTokioMutexKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioMutexAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -7067,10 +3351,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::Mutex<InnerValue>>>,
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        OptionalArcTokioMutexOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioMutexAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -7083,10 +3364,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::Mutex<InnerValue>>>,
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        OptionalArcTokioMutexWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioMutexAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -7099,10 +3377,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::Mutex<InnerValue>>>,
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        OptionalArcTokioMutexWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioMutexAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -7115,10 +3390,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::RwLock<InnerValue>>>,
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        OptionalArcTokioRwLockKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioRwLockAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -7131,10 +3403,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::RwLock<InnerValue>>>,
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        OptionalArcTokioRwLockOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioRwLockAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -7147,10 +3416,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::RwLock<InnerValue>>>,
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        OptionalArcTokioRwLockWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioRwLockAccess, inner_keypath)
     }
 
     #[cfg(feature = "tokio")]
@@ -7163,10 +3429,7 @@ where
         Value: std::borrow::Borrow<Arc<tokio::sync::RwLock<InnerValue>>>,
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        OptionalArcTokioRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        AsyncLockChain::new(self, ArcTokioRwLockAccess, inner_keypath)
     }
 
     // Swift-like operator for chaining OptionalKeyPath
@@ -7388,10 +3651,7 @@ where
         InnerValue: 'static,
     {
         let identity = KeyPath::new(|inner: &InnerValue| inner);
-        OptionalArcRwLockKeyPathChain {
-            outer_keypath: self,
-            inner_keypath: identity,
-        }
+        LockChain::new(self, ArcRwLockAccess, identity)
     }
 
     /// Convert this optional keypath to an Arc<Mutex> chain keypath
@@ -7413,10 +3673,7 @@ where
         InnerValue: 'static,
     {
         let identity = KeyPath::new(|inner: &InnerValue| inner);
-        OptionalArcMutexKeyPathChain {
-            outer_keypath: self,
-            inner_keypath: identity,
-        }
+        LockChain::new(self, ArcMutexAccess, identity)
     }
 
     // #[cfg(feature = "parking_lot")]
@@ -7607,10 +3864,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        OptionalArcParkingMutexKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingMutexAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with an optional inner keypath through Arc<parking_lot::Mutex<T>>
@@ -7621,10 +3875,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        OptionalArcParkingMutexOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingMutexAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with a writable inner keypath through Arc<parking_lot::Mutex<T>>
@@ -7635,10 +3886,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        OptionalArcParkingMutexWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingMutexAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with a writable optional inner keypath through Arc<parking_lot::Mutex<T>>
@@ -7649,10 +3897,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        OptionalArcParkingMutexWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingMutexAccess, inner_keypath)
     }
 }
 
@@ -7669,10 +3914,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> &'r SubValue,
     {
-        OptionalArcParkingRwLockKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingRwLockAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with an optional inner keypath through Arc<parking_lot::RwLock<T>>
@@ -7683,10 +3925,7 @@ where
     where
         G: for<'r> Fn(&'r InnerValue) -> Option<&'r SubValue>,
     {
-        OptionalArcParkingRwLockOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingRwLockAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with a writable inner keypath through Arc<parking_lot::RwLock<T>>
@@ -7697,10 +3936,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> &'r mut SubValue,
     {
-        OptionalArcParkingRwLockWritableKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingRwLockAccess, inner_keypath)
     }
 
     /// Chain this optional keypath with a writable optional inner keypath through Arc<parking_lot::RwLock<T>>
@@ -7711,10 +3947,7 @@ where
     where
         G: for<'r> Fn(&'r mut InnerValue) -> Option<&'r mut SubValue>,
     {
-        OptionalArcParkingRwLockWritableOptionalKeyPathChain {
-            outer_keypath: self,
-            inner_keypath,
-        }
+        LockChain::new(self, ArcParkingRwLockAccess, inner_keypath)
     }
 }
 
@@ -10133,6 +6366,283 @@ impl TestKP {
     }
 }
 
+// ========== rust-key-paths LENS TRAITS ==========
+
+/// [KeyPath], [OptionalKeyPath], [WritableKeyPath] and [WritableOptionalKeyPath] as
+/// `rust_key_paths::lens` keypaths, so helpers written against `ReadKp` / `WriteKp` accept them.
+#[cfg(feature = "lens")]
+mod lens_impls {
+    use super::{KeyPath, OptionalKeyPath, WritableKeyPath, WritableOptionalKeyPath};
+    use rust_key_paths::lens::{Lens, ReadKp, WriteKp};
+
+    impl<Root, Value, F> Lens for KeyPath<Root, Value, F>
+    where
+        F: for<'r> Fn(&'r Root) -> &'r Value,
+    {
+        type Root = Root;
+        type Value = Value;
+    }
+
+    impl<Root, Value, F> ReadKp for KeyPath<Root, Value, F>
+    where
+        F: for<'r> Fn(&'r Root) -> &'r Value,
+    {
+        fn read<'a>(&self, root: &'a Root) -> Option<&'a Value> {
+            Some(self.get(root))
+        }
+    }
+
+    impl<Root, Value, F> Lens for OptionalKeyPath<Root, Value, F>
+    where
+        F: for<'r> Fn(&'r Root) -> Option<&'r Value>,
+    {
+        type Root = Root;
+        type Value = Value;
+    }
+
+    impl<Root, Value, F> ReadKp for OptionalKeyPath<Root, Value, F>
+    where
+        F: for<'r> Fn(&'r Root) -> Option<&'r Value>,
+    {
+        fn read<'a>(&self, root: &'a Root) -> Option<&'a Value> {
+            self.get(root)
+        }
+    }
+
+    impl<Root, Value, F> Lens for WritableKeyPath<Root, Value, F>
+    where
+        F: for<'r> Fn(&'r mut Root) -> &'r mut Value,
+    {
+        type Root = Root;
+        type Value = Value;
+    }
+
+    impl<Root, Value, F> WriteKp for WritableKeyPath<Root, Value, F>
+    where
+        F: for<'r> Fn(&'r mut Root) -> &'r mut Value,
+    {
+        fn write<'a>(&self, root: &'a mut Root) -> Option<&'a mut Value> {
+            Some(self.get_mut(root))
+        }
+    }
+
+    impl<Root, Value, F> Lens for WritableOptionalKeyPath<Root, Value, F>
+    where
+        F: for<'r> Fn(&'r mut Root) -> Option<&'r mut Value>,
+    {
+        type Root = Root;
+        type Value = Value;
+    }
+
+    impl<Root, Value, F> WriteKp for WritableOptionalKeyPath<Root, Value, F>
+    where
+        F: for<'r> Fn(&'r mut Root) -> Option<&'r mut Value>,
+    {
+        fn write<'a>(&self, root: &'a mut Root) -> Option<&'a mut Value> {
+            self.get_mut(root)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        struct Order {
+            total: f64,
+            note: Option<String>,
+        }
+
+        fn total_of(kp: &impl ReadKp<Root = Order, Value = f64>, order: &Order) -> Option<f64> {
+            kp.read_cloned(order)
+        }
+
+        #[test]
+        fn test_keypaths_as_lens() {
+            let mut order = Order {
+                total: 4.0,
+                note: None,
+            };
+            let total = KeyPath::new(|o: &Order| &o.total);
+            assert_eq!(total_of(&total, &order), Some(4.0));
+
+            let note = OptionalKeyPath::new(|o: &Order| o.note.as_ref());
+            assert_eq!(note.read(&order), None);
+
+            let total_mut = WritableKeyPath::new(|o: &mut Order| &mut o.total);
+            assert!(total_mut.modify(&mut order, |t| *t += 1.0));
+            let note_mut = WritableOptionalKeyPath::new(|o: &mut Order| o.note.as_mut());
+            assert_eq!(note_mut.replace(&mut order, "gift".to_string()), None);
+            assert_eq!(order.total, 5.0);
+        }
+    }
+}
+
+// ========== rust-key-paths Kp INTEROP ==========
+
+/// Conversions between [KpType] and `rust_key_paths::Kp`, and [IntoKp] for handing out `Kp`s
+/// from `#[derive(Kps)]` accessors.
+///
+/// Both sides store a getter and a setter, so the conversions move the closures across as they
+/// are (a `rust_key_paths::KpType` becomes a [Kp] and back). Read-only keypaths have no setter
+/// to give, so pair them with their writable counterpart first:
+///
+/// ```
+/// use keypaths_proc::Kps;
+/// use rust_keypaths::IntoKp;
+///
+/// #[derive(Kps)]
+/// #[All]
+/// struct User { age: Option<u32> }
+///
+/// let age = (User::age_fr(), User::age_fw()).into_kp();
+/// let mut user = User { age: Some(30) };
+/// *age.get_mut(&mut user).unwrap() += 1;
+/// assert_eq!(age.get(&user), Some(&31));
+/// ```
+#[cfg(feature = "lens")]
+mod kp_interop {
+    use super::{FailableCombinedKeyPath, KpType, OptionalKeyPath, WritableOptionalKeyPath};
+
+    /// `rust_key_paths::Kp` over references, with getter `G` and setter `S`.
+    pub type RefKp<'x, R, V, G, S> =
+        rust_key_paths::Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>;
+
+    impl<'x, R, V, G, S> From<RefKp<'x, R, V, G, S>> for KpType<R, V, G, S>
+    where
+        G: for<'r> Fn(&'r R) -> Option<&'r V>,
+        S: for<'r> Fn(&'r mut R) -> Option<&'r mut V>,
+    {
+        fn from(kp: RefKp<'x, R, V, G, S>) -> Self {
+            let (get, set) = kp.into_parts();
+            KpType::new(get, set)
+        }
+    }
+
+    impl<'x, R, V, G, S> From<KpType<R, V, G, S>> for RefKp<'x, R, V, G, S>
+    where
+        G: for<'r> Fn(&'r R) -> Option<&'r V>,
+        S: for<'r> Fn(&'r mut R) -> Option<&'r mut V>,
+    {
+        fn from(kp: KpType<R, V, G, S>) -> Self {
+            rust_key_paths::Kp::new(kp.g, kp.s)
+        }
+    }
+
+    impl<R, V, F, W> From<(OptionalKeyPath<R, V, F>, WritableOptionalKeyPath<R, V, W>)>
+        for KpType<R, V, F, W>
+    where
+        F: for<'r> Fn(&'r R) -> Option<&'r V>,
+        W: for<'r> Fn(&'r mut R) -> Option<&'r mut V>,
+    {
+        fn from((read, write): (OptionalKeyPath<R, V, F>, WritableOptionalKeyPath<R, V, W>)) -> Self {
+            KpType::new(read.getter, write.getter)
+        }
+    }
+
+    /// Drops the owned accessor.
+    impl<R, V, ReadFn, WriteFn, OwnedFn> From<FailableCombinedKeyPath<R, V, ReadFn, WriteFn, OwnedFn>>
+        for KpType<R, V, ReadFn, WriteFn>
+    where
+        ReadFn: for<'r> Fn(&'r R) -> Option<&'r V> + 'static,
+        WriteFn: for<'r> Fn(&'r mut R) -> Option<&'r mut V> + 'static,
+        OwnedFn: Fn(R) -> Option<V> + 'static,
+    {
+        fn from(kp: FailableCombinedKeyPath<R, V, ReadFn, WriteFn, OwnedFn>) -> Self {
+            KpType::new(kp.readable, kp.writable)
+        }
+    }
+
+    /// Convert a `rust-keypaths` keypath (or a readable + writable pair) into a `rust_key_paths::Kp`.
+    pub trait IntoKp<R, V> {
+        type Get: for<'r> Fn(&'r R) -> Option<&'r V>;
+        type Set: for<'r> Fn(&'r mut R) -> Option<&'r mut V>;
+
+        fn into_kp(self) -> RefKp<'static, R, V, Self::Get, Self::Set>;
+    }
+
+    impl<R, V, G, S> IntoKp<R, V> for KpType<R, V, G, S>
+    where
+        G: for<'r> Fn(&'r R) -> Option<&'r V>,
+        S: for<'r> Fn(&'r mut R) -> Option<&'r mut V>,
+    {
+        type Get = G;
+        type Set = S;
+
+        fn into_kp(self) -> RefKp<'static, R, V, G, S> {
+            self.into()
+        }
+    }
+
+    impl<R, V, F, W> IntoKp<R, V> for (OptionalKeyPath<R, V, F>, WritableOptionalKeyPath<R, V, W>)
+    where
+        F: for<'r> Fn(&'r R) -> Option<&'r V>,
+        W: for<'r> Fn(&'r mut R) -> Option<&'r mut V>,
+    {
+        type Get = F;
+        type Set = W;
+
+        fn into_kp(self) -> RefKp<'static, R, V, F, W> {
+            KpType::from(self).into()
+        }
+    }
+
+    impl<R, V, ReadFn, WriteFn, OwnedFn> IntoKp<R, V>
+        for FailableCombinedKeyPath<R, V, ReadFn, WriteFn, OwnedFn>
+    where
+        ReadFn: for<'r> Fn(&'r R) -> Option<&'r V> + 'static,
+        WriteFn: for<'r> Fn(&'r mut R) -> Option<&'r mut V> + 'static,
+        OwnedFn: Fn(R) -> Option<V> + 'static,
+    {
+        type Get = ReadFn;
+        type Set = WriteFn;
+
+        fn into_kp(self) -> RefKp<'static, R, V, ReadFn, WriteFn> {
+            KpType::from(self).into()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use rust_key_paths::lens::{ReadKp, WriteKp};
+
+        struct Account {
+            owner: Option<String>,
+            balance: i64,
+        }
+
+        #[test]
+        fn test_kp_round_trip() {
+            let mut account = Account {
+                owner: Some("Ada".to_string()),
+                balance: 10,
+            };
+
+            let balance: rust_key_paths::KpType<'static, Account, i64> = rust_key_paths::Kp::new(
+                |a: &Account| Some(&a.balance),
+                |a: &mut Account| Some(&mut a.balance),
+            );
+            let local: super::super::Kp<Account, i64> = balance.into();
+            *local.get_mut(&mut account).unwrap() += 5;
+            let back: rust_key_paths::KpType<'static, Account, i64> = local.into();
+            assert_eq!(back.get(&account), Some(&15));
+
+            let owner = (
+                OptionalKeyPath::new(|a: &Account| a.owner.as_ref()),
+                WritableOptionalKeyPath::new(|a: &mut Account| a.owner.as_mut()),
+            )
+                .into_kp();
+            owner.write(&mut account).unwrap().push('!');
+            assert_eq!(owner.read(&account).map(String::as_str), Some("Ada!"));
+            account.owner = None;
+            assert!(owner.get(&account).is_none());
+        }
+    }
+}
+
+#[cfg(feature = "lens")]
+pub use kp_interop::{IntoKp, RefKp};
+
 #[cfg(test)]
 mod testsas {
     use super::*;
@@ -11129,6 +7639,126 @@ mod tests {
         assert!(std::ptr::eq(kp.get(&x).unwrap(), &x));
         assert_eq!(kp.embed(100), 100);
     }
+
+    #[test]
+    fn test_lock_chain_aliases() {
+        struct Inner {
+            name: String,
+            nick: Option<String>,
+        }
+        struct Outer {
+            inner: Arc<RwLock<Inner>>,
+            maybe: Option<Arc<Mutex<Inner>>>,
+        }
+        let outer = Outer {
+            inner: Arc::new(RwLock::new(Inner {
+                name: "a".into(),
+                nick: None,
+            })),
+            maybe: None,
+        };
+
+        let chain: ArcRwLockWritableKeyPathChain<_, _, _, _, _, _> =
+            KeyPath::new(|o: &Outer| &o.inner)
+                .chain_arc_rwlock_writable_at_kp(WritableKeyPath::new(|i: &mut Inner| &mut i.name));
+        assert_eq!(chain.get_mut(&outer, |name| name.push('b')), Some(()));
+        assert_eq!(outer.inner.read().unwrap().name, "ab");
+
+        let nick = KeyPath::new(|o: &Outer| &o.inner)
+            .chain_arc_rwlock_at_kp(KeyPath::identity())
+            .chain_optional(OptionalKeyPath::new(|i: &Inner| i.nick.as_ref()));
+        assert_eq!(nick.get(&outer, |_| panic!("no nick")), None);
+
+        let missing = OptionalKeyPath::new(|o: &Outer| o.maybe.as_ref())
+            .chain_arc_mutex_at_kp(KeyPath::new(|i: &Inner| &i.name));
+        assert_eq!(missing.get(&outer, |_| panic!("no lock")), None);
+    }
+
+    #[cfg(feature = "parking_lot")]
+    #[test]
+    fn test_lock_chain_infallible_parts_skip_option() {
+        struct Inner {
+            hits: u32,
+            nick: Option<String>,
+        }
+        struct Outer {
+            inner: Arc<parking_lot::Mutex<Inner>>,
+        }
+        let outer = Outer {
+            inner: Arc::new(parking_lot::Mutex::new(Inner { hits: 1, nick: None })),
+        };
+
+        // KeyPath + parking_lot lock + KeyPath: nothing can fail, so no Option
+        let mut seen = 0;
+        let () = KeyPath::new(|o: &Outer| &o.inner)
+            .chain_arc_parking_mutex_at_kp(KeyPath::new(|i: &Inner| &i.hits))
+            .get(&outer, |hits| seen = *hits);
+        assert_eq!(seen, 1);
+
+        let hits: u32 = KeyPath::new(|o: &Outer| &o.inner)
+            .chain_arc_parking_mutex_writable_at_kp(WritableKeyPath::new(|i: &mut Inner| {
+                &mut i.hits
+            }))
+            .get_mut(&outer, |hits| {
+                *hits += 1;
+                *hits
+            });
+        assert_eq!(hits, 2);
+
+        // An optional part brings the Option back
+        let nick = KeyPath::new(|o: &Outer| &o.inner)
+            .then_arc_parking_mutex_optional_at_kp(OptionalKeyPath::new(|i: &Inner| {
+                i.nick.as_ref()
+            }));
+        assert_eq!(nick.get(&outer, |_| panic!("no nick")), None);
+    }
+
+    #[test]
+    fn test_lock_chain_custom_lock() {
+        // A new lock type only needs a LockAccess impl
+        struct RefCellAccess;
+
+        impl<T> LockAccess<T> for RefCellAccess {
+            type Lock = Rc<RefCell<T>>;
+            type Mode = Maybe;
+
+            fn with_read<R>(&self, lock: &Self::Lock, f: impl FnOnce(&T) -> R) -> Option<R> {
+                lock.try_borrow().ok().map(|guard| f(&guard))
+            }
+
+            fn with_write<R>(&self, lock: &Self::Lock, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+                lock.try_borrow_mut().ok().map(|mut guard| f(&mut guard))
+            }
+        }
+
+        struct Counter {
+            hits: u32,
+        }
+        struct Page {
+            counter: Rc<RefCell<Counter>>,
+        }
+        let page = Page {
+            counter: Rc::new(RefCell::new(Counter { hits: 1 })),
+        };
+
+        let hits = || {
+            LockChain::new(
+                KeyPath::new(|p: &Page| &p.counter),
+                RefCellAccess,
+                WritableKeyPath::new(|c: &mut Counter| &mut c.hits),
+            )
+        };
+        assert_eq!(
+            hits().get_mut(&page, |h| {
+                *h += 1;
+                *h
+            }),
+            Some(2)
+        );
+
+        let _held = page.counter.borrow();
+        assert_eq!(hits().get_mut(&page, |h| *h), None);
+    }
 }
 
 // ========== WithContainer Trait ==========
@@ -30,6 +30,8 @@
 //!    - Compiled away completely - zero runtime cost
 
 use crate::Kp;
+#[cfg(any(feature = "tokio", feature = "futures", feature = "async_lock"))]
+use std::sync::Arc;
 use async_trait::async_trait;
 
 // Re-export tokio sync types for convenience
@@ -1619,6 +1621,384 @@ impl<'a, T: 'static + Send + Sync> AsyncLockLike<Arc<tokio::sync::RwLock<T>>, &'
     }
 }
 
+// ============================================================================
+// Tokio OnceCell Access Implementation
+// ============================================================================
+
+#[cfg(feature = "tokio")]
+/// Async access for Arc<tokio::sync::OnceCell<T>>: reads the value once it is set.
+///
+/// A `OnceCell` has no shared mutable access, so the `&mut T` side always yields `None`.
+pub struct TokioOnceCellAccess<T> {
+    _phantom: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "tokio")]
+impl<T> TokioOnceCellAccess<T> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> Default for TokioOnceCellAccess<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> Clone for TokioOnceCellAccess<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[async_trait]
+impl<'a, T: 'static + Send + Sync> AsyncLockLike<Arc<tokio::sync::OnceCell<T>>, &'a T>
+    for TokioOnceCellAccess<T>
+{
+    async fn lock_read(&self, lock: &Arc<tokio::sync::OnceCell<T>>) -> Option<&'a T> {
+        let ptr = lock.get()? as *const T;
+        unsafe { Some(&*ptr) }
+    }
+
+    async fn lock_write(&self, lock: &mut Arc<tokio::sync::OnceCell<T>>) -> Option<&'a T> {
+        let ptr = lock.get()? as *const T;
+        unsafe { Some(&*ptr) }
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[async_trait]
+impl<'a, T: 'static + Send + Sync> AsyncLockLike<Arc<tokio::sync::OnceCell<T>>, &'a mut T>
+    for TokioOnceCellAccess<T>
+{
+    async fn lock_read(&self, _lock: &Arc<tokio::sync::OnceCell<T>>) -> Option<&'a mut T> {
+        None
+    }
+
+    async fn lock_write(&self, _lock: &mut Arc<tokio::sync::OnceCell<T>>) -> Option<&'a mut T> {
+        None
+    }
+}
+
+// ============================================================================
+// Owned guards for the runtime-agnostic locks
+// ============================================================================
+//
+// futures and async-lock can lock through an `Arc` and hand back a guard that owns it, so their
+// accessors return the guard itself: the lock stays held for as long as the caller uses the value.
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+/// Lock guard returned by [FuturesMutexAccess], [AsyncLockMutexAccess] and [AsyncLockRwLockAccess].
+///
+/// Holds the lock until dropped and derefs (and borrows) to the locked `T`.
+pub struct AsyncLockGuard<T: ?Sized, G> {
+    guard: G,
+    _value: std::marker::PhantomData<fn(&T)>,
+}
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+impl<T: ?Sized, G> AsyncLockGuard<T, G> {
+    fn new(guard: G) -> Self {
+        Self {
+            guard,
+            _value: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+impl<T: ?Sized, G: std::ops::Deref<Target = T>> std::ops::Deref for AsyncLockGuard<T, G> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+impl<T: ?Sized, G: std::ops::DerefMut<Target = T>> std::ops::DerefMut for AsyncLockGuard<T, G> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+impl<T: ?Sized, G: std::ops::Deref<Target = T>> std::borrow::Borrow<T> for AsyncLockGuard<T, G> {
+    fn borrow(&self) -> &T {
+        &self.guard
+    }
+}
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+impl<T: ?Sized, G: std::ops::DerefMut<Target = T>> std::borrow::BorrowMut<T>
+    for AsyncLockGuard<T, G>
+{
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+impl<T: ?Sized + std::fmt::Debug, G: std::ops::Deref<Target = T>> std::fmt::Debug
+    for AsyncLockGuard<T, G>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "futures")]
+/// Guard of an `Arc<futures::lock::Mutex<T>>`
+pub type FuturesMutexGuard<T> = AsyncLockGuard<T, futures_util::lock::OwnedMutexGuard<T>>;
+
+#[cfg(feature = "async_lock")]
+/// Guard of an `Arc<async_lock::Mutex<T>>`
+pub type AsyncLockMutexGuard<T> = AsyncLockGuard<T, async_lock::MutexGuardArc<T>>;
+
+#[cfg(feature = "async_lock")]
+/// Read guard of an `Arc<async_lock::RwLock<T>>`
+pub type AsyncLockReadGuard<T> = AsyncLockGuard<T, async_lock::RwLockReadGuardArc<T>>;
+
+#[cfg(feature = "async_lock")]
+/// Write guard of an `Arc<async_lock::RwLock<T>>`
+pub type AsyncLockWriteGuard<T> = AsyncLockGuard<T, async_lock::RwLockWriteGuardArc<T>>;
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+/// Guard types an [AsyncLockLike] access hands out for reads and writes; see [AsyncLockKpGuardFor].
+pub trait AsyncGuardAccess<T> {
+    type ReadGuard: std::borrow::Borrow<T>;
+    type WriteGuard: std::borrow::BorrowMut<T>;
+}
+
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+impl<R, Lock, Inner, Root, LockValue, MutRoot, MutLock, G1, S1, L>
+    AsyncLockKp<
+        R,
+        Lock,
+        Inner,
+        Inner,
+        Root,
+        LockValue,
+        <L as AsyncGuardAccess<Inner>>::ReadGuard,
+        <L as AsyncGuardAccess<Inner>>::ReadGuard,
+        MutRoot,
+        MutLock,
+        <L as AsyncGuardAccess<Inner>>::WriteGuard,
+        <L as AsyncGuardAccess<Inner>>::WriteGuard,
+        G1,
+        S1,
+        L,
+        fn(<L as AsyncGuardAccess<Inner>>::ReadGuard) -> Option<<L as AsyncGuardAccess<Inner>>::ReadGuard>,
+        fn(<L as AsyncGuardAccess<Inner>>::WriteGuard) -> Option<<L as AsyncGuardAccess<Inner>>::WriteGuard>,
+    >
+where
+    Root: std::borrow::Borrow<R>,
+    LockValue: std::borrow::Borrow<Lock>,
+    MutRoot: std::borrow::BorrowMut<R>,
+    MutLock: std::borrow::BorrowMut<Lock>,
+    G1: Fn(Root) -> Option<LockValue> + Clone,
+    S1: Fn(MutRoot) -> Option<MutLock> + Clone,
+    L: AsyncGuardAccess<Inner>
+        + AsyncLockLike<Lock, <L as AsyncGuardAccess<Inner>>::ReadGuard>
+        + AsyncLockLike<Lock, <L as AsyncGuardAccess<Inner>>::WriteGuard>
+        + Clone,
+{
+    /// AsyncLockKp through `prev`'s lock whose `get`/`get_mut` return `mid`'s guards, so the lock
+    /// stays held while the value is in use.
+    pub fn guarded(prev: Kp<R, Lock, Root, LockValue, MutRoot, MutLock, G1, S1>, mid: L) -> Self {
+        Self::new(prev, mid, Kp::new(Some, Some))
+    }
+}
+
+// ============================================================================
+// futures::lock::Mutex Access Implementation
+// ============================================================================
+
+#[cfg(feature = "futures")]
+/// Async lock access for Arc<futures::lock::Mutex<T>> (runtime-agnostic)
+pub struct FuturesMutexAccess<T> {
+    _phantom: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "futures")]
+impl<T> FuturesMutexAccess<T> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> Default for FuturesMutexAccess<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> Clone for FuturesMutexAccess<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> AsyncGuardAccess<T> for FuturesMutexAccess<T> {
+    type ReadGuard = FuturesMutexGuard<T>;
+    type WriteGuard = FuturesMutexGuard<T>;
+}
+
+#[cfg(feature = "futures")]
+#[async_trait]
+impl<T: 'static + Send + Sync> AsyncLockLike<Arc<futures_util::lock::Mutex<T>>, FuturesMutexGuard<T>>
+    for FuturesMutexAccess<T>
+{
+    async fn lock_read(&self, lock: &Arc<futures_util::lock::Mutex<T>>) -> Option<FuturesMutexGuard<T>> {
+        Some(AsyncLockGuard::new(Arc::clone(lock).lock_owned().await))
+    }
+
+    async fn lock_write(
+        &self,
+        lock: &mut Arc<futures_util::lock::Mutex<T>>,
+    ) -> Option<FuturesMutexGuard<T>> {
+        Some(AsyncLockGuard::new(Arc::clone(lock).lock_owned().await))
+    }
+}
+
+// ============================================================================
+// async-lock (smol / async-std) Mutex and RwLock Access Implementations
+// ============================================================================
+
+#[cfg(feature = "async_lock")]
+/// Async lock access for Arc<async_lock::Mutex<T>> (runtime-agnostic)
+pub struct AsyncLockMutexAccess<T> {
+    _phantom: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "async_lock")]
+impl<T> AsyncLockMutexAccess<T> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "async_lock")]
+impl<T> Default for AsyncLockMutexAccess<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "async_lock")]
+impl<T> Clone for AsyncLockMutexAccess<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "async_lock")]
+impl<T> AsyncGuardAccess<T> for AsyncLockMutexAccess<T> {
+    type ReadGuard = AsyncLockMutexGuard<T>;
+    type WriteGuard = AsyncLockMutexGuard<T>;
+}
+
+#[cfg(feature = "async_lock")]
+#[async_trait]
+impl<T: 'static + Send + Sync> AsyncLockLike<Arc<async_lock::Mutex<T>>, AsyncLockMutexGuard<T>>
+    for AsyncLockMutexAccess<T>
+{
+    async fn lock_read(&self, lock: &Arc<async_lock::Mutex<T>>) -> Option<AsyncLockMutexGuard<T>> {
+        Some(AsyncLockGuard::new(lock.lock_arc().await))
+    }
+
+    async fn lock_write(
+        &self,
+        lock: &mut Arc<async_lock::Mutex<T>>,
+    ) -> Option<AsyncLockMutexGuard<T>> {
+        Some(AsyncLockGuard::new(lock.lock_arc().await))
+    }
+}
+
+#[cfg(feature = "async_lock")]
+/// Async lock access for Arc<async_lock::RwLock<T>> (runtime-agnostic)
+pub struct AsyncLockRwLockAccess<T> {
+    _phantom: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "async_lock")]
+impl<T> AsyncLockRwLockAccess<T> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "async_lock")]
+impl<T> Default for AsyncLockRwLockAccess<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "async_lock")]
+impl<T> Clone for AsyncLockRwLockAccess<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "async_lock")]
+impl<T> AsyncGuardAccess<T> for AsyncLockRwLockAccess<T> {
+    type ReadGuard = AsyncLockReadGuard<T>;
+    type WriteGuard = AsyncLockWriteGuard<T>;
+}
+
+// Immutable access (read lock)
+#[cfg(feature = "async_lock")]
+#[async_trait]
+impl<T: 'static + Send + Sync> AsyncLockLike<Arc<async_lock::RwLock<T>>, AsyncLockReadGuard<T>>
+    for AsyncLockRwLockAccess<T>
+{
+    async fn lock_read(&self, lock: &Arc<async_lock::RwLock<T>>) -> Option<AsyncLockReadGuard<T>> {
+        Some(AsyncLockGuard::new(lock.read_arc().await))
+    }
+
+    async fn lock_write(
+        &self,
+        lock: &mut Arc<async_lock::RwLock<T>>,
+    ) -> Option<AsyncLockReadGuard<T>> {
+        Some(AsyncLockGuard::new(lock.read_arc().await))
+    }
+}
+
+// Mutable access (write lock)
+#[cfg(feature = "async_lock")]
+#[async_trait]
+impl<T: 'static + Send + Sync> AsyncLockLike<Arc<async_lock::RwLock<T>>, AsyncLockWriteGuard<T>>
+    for AsyncLockRwLockAccess<T>
+{
+    async fn lock_read(&self, lock: &Arc<async_lock::RwLock<T>>) -> Option<AsyncLockWriteGuard<T>> {
+        Some(AsyncLockGuard::new(lock.write_arc().await))
+    }
+
+    async fn lock_write(
+        &self,
+        lock: &mut Arc<async_lock::RwLock<T>>,
+    ) -> Option<AsyncLockWriteGuard<T>> {
+        Some(AsyncLockGuard::new(lock.write_arc().await))
+    }
+}
+
 // ============================================================================
 // Type aliases for derive macro (return concrete type to avoid lifetime issues)
 // ============================================================================
@@ -1626,9 +2006,9 @@ impl<'a, T: 'static + Send + Sync> AsyncLockLike<Arc<tokio::sync::RwLock<T>>, &'
 // The keypath object is 'static—references are created when get() is called
 // with a root, not when the keypath is constructed.
 
-#[cfg(feature = "tokio")]
-/// Type alias for AsyncLockKp over Arc<tokio::sync::Mutex<T>>. Use with derive macro's `_async()` methods.
-pub type AsyncLockKpMutexFor<Root, Lock, Inner> = AsyncLockKp<
+/// AsyncLockKp from `Root` through the lock field `Lock` (accessed with `L`) to its `Inner` value.
+/// Use with derive macro's `_async()` methods.
+pub type AsyncLockKpFor<Root, Lock, Inner, L> = AsyncLockKp<
     Root,
     Lock,
     Inner,
@@ -1643,33 +2023,44 @@ pub type AsyncLockKpMutexFor<Root, Lock, Inner> = AsyncLockKp<
     &'static mut Inner,
     for<'b> fn(&'b Root) -> Option<&'b Lock>,
     for<'b> fn(&'b mut Root) -> Option<&'b mut Lock>,
-    TokioMutexAccess<Inner>,
+    L,
     for<'b> fn(&'b Inner) -> Option<&'b Inner>,
     for<'b> fn(&'b mut Inner) -> Option<&'b mut Inner>,
 >;
 
-#[cfg(feature = "tokio")]
-/// Type alias for AsyncLockKp over Arc<tokio::sync::RwLock<T>>. Use with derive macro's `_async()` methods.
-pub type AsyncLockKpRwLockFor<Root, Lock, Inner> = AsyncLockKp<
+#[cfg(any(feature = "futures", feature = "async_lock"))]
+/// AsyncLockKp from `Root` through the lock field `Lock` whose `get`/`get_mut` return the guards
+/// of `L` ([AsyncGuardAccess]). Use with derive macro's `_async()` methods.
+pub type AsyncLockKpGuardFor<Root, Lock, Inner, L> = AsyncLockKp<
     Root,
     Lock,
     Inner,
     Inner,
     &'static Root,
     &'static Lock,
-    &'static Inner,
-    &'static Inner,
+    <L as AsyncGuardAccess<Inner>>::ReadGuard,
+    <L as AsyncGuardAccess<Inner>>::ReadGuard,
     &'static mut Root,
     &'static mut Lock,
-    &'static mut Inner,
-    &'static mut Inner,
+    <L as AsyncGuardAccess<Inner>>::WriteGuard,
+    <L as AsyncGuardAccess<Inner>>::WriteGuard,
     for<'b> fn(&'b Root) -> Option<&'b Lock>,
     for<'b> fn(&'b mut Root) -> Option<&'b mut Lock>,
-    TokioRwLockAccess<Inner>,
-    for<'b> fn(&'b Inner) -> Option<&'b Inner>,
-    for<'b> fn(&'b mut Inner) -> Option<&'b mut Inner>,
+    L,
+    fn(<L as AsyncGuardAccess<Inner>>::ReadGuard) -> Option<<L as AsyncGuardAccess<Inner>>::ReadGuard>,
+    fn(<L as AsyncGuardAccess<Inner>>::WriteGuard) -> Option<<L as AsyncGuardAccess<Inner>>::WriteGuard>,
 >;
 
+#[cfg(feature = "tokio")]
+/// Type alias for AsyncLockKp over Arc<tokio::sync::Mutex<T>>. Use with derive macro's `_async()` methods.
+pub type AsyncLockKpMutexFor<Root, Lock, Inner> =
+    AsyncLockKpFor<Root, Lock, Inner, TokioMutexAccess<Inner>>;
+
+#[cfg(feature = "tokio")]
+/// Type alias for AsyncLockKp over Arc<tokio::sync::RwLock<T>>. Use with derive macro's `_async()` methods.
+pub type AsyncLockKpRwLockFor<Root, Lock, Inner> =
+    AsyncLockKpFor<Root, Lock, Inner, TokioRwLockAccess<Inner>>;
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -2000,4 +2391,81 @@ mod tests {
         let result = chained.get(&root).await;
         assert_eq!(result, Some(&42));
     }
+
+    #[tokio::test]
+    async fn test_async_lock_kp_tokio_once_cell() {
+        use tokio::sync::OnceCell;
+
+        struct Root {
+            config: Arc<OnceCell<String>>,
+        }
+
+        let root = Root {
+            config: Arc::new(OnceCell::new()),
+        };
+        let prev: KpType<Root, Arc<OnceCell<String>>> =
+            Kp::new(|r: &Root| Some(&r.config), |r: &mut Root| Some(&mut r.config));
+        let next: KpType<String, String> = Kp::new(|s: &String| Some(s), |s: &mut String| Some(s));
+        let lock_kp = AsyncLockKp::new(prev, TokioOnceCellAccess::new(), next);
+
+        assert!(lock_kp.get(&root).await.is_none());
+        root.config.set("ready".to_string()).unwrap();
+        assert_eq!(lock_kp.get(&root).await.map(String::as_str), Some("ready"));
+    }
+
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn test_async_lock_kp_futures_mutex() {
+        use futures_util::lock::Mutex;
+
+        struct Root {
+            data: Arc<Mutex<i32>>,
+        }
+
+        let mut root = Root {
+            data: Arc::new(Mutex::new(1)),
+        };
+        let prev: KpType<Root, Arc<Mutex<i32>>> =
+            Kp::new(|r: &Root| Some(&r.data), |r: &mut Root| Some(&mut r.data));
+        let lock_kp = AsyncLockKp::guarded(prev, FuturesMutexAccess::new());
+
+        *lock_kp.get_mut(&mut root).await.unwrap() += 1;
+        let guard = lock_kp.get(&root).await.unwrap();
+        assert_eq!(*guard, 2);
+        assert!(root.data.try_lock().is_none());
+        drop(guard);
+        assert!(root.data.try_lock().is_some());
+    }
+
+    #[cfg(feature = "async_lock")]
+    #[tokio::test]
+    async fn test_async_lock_kp_async_lock_mutex_and_rwlock() {
+        struct Root {
+            name: Arc<async_lock::Mutex<String>>,
+            scores: Arc<async_lock::RwLock<Vec<u32>>>,
+        }
+
+        let mut root = Root {
+            name: Arc::new(async_lock::Mutex::new("a".to_string())),
+            scores: Arc::new(async_lock::RwLock::new(vec![1])),
+        };
+
+        let name = {
+            let prev: KpType<Root, Arc<async_lock::Mutex<String>>> =
+                Kp::new(|r: &Root| Some(&r.name), |r: &mut Root| Some(&mut r.name));
+            AsyncLockKp::guarded(prev, AsyncLockMutexAccess::new())
+        };
+        name.get_mut(&mut root).await.unwrap().push('b');
+        assert_eq!(name.get(&root).await.as_deref().map(String::as_str), Some("ab"));
+
+        let scores = {
+            let prev: KpType<Root, Arc<async_lock::RwLock<Vec<u32>>>> =
+                Kp::new(|r: &Root| Some(&r.scores), |r: &mut Root| Some(&mut r.scores));
+            AsyncLockKp::guarded(prev, AsyncLockRwLockAccess::new())
+        };
+        scores.get_mut(&mut root).await.unwrap().push(2);
+        let read = scores.get(&root).await.unwrap();
+        assert_eq!(*read, vec![1, 2]);
+        assert!(root.scores.try_write().is_none());
+    }
 }
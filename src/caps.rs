@@ -0,0 +1,251 @@
+//! [CapPKp]: a [PKp] that also carries the leaf's `Debug` / `Display` / `PartialEq` / `Hash` /
+//! `Clone` (and, with feature `serde`, `Serialize`) implementations.
+//!
+//! Capabilities are picked while the value type is still known, through [CapPKpBuilder]; after
+//! that the keypath is erased and `debug`, `eq`, `to_json`, ... work without naming the type.
+//! `#[derive(Pkp)]` with `#[pkp(debug, eq, ...)]` generates `cap_partial_kps()`.
+
+use std::any::{Any, TypeId};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
+
+use crate::{KpType, PKp};
+
+fn debug_any<V: Debug + 'static>(value: &dyn Any) -> Option<String> {
+    value.downcast_ref::<V>().map(|v| format!("{:?}", v))
+}
+
+fn display_any<V: Display + 'static>(value: &dyn Any) -> Option<String> {
+    value.downcast_ref::<V>().map(|v| v.to_string())
+}
+
+fn eq_any<V: PartialEq + 'static>(a: &dyn Any, b: &dyn Any) -> bool {
+    matches!((a.downcast_ref::<V>(), b.downcast_ref::<V>()), (Some(a), Some(b)) if a == b)
+}
+
+fn hash_any<V: Hash + 'static>(value: &dyn Any, mut state: &mut dyn Hasher) {
+    if let Some(v) = value.downcast_ref::<V>() {
+        v.hash(&mut state);
+    }
+}
+
+fn clone_any<V: Clone + 'static>(value: &dyn Any) -> Option<Box<dyn Any>> {
+    value
+        .downcast_ref::<V>()
+        .map(|v| Box::new(v.clone()) as Box<dyn Any>)
+}
+
+#[cfg(feature = "serde")]
+fn json_any<V: serde::Serialize + 'static>(value: &dyn Any) -> Option<serde_json::Value> {
+    value
+        .downcast_ref::<V>()
+        .and_then(|v| serde_json::to_value(v).ok())
+}
+
+type TextFn = fn(&dyn Any) -> Option<String>;
+type EqFn = fn(&dyn Any, &dyn Any) -> bool;
+type HashFn = fn(&dyn Any, &mut dyn Hasher);
+type CloneFn = fn(&dyn Any) -> Option<Box<dyn Any>>;
+#[cfg(feature = "serde")]
+type JsonFn = fn(&dyn Any) -> Option<serde_json::Value>;
+
+/// Type-erased vtable of the leaf capabilities a [CapPKp] was built with.
+#[derive(Clone, Copy, Default)]
+struct Caps {
+    debug: Option<TextFn>,
+    display: Option<TextFn>,
+    eq: Option<EqFn>,
+    hash: Option<HashFn>,
+    clone: Option<CloneFn>,
+    #[cfg(feature = "serde")]
+    json: Option<JsonFn>,
+}
+
+/// Partial keypath (root known, value erased) that can still print, compare, hash, clone and
+/// serialize its leaf.
+///
+/// Every capability method returns `None` when the keypath was built without that capability
+/// or the path does not resolve.
+///
+/// # Example
+/// ```
+/// use rust_key_paths::{CapPKp, KpType};
+///
+/// struct User { name: String, age: u32 }
+///
+/// let fields = vec![
+///     CapPKp::builder(KpType::new(|u: &User| Some(&u.name), |u: &mut User| Some(&mut u.name)))
+///         .debug()
+///         .eq()
+///         .clone_value()
+///         .build(),
+///     CapPKp::builder(KpType::new(|u: &User| Some(&u.age), |u: &mut User| Some(&mut u.age)))
+///         .debug()
+///         .display()
+///         .eq()
+///         .hash()
+///         .build(),
+/// ];
+///
+/// let a = User { name: "Ada".to_string(), age: 36 };
+/// let mut b = User { name: "Bob".to_string(), age: 36 };
+/// assert_eq!(fields[0].debug(&a).as_deref(), Some("\"Ada\""));
+/// assert_eq!(fields[1].display(&a).as_deref(), Some("36"));
+/// assert_eq!(fields[0].eq(&a, &b), Some(false));
+/// assert_eq!(fields[1].eq(&a, &b), Some(true));
+///
+/// // Copy a field between roots without knowing its type
+/// fields[0].set_any(&mut b, fields[0].clone_value(&a).unwrap()).unwrap();
+/// assert_eq!(b.name, "Ada");
+/// ```
+pub struct CapPKp<Root> {
+    pkp: PKp<Root>,
+    caps: Caps,
+}
+
+/// Builder for [CapPKp]: each method adds one capability and requires the matching bound on
+/// the value type.
+pub struct CapPKpBuilder<'a, Root, V> {
+    kp: KpType<'a, Root, V>,
+    caps: Caps,
+}
+
+impl<Root: 'static> CapPKp<Root> {
+    /// Start building from a typed keypath.
+    pub fn builder<'a, V: Any>(kp: KpType<'a, Root, V>) -> CapPKpBuilder<'a, Root, V> {
+        CapPKpBuilder {
+            kp,
+            caps: Caps::default(),
+        }
+    }
+
+    /// The plain partial keypath (drops the capabilities).
+    pub fn as_pkp(&self) -> &PKp<Root> {
+        &self.pkp
+    }
+
+    /// Get the value as a trait object
+    pub fn get<'r>(&self, root: &'r Root) -> Option<&'r dyn Any> {
+        self.pkp.get(root)
+    }
+
+    /// Try to downcast the result to a specific type
+    pub fn get_as<'a, Value: Any>(&self, root: &'a Root) -> Option<&'a Value> {
+        self.pkp.get_as(root)
+    }
+
+    /// Replace the value, checking the value type (see [PKp::set_any]).
+    pub fn set_any(&self, root: &mut Root, value: Box<dyn Any>) -> Result<(), crate::KpError> {
+        self.pkp.set_any(root, value)
+    }
+
+    /// Get the TypeId of the Value type
+    pub fn value_type_id(&self) -> TypeId {
+        self.pkp.value_type_id()
+    }
+
+    /// The leaf formatted with `{:?}`.
+    pub fn debug(&self, root: &Root) -> Option<String> {
+        self.caps.debug.and_then(|f| f(self.pkp.get(root)?))
+    }
+
+    /// The leaf formatted with `{}`.
+    pub fn display(&self, root: &Root) -> Option<String> {
+        self.caps.display.and_then(|f| f(self.pkp.get(root)?))
+    }
+
+    /// Compare the leaves of two roots. Two unresolved paths are equal; one unresolved path is not.
+    pub fn eq(&self, a: &Root, b: &Root) -> Option<bool> {
+        let eq = self.caps.eq?;
+        Some(match (self.pkp.get(a), self.pkp.get(b)) {
+            (Some(a), Some(b)) => eq(a, b),
+            (None, None) => true,
+            _ => false,
+        })
+    }
+
+    /// Feed the leaf into `state`. Returns `None` (and leaves `state` untouched) without the
+    /// capability or when the path does not resolve.
+    pub fn hash(&self, root: &Root, state: &mut dyn Hasher) -> Option<()> {
+        let hash = self.caps.hash?;
+        hash(self.pkp.get(root)?, state);
+        Some(())
+    }
+
+    /// Hash of the leaf with the std `DefaultHasher`.
+    pub fn hash_value(&self, root: &Root) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        self.hash(root, &mut hasher)?;
+        Some(hasher.finish())
+    }
+
+    /// A boxed clone of the leaf, e.g. for [CapPKp::set_any] on another root.
+    pub fn clone_value(&self, root: &Root) -> Option<Box<dyn Any>> {
+        self.caps.clone.and_then(|f| f(self.pkp.get(root)?))
+    }
+
+    /// The leaf serialized to JSON (`None` also when serialization fails).
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self, root: &Root) -> Option<serde_json::Value> {
+        self.caps.json.and_then(|f| f(self.pkp.get(root)?))
+    }
+}
+
+impl<'a, Root: 'static, V: Any> CapPKpBuilder<'a, Root, V> {
+    pub fn debug(mut self) -> Self
+    where
+        V: Debug,
+    {
+        self.caps.debug = Some(debug_any::<V>);
+        self
+    }
+
+    pub fn display(mut self) -> Self
+    where
+        V: Display,
+    {
+        self.caps.display = Some(display_any::<V>);
+        self
+    }
+
+    pub fn eq(mut self) -> Self
+    where
+        V: PartialEq,
+    {
+        self.caps.eq = Some(eq_any::<V>);
+        self
+    }
+
+    pub fn hash(mut self) -> Self
+    where
+        V: Hash,
+    {
+        self.caps.hash = Some(hash_any::<V>);
+        self
+    }
+
+    pub fn clone_value(mut self) -> Self
+    where
+        V: Clone,
+    {
+        self.caps.clone = Some(clone_any::<V>);
+        self
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn serialize(mut self) -> Self
+    where
+        V: serde::Serialize,
+    {
+        self.caps.json = Some(json_any::<V>);
+        self
+    }
+
+    pub fn build(self) -> CapPKp<Root> {
+        CapPKp {
+            pkp: PKp::new(self.kp),
+            caps: self.caps,
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Keypaths for interior-mutability and lazy-init wrappers that cannot hand out a plain `&V`
+//! through [crate::KpType]:
+//!
+//! - [CellKp]: `Cell<T>` fields — copy the value out, write it back through `&Root`.
+//! - [OnceKp]: `OnceLock<T>` / `OnceCell<T>` fields — read, or initialize through the path.
+//!
+//! The Kp derive generates `{field}_cell()` for `Cell<T>` fields and `{field}_once()` for
+//! `OnceLock<T>` / `OnceCell<T>` fields. `{field}()` keeps returning the plain [crate::KpType].
+
+use core::cell::Cell;
+
+use crate::{Kp, KpType};
+
+/// Keypath to a [Cell] field.
+///
+/// `Cell<T>` never hands out `&T`, so reads copy the value out (`T: Copy`) and writes go through
+/// a shared `&Root`. [CellKp::get_mut] uses [Cell::get_mut] when you do hold `&mut Root`.
+///
+/// # Example
+/// ```
+/// use std::cell::Cell;
+/// use rust_key_paths::CellKp;
+///
+/// struct Counter { hits: Cell<u32> }
+///
+/// let hits = CellKp::new(|c: &Counter| Some(&c.hits), |c: &mut Counter| Some(&mut c.hits));
+/// let counter = Counter { hits: Cell::new(1) };
+/// hits.set(&counter, 2).unwrap();
+/// assert_eq!(hits.get(&counter), Some(2));
+/// ```
+pub struct CellKp<R, T> {
+    get: for<'b> fn(&'b R) -> Option<&'b Cell<T>>,
+    get_mut: for<'b> fn(&'b mut R) -> Option<&'b mut Cell<T>>,
+}
+
+// CellKp holds only fn pointers; clone/copy never touch R or T.
+impl<R, T> Clone for CellKp<R, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<R, T> Copy for CellKp<R, T> {}
+
+impl<R, T> CellKp<R, T> {
+    pub const fn new(
+        get: for<'b> fn(&'b R) -> Option<&'b Cell<T>>,
+        get_mut: for<'b> fn(&'b mut R) -> Option<&'b mut Cell<T>>,
+    ) -> Self {
+        Self { get, get_mut }
+    }
+
+    /// Copy the current value out of the cell.
+    #[inline]
+    pub fn get(&self, root: &R) -> Option<T>
+    where
+        T: Copy,
+    {
+        (self.get)(root).map(Cell::get)
+    }
+
+    /// Store `value` in the cell through a shared reference.
+    /// Returns `Err(value)` if the path does not resolve (e.g. enum variant mismatch).
+    #[inline]
+    pub fn set(&self, root: &R, value: T) -> Result<(), T> {
+        match (self.get)(root) {
+            Some(cell) => {
+                cell.set(value);
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Store `value` and return the previous one.
+    /// Returns `Err(value)` if the path does not resolve.
+    #[inline]
+    pub fn replace(&self, root: &R, value: T) -> Result<T, T> {
+        match (self.get)(root) {
+            Some(cell) => Ok(cell.replace(value)),
+            None => Err(value),
+        }
+    }
+
+    /// Mutable access to the inner value via [Cell::get_mut].
+    #[inline]
+    pub fn get_mut<'b>(&self, root: &'b mut R) -> Option<&'b mut T> {
+        (self.get_mut)(root).map(Cell::get_mut)
+    }
+
+    /// The underlying keypath to the `Cell<T>` container.
+    #[inline]
+    pub fn to_kp<'a>(&self) -> KpType<'a, R, Cell<T>> {
+        Kp::new(self.get, self.get_mut)
+    }
+}
+
+impl<'a, R, T> From<KpType<'a, R, Cell<T>>> for CellKp<R, T> {
+    fn from(kp: KpType<'a, R, Cell<T>>) -> Self {
+        Self::new(kp.get, kp.set)
+    }
+}
+
+/// Once-initialized cells: [std::sync::OnceLock] and [core::cell::OnceCell].
+/// Used by [OnceKp] so one keypath type covers both.
+pub trait OnceAccess {
+    type Value;
+    fn get(&self) -> Option<&Self::Value>;
+    fn get_mut(&mut self) -> Option<&mut Self::Value>;
+    fn get_or_init<F: FnOnce() -> Self::Value>(&self, f: F) -> &Self::Value;
+    fn set(&self, value: Self::Value) -> Result<(), Self::Value>;
+}
+
+#[cfg(feature = "std")]
+impl<T> OnceAccess for std::sync::OnceLock<T> {
+    type Value = T;
+    #[inline]
+    fn get(&self) -> Option<&T> {
+        std::sync::OnceLock::get(self)
+    }
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut T> {
+        std::sync::OnceLock::get_mut(self)
+    }
+    #[inline]
+    fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+        std::sync::OnceLock::get_or_init(self, f)
+    }
+    #[inline]
+    fn set(&self, value: T) -> Result<(), T> {
+        std::sync::OnceLock::set(self, value)
+    }
+}
+
+impl<T> OnceAccess for core::cell::OnceCell<T> {
+    type Value = T;
+    #[inline]
+    fn get(&self) -> Option<&T> {
+        core::cell::OnceCell::get(self)
+    }
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut T> {
+        core::cell::OnceCell::get_mut(self)
+    }
+    #[inline]
+    fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+        core::cell::OnceCell::get_or_init(self, f)
+    }
+    #[inline]
+    fn set(&self, value: T) -> Result<(), T> {
+        core::cell::OnceCell::set(self, value)
+    }
+}
+
+/// Keypath to a once-initialized field (`OnceLock<T>`, `OnceCell<T>`).
+///
+/// [OnceKp::get] returns `None` until the cell is initialized; [OnceKp::get_or_init] initializes
+/// it through the path using only `&Root`.
+///
+/// # Example
+/// ```
+/// use std::sync::OnceLock;
+/// use rust_key_paths::OnceKp;
+///
+/// struct Config { name: OnceLock<String> }
+///
+/// let name = OnceKp::new(|c: &Config| Some(&c.name), |c: &mut Config| Some(&mut c.name));
+/// let config = Config { name: OnceLock::new() };
+/// assert_eq!(name.get(&config), None);
+/// assert_eq!(name.get_or_init(&config, || "app".to_string()).map(String::as_str), Some("app"));
+/// assert_eq!(name.get(&config).map(String::as_str), Some("app"));
+/// ```
+pub struct OnceKp<R, C> {
+    get: for<'b> fn(&'b R) -> Option<&'b C>,
+    get_mut: for<'b> fn(&'b mut R) -> Option<&'b mut C>,
+}
+
+// OnceKp holds only fn pointers; clone/copy never touch R or C.
+impl<R, C> Clone for OnceKp<R, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<R, C> Copy for OnceKp<R, C> {}
+
+impl<R, C> OnceKp<R, C> {
+    pub const fn new(
+        get: for<'b> fn(&'b R) -> Option<&'b C>,
+        get_mut: for<'b> fn(&'b mut R) -> Option<&'b mut C>,
+    ) -> Self {
+        Self { get, get_mut }
+    }
+
+    /// The underlying keypath to the cell container.
+    #[inline]
+    pub fn to_kp<'a>(&self) -> KpType<'a, R, C> {
+        Kp::new(self.get, self.get_mut)
+    }
+}
+
+impl<R, C: OnceAccess> OnceKp<R, C> {
+    /// The value if the cell has been initialized.
+    #[inline]
+    pub fn get<'b>(&self, root: &'b R) -> Option<&'b C::Value>
+    where
+        C: 'b,
+    {
+        (self.get)(root).and_then(OnceAccess::get)
+    }
+
+    /// Mutable access to the value if the cell has been initialized.
+    #[inline]
+    pub fn get_mut<'b>(&self, root: &'b mut R) -> Option<&'b mut C::Value>
+    where
+        C: 'b,
+    {
+        (self.get_mut)(root).and_then(OnceAccess::get_mut)
+    }
+
+    /// The value, initializing the cell with `f` first if needed.
+    /// Returns `None` only if the path does not resolve (`f` is not called then).
+    #[inline]
+    pub fn get_or_init<'b, F>(&self, root: &'b R, f: F) -> Option<&'b C::Value>
+    where
+        F: FnOnce() -> C::Value,
+        C: 'b,
+    {
+        (self.get)(root).map(|cell| cell.get_or_init(f))
+    }
+
+    /// Initialize the cell. Returns `Err(value)` if it was already set or the path does not resolve.
+    #[inline]
+    pub fn set(&self, root: &R, value: C::Value) -> Result<(), C::Value> {
+        match (self.get)(root) {
+            Some(cell) => cell.set(value),
+            None => Err(value),
+        }
+    }
+}
+
+impl<'a, R, C> From<KpType<'a, R, C>> for OnceKp<R, C> {
+    fn from(kp: KpType<'a, R, C>) -> Self {
+        Self::new(kp.get, kp.set)
+    }
+}
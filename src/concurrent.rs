@@ -0,0 +1,181 @@
+//! Lock-like keypaths for concurrent containers from third-party crates.
+//!
+//! - [DashMapKp] (feature `dashmap`): one entry of a `DashMap`. `get` / `get_mut` hold the
+//!   entry's shard read / write lock for as long as the returned guard lives.
+//! - [ArcSwapKp] (feature `arc_swap`): the current value of an `ArcSwap`. `load` is a lock-free
+//!   snapshot; `store` / `rcu` replace the value through `&Root`.
+//!
+//! Unlike [crate::lock::LockKp], these return the container's own guards instead of plain
+//! references, so the value cannot outlive the lock / snapshot that protects it.
+//!
+//! With the matching `key-paths-derive` features, the Kp derive generates `{field}_at(key)` for
+//! `DashMap` fields and `{field}_load()` for `ArcSwap` fields.
+
+#[cfg(feature = "dashmap")]
+pub use self::dash_map::DashMapKp;
+
+#[cfg(feature = "arc_swap")]
+pub use self::arc_swap_kp::ArcSwapKp;
+
+#[cfg(feature = "dashmap")]
+mod dash_map {
+    use std::hash::{BuildHasher, Hash};
+
+    use dashmap::DashMap;
+    use dashmap::mapref::one::{Ref, RefMut};
+
+    /// Keypath to the entry for `key` in a `DashMap` field.
+    ///
+    /// `M` is the full map type (`DashMap<K, V, S>`), so custom hashers work unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use dashmap::DashMap;
+    /// use rust_key_paths::DashMapKp;
+    ///
+    /// struct Cache { hits: DashMap<String, u32> }
+    ///
+    /// let cache = Cache { hits: DashMap::new() };
+    /// cache.hits.insert("a".to_string(), 1);
+    ///
+    /// let a = DashMapKp::new(|c: &Cache| Some(&c.hits), "a".to_string());
+    /// *a.get_mut(&cache).unwrap() += 1;
+    /// assert_eq!(a.get(&cache).map(|v| *v), Some(2));
+    /// ```
+    pub struct DashMapKp<R, M, K> {
+        map: for<'b> fn(&'b R) -> Option<&'b M>,
+        key: K,
+    }
+
+    impl<R, M, K: Clone> Clone for DashMapKp<R, M, K> {
+        fn clone(&self) -> Self {
+            Self {
+                map: self.map,
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    impl<R, M, K> DashMapKp<R, M, K> {
+        pub fn new(map: for<'b> fn(&'b R) -> Option<&'b M>, key: K) -> Self {
+            Self { map, key }
+        }
+
+        /// The key this keypath selects.
+        #[inline]
+        pub fn key(&self) -> &K {
+            &self.key
+        }
+    }
+
+    impl<R, K, V, S> DashMapKp<R, DashMap<K, V, S>, K>
+    where
+        K: Eq + Hash,
+        S: BuildHasher + Clone,
+    {
+        /// Read guard for the entry (shard read-locked while the guard lives).
+        #[inline]
+        pub fn get<'b>(&self, root: &'b R) -> Option<Ref<'b, K, V>>
+        where
+            DashMap<K, V, S>: 'b,
+        {
+            (self.map)(root).and_then(|map| map.get(&self.key))
+        }
+
+        /// Write guard for the entry (shard write-locked while the guard lives).
+        /// Takes `&Root`: the map provides interior mutability, like [crate::lock::LockKp::set].
+        #[inline]
+        pub fn get_mut<'b>(&self, root: &'b R) -> Option<RefMut<'b, K, V>>
+        where
+            DashMap<K, V, S>: 'b,
+        {
+            (self.map)(root).and_then(|map| map.get_mut(&self.key))
+        }
+
+        /// Update the entry in place under the shard write lock.
+        pub fn set<F>(&self, root: &R, updater: F) -> Result<(), String>
+        where
+            F: FnOnce(&mut V),
+        {
+            let mut entry = self
+                .get_mut(root)
+                .ok_or_else(|| "Failed to get map entry".to_string())?;
+            updater(entry.value_mut());
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "arc_swap")]
+mod arc_swap_kp {
+    use std::sync::Arc;
+
+    use arc_swap::{ArcSwap, Guard};
+
+    /// Keypath to the current value of an `ArcSwap<T>` field.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use arc_swap::ArcSwap;
+    /// use rust_key_paths::ArcSwapKp;
+    ///
+    /// struct Service { config: ArcSwap<String> }
+    ///
+    /// let svc = Service { config: ArcSwap::from_pointee("v1".to_string()) };
+    /// let config = ArcSwapKp::new(|s: &Service| Some(&s.config));
+    /// assert_eq!(config.load(&svc).map(|g| g.as_str().to_owned()), Some("v1".to_string()));
+    /// config.store(&svc, Arc::new("v2".to_string())).unwrap();
+    /// assert_eq!(config.load_full(&svc).as_deref().map(String::as_str), Some("v2"));
+    /// ```
+    pub struct ArcSwapKp<R, T> {
+        swap: for<'b> fn(&'b R) -> Option<&'b ArcSwap<T>>,
+    }
+
+    // ArcSwapKp holds only a fn pointer; clone/copy never touch R or T.
+    impl<R, T> Clone for ArcSwapKp<R, T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl<R, T> Copy for ArcSwapKp<R, T> {}
+
+    impl<R, T> ArcSwapKp<R, T> {
+        pub const fn new(swap: for<'b> fn(&'b R) -> Option<&'b ArcSwap<T>>) -> Self {
+            Self { swap }
+        }
+
+        /// Lock-free snapshot of the current value.
+        #[inline]
+        pub fn load(&self, root: &R) -> Option<Guard<Arc<T>>> {
+            (self.swap)(root).map(|swap| swap.load())
+        }
+
+        /// Current value as an owned `Arc<T>`.
+        #[inline]
+        pub fn load_full(&self, root: &R) -> Option<Arc<T>> {
+            (self.swap)(root).map(|swap| swap.load_full())
+        }
+
+        /// Replace the value. Returns `Err(value)` if the path does not resolve.
+        #[inline]
+        pub fn store(&self, root: &R, value: Arc<T>) -> Result<(), Arc<T>> {
+            match (self.swap)(root) {
+                Some(swap) => {
+                    swap.store(value);
+                    Ok(())
+                }
+                None => Err(value),
+            }
+        }
+
+        /// Read-copy-update: retries `f` until the swap succeeds, returns the previous value.
+        #[inline]
+        pub fn rcu<F>(&self, root: &R, f: F) -> Option<Arc<T>>
+        where
+            F: FnMut(&Arc<T>) -> Arc<T>,
+        {
+            (self.swap)(root).map(|swap| swap.rcu(f))
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Extension points for teaching the Kp derive your own collections and smart pointers.
+//!
+//! Two ways to opt a field in, both via the `#[kp(...)]` field attribute:
+//!
+//! - `#[kp(container)]`: the field type implements [KpContainer]; the derive generates
+//!   `{field}_at(key)`.
+//! - `#[kp(via = "MyVec::get", mut_via = "MyVec::get_mut", at)]`: name the accessor functions
+//!   directly. With `at` they take `(&C, key)` and the derive generates `{field}_at(key)`;
+//!   without it they take `&C` and the derive generates `{field}_inner()`. They may return either
+//!   `&T` or `Option<&T>` (see [IntoKpOption]).
+//!
+//! `#[kp(lock = "MyAccess")]` generates `{field}_lock()` through a [crate::LockAccess] impl.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, VecDeque};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Keyed element access for a container. Implement this for in-house collections (slot maps,
+/// arenas, interners) to get `{field}_at(key)` from `#[kp(container)]`.
+///
+/// # Example
+/// ```
+/// use rust_key_paths::KpContainer;
+///
+/// struct SlotMap<T> { slots: Vec<Option<T>> }
+///
+/// impl<T> KpContainer for SlotMap<T> {
+///     type Key = usize;
+///     type Item = T;
+///     fn kp_get(&self, key: &usize) -> Option<&T> {
+///         self.slots.get(*key)?.as_ref()
+///     }
+///     fn kp_get_mut(&mut self, key: &usize) -> Option<&mut T> {
+///         self.slots.get_mut(*key)?.as_mut()
+///     }
+/// }
+///
+/// let map = SlotMap { slots: vec![None, Some("b")] };
+/// assert_eq!(map.kp_get(&1), Some(&"b"));
+/// assert_eq!(map.kp_get(&0), None);
+/// ```
+pub trait KpContainer {
+    type Key;
+    type Item;
+    fn kp_get(&self, key: &Self::Key) -> Option<&Self::Item>;
+    fn kp_get_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Item>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> KpContainer for Vec<T> {
+    type Key = usize;
+    type Item = T;
+    #[inline]
+    fn kp_get(&self, key: &usize) -> Option<&T> {
+        self.get(*key)
+    }
+    #[inline]
+    fn kp_get_mut(&mut self, key: &usize) -> Option<&mut T> {
+        self.get_mut(*key)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> KpContainer for VecDeque<T> {
+    type Key = usize;
+    type Item = T;
+    #[inline]
+    fn kp_get(&self, key: &usize) -> Option<&T> {
+        self.get(*key)
+    }
+    #[inline]
+    fn kp_get_mut(&mut self, key: &usize) -> Option<&mut T> {
+        self.get_mut(*key)
+    }
+}
+
+impl<T, const N: usize> KpContainer for [T; N] {
+    type Key = usize;
+    type Item = T;
+    #[inline]
+    fn kp_get(&self, key: &usize) -> Option<&T> {
+        self.get(*key)
+    }
+    #[inline]
+    fn kp_get_mut(&mut self, key: &usize) -> Option<&mut T> {
+        self.get_mut(*key)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> KpContainer for Box<[T]> {
+    type Key = usize;
+    type Item = T;
+    #[inline]
+    fn kp_get(&self, key: &usize) -> Option<&T> {
+        self.get(*key)
+    }
+    #[inline]
+    fn kp_get_mut(&mut self, key: &usize) -> Option<&mut T> {
+        self.get_mut(*key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> KpContainer for HashMap<K, V, S> {
+    type Key = K;
+    type Item = V;
+    #[inline]
+    fn kp_get(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+    #[inline]
+    fn kp_get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> KpContainer for BTreeMap<K, V> {
+    type Key = K;
+    type Item = V;
+    #[inline]
+    fn kp_get(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+    #[inline]
+    fn kp_get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+/// A [KpContainer] that can create the item at a missing key: maps insert `Default::default()`,
+/// `Vec` / `VecDeque` grow with defaults up to the index. Used by [crate::vivify::Entry] and the
+/// derived `{field}_entry(key)`.
+pub trait KpEntry: KpContainer {
+    fn kp_entry(&mut self, key: Self::Key) -> &mut Self::Item;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Default> KpEntry for Vec<T> {
+    fn kp_entry(&mut self, key: usize) -> &mut T {
+        if key >= self.len() {
+            self.resize_with(key + 1, T::default);
+        }
+        &mut self[key]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Default> KpEntry for VecDeque<T> {
+    fn kp_entry(&mut self, key: usize) -> &mut T {
+        if key >= self.len() {
+            self.resize_with(key + 1, T::default);
+        }
+        &mut self[key]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V: Default, S: BuildHasher> KpEntry for HashMap<K, V, S> {
+    #[inline]
+    fn kp_entry(&mut self, key: K) -> &mut V {
+        self.entry(key).or_default()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V: Default> KpEntry for BTreeMap<K, V> {
+    #[inline]
+    fn kp_entry(&mut self, key: K) -> &mut V {
+        self.entry(key).or_default()
+    }
+}
+
+/// Normalizes `#[kp(via = ...)]` accessor results: both `&T` and `Option<&T>` become `Option<&T>`.
+pub trait IntoKpOption<T> {
+    fn into_kp_option(self) -> Option<T>;
+}
+
+impl<T> IntoKpOption<T> for T {
+    #[inline]
+    fn into_kp_option(self) -> Option<T> {
+        Some(self)
+    }
+}
+
+impl<T> IntoKpOption<T> for Option<T> {
+    #[inline]
+    fn into_kp_option(self) -> Option<T> {
+        self
+    }
+}
@@ -0,0 +1,284 @@
+//! Persistent updates of shared snapshots: [CowKp::update_cow] takes an `Arc<Root>` (or
+//! `Rc<Root>`) and returns a new one with the value changed, cloning only the nodes on the path.
+//!
+//! Every `Arc` / `Rc` segment is a [MakeMut] step: it reads through the pointer and, on the copy
+//! being built, calls `Arc::make_mut` / `Rc::make_mut`, so a node is cloned only when it is still
+//! shared with an older snapshot. Everything off the path keeps pointing at the same allocation,
+//! which makes old versions cheap to keep around for undo or time-travel debugging. Plain field,
+//! variant, [OrDefault] and [Entry] steps write in place on the already cloned parent.
+//!
+//! `#[derive(Kp)]` generates `{field}_cow()` for `Arc<T>` and `Rc<T>` fields; chain the steps with
+//! [Lens::compose].
+//!
+//! # Example
+//! ```
+//! use rust_key_paths::cow::{CowKp, MakeMut};
+//! use rust_key_paths::{KpType, Lens};
+//! use std::sync::Arc;
+//!
+//! #[derive(Clone)]
+//! struct Ui { theme: String }
+//! #[derive(Clone)]
+//! struct AppState { ui: Arc<Ui>, log: Arc<Vec<String>> }
+//!
+//! let ui: KpType<'static, AppState, Arc<Ui>> =
+//!     KpType::new(|s: &AppState| Some(&s.ui), |s: &mut AppState| Some(&mut s.ui));
+//! let theme: KpType<'static, Ui, String> =
+//!     KpType::new(|u: &Ui| Some(&u.theme), |u: &mut Ui| Some(&mut u.theme));
+//! let ui_theme = MakeMut::new(ui).compose(theme);
+//!
+//! let v1 = Arc::new(AppState {
+//!     ui: Arc::new(Ui { theme: "light".into() }),
+//!     log: Arc::new(vec![]),
+//! });
+//! let v2 = ui_theme.update_cow(&v1, |t| *t = "dark".into());
+//!
+//! assert_eq!(v1.ui.theme, "light");
+//! assert_eq!(v2.ui.theme, "dark");
+//! assert!(Arc::ptr_eq(&v1.log, &v2.log));
+//! ```
+
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::ops::{Deref, Shr};
+
+use crate::container::{KpContainer, KpEntry};
+use crate::lens::{Lens, ReadKp, Then, WriteKp};
+use crate::vivify::{Entry, OrDefault};
+use crate::{EnumKp, Kp, KpStatic, OffsetKp};
+
+/// A reference-counted pointer that can be copied on write: `Arc` and `Rc`.
+pub trait SharedPtr: Deref + Clone {
+    /// `&mut` to the target only when this is the last reference.
+    fn get_mut(this: &mut Self) -> Option<&mut Self::Target>;
+
+    /// `&mut` to the target, cloning it first when it is shared.
+    fn make_mut(this: &mut Self) -> &mut Self::Target
+    where
+        Self::Target: Clone;
+
+    /// Whether both point at the same allocation.
+    fn ptr_eq(this: &Self, other: &Self) -> bool;
+}
+
+impl<T> SharedPtr for Arc<T> {
+    #[inline]
+    fn get_mut(this: &mut Self) -> Option<&mut T> {
+        Arc::get_mut(this)
+    }
+
+    #[inline]
+    fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        Arc::make_mut(this)
+    }
+
+    #[inline]
+    fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(this, other)
+    }
+}
+
+impl<T> SharedPtr for Rc<T> {
+    #[inline]
+    fn get_mut(this: &mut Self) -> Option<&mut T> {
+        Rc::get_mut(this)
+    }
+
+    #[inline]
+    fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        Rc::make_mut(this)
+    }
+
+    #[inline]
+    fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Rc::ptr_eq(this, other)
+    }
+}
+
+/// A keypath that can write through shared `Arc` / `Rc` segments by cloning them.
+pub trait CowKp: WriteKp {
+    /// Like [WriteKp::write], but a shared pointer on the way is cloned instead of refusing the
+    /// write. `None` only when the path does not exist, e.g. an inactive enum variant.
+    fn cow_mut<'a>(&self, root: &'a mut Self::Root) -> Option<&'a mut Self::Value>;
+
+    /// A new snapshot with `f` applied to the value; `root` is left untouched and every subtree
+    /// off the path is shared between the two. When the path does not exist the result is `root`
+    /// itself (same allocation) and `f` is not called.
+    fn update_cow<P, F>(&self, root: &P, f: F) -> P
+    where
+        Self: ReadKp,
+        Self::Root: Clone,
+        P: SharedPtr<Target = Self::Root>,
+        F: FnOnce(&mut Self::Value),
+    {
+        let mut next = root.clone();
+        if self.read(root).is_some()
+            && let Some(value) = self.cow_mut(P::make_mut(&mut next))
+        {
+            f(value);
+        }
+        next
+    }
+
+    /// Continue through the `Arc` / `Rc` this keypath points at (see [MakeMut]).
+    fn make_mut(self) -> MakeMut<Self>
+    where
+        Self: Sized,
+    {
+        MakeMut::new(self)
+    }
+}
+
+impl<K: CowKp + ?Sized> CowKp for &K {
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut K::Value> {
+        (**self).cow_mut(root)
+    }
+}
+
+impl<'x, R, V, G, S> CowKp for Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+{
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        self.write(root)
+    }
+}
+
+impl<'x, Enum, Variant, G, S, E> CowKp
+    for EnumKp<Enum, Variant, &'x Enum, &'x Variant, &'x mut Enum, &'x mut Variant, G, S, E>
+where
+    G: for<'b> Fn(&'b Enum) -> Option<&'b Variant>,
+    S: for<'b> Fn(&'b mut Enum) -> Option<&'b mut Variant>,
+    E: Fn(Variant) -> Enum,
+{
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut Enum) -> Option<&'a mut Variant> {
+        self.write(root)
+    }
+}
+
+impl<R, V> CowKp for OffsetKp<R, V> {
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        Some(self.get_mut(root))
+    }
+}
+
+impl<R, V> CowKp for KpStatic<R, V> {
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        self.get_mut(root)
+    }
+}
+
+impl<A, B> CowKp for Then<A, B>
+where
+    A: CowKp,
+    A::Value: 'static,
+    B: CowKp<Root = A::Value>,
+{
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut A::Root) -> Option<&'a mut B::Value> {
+        self.second.cow_mut(self.first.cow_mut(root)?)
+    }
+}
+
+impl<K, T> CowKp for OrDefault<K>
+where
+    K: CowKp<Value = Option<T>>,
+{
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut T> {
+        self.kp.cow_mut(root)?.as_mut()
+    }
+}
+
+impl<K, Key> CowKp for Entry<K, Key>
+where
+    K: CowKp,
+    K::Value: KpEntry<Key = Key> + 'static,
+{
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut Self::Value> {
+        self.kp.cow_mut(root)?.kp_get_mut(&self.key)
+    }
+}
+
+/// `Arc<T>` / `Rc<T>` → `T`: reads deref, writes need the only reference, and
+/// [CowKp::cow_mut] clones a shared target first (`make_mut`).
+#[derive(Clone, Copy)]
+pub struct MakeMut<K> {
+    kp: K,
+}
+
+impl<K> MakeMut<K> {
+    pub fn new(kp: K) -> Self {
+        Self { kp }
+    }
+}
+
+impl<K, P> Lens for MakeMut<K>
+where
+    K: Lens<Value = P>,
+    P: SharedPtr,
+{
+    type Root = K::Root;
+    type Value = P::Target;
+}
+
+impl<K, P> ReadKp for MakeMut<K>
+where
+    K: ReadKp<Value = P>,
+    P: SharedPtr + 'static,
+{
+    #[inline]
+    fn read<'a>(&self, root: &'a K::Root) -> Option<&'a P::Target> {
+        Some(&**self.kp.read(root)?)
+    }
+}
+
+impl<K, P> WriteKp for MakeMut<K>
+where
+    K: WriteKp<Value = P>,
+    P: SharedPtr + 'static,
+{
+    #[inline]
+    fn write<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut P::Target> {
+        P::get_mut(self.kp.write(root)?)
+    }
+}
+
+impl<K, P> CowKp for MakeMut<K>
+where
+    K: CowKp<Value = P>,
+    P: SharedPtr + 'static,
+    P::Target: Clone,
+{
+    #[inline]
+    fn cow_mut<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut P::Target> {
+        Some(P::make_mut(self.kp.cow_mut(root)?))
+    }
+}
+
+impl<K, P, N> Shr<N> for MakeMut<K>
+where
+    K: Lens<Value = P>,
+    P: SharedPtr,
+    N: Lens<Root = P::Target>,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
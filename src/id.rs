@@ -0,0 +1,482 @@
+//! Keypath identity: a [KpId] names a path (root type plus one [KpSegment] per step), so
+//! keypaths can key a `HashMap` / `BTreeMap` (subscriptions, indexes, ACL tables, dirty sets)
+//! and be compared structurally (`starts_with`, `strip_prefix`, `common_prefix`).
+//!
+//! Identity is opt-in, so plain keypaths stay two closures wide and `then` does no extra work.
+//! [IdKp] pairs a keypath with its id: [crate::Kp::with_segment] / [crate::Kp::with_id] build
+//! one, as does [crate::KpStatic::to_id_kp] for the `{FIELD}_KP` constants `#[derive(Kp)]`
+//! generates, and [IdKp::then] joins the ids of both sides. [crate::PKp] and [crate::AKp]
+//! carry an optional id (`with_id`), and [crate::lock::LockKp::with_ids] names a lock step.
+//!
+//! Paths of up to four steps are stored inline, so composing identified keypaths does not
+//! allocate; longer ones go to a shared slice.
+
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::any::TypeId;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use crate::lens::{Lens, ReadKp, WriteKp};
+
+/// One step of a [KpId]: a named field (or variant, or lock) of `owner` whose value is `value`.
+///
+/// Types are held as `TypeId::of` function pointers so segments can be built in `const fn`.
+#[derive(Clone, Copy)]
+pub struct KpSegment {
+    owner: fn() -> TypeId,
+    value: fn() -> TypeId,
+    owner_name: fn() -> &'static str,
+    value_name: fn() -> &'static str,
+    name: &'static str,
+}
+
+impl KpSegment {
+    pub const fn new<Owner: ?Sized + 'static, Value: ?Sized + 'static>(name: &'static str) -> Self {
+        Self {
+            owner: TypeId::of::<Owner> as fn() -> TypeId,
+            value: TypeId::of::<Value> as fn() -> TypeId,
+            owner_name: core::any::type_name::<Owner> as fn() -> &'static str,
+            value_name: core::any::type_name::<Value> as fn() -> &'static str,
+            name,
+        }
+    }
+
+    /// Field / variant name (`"0"`, `"1"`, ... for tuple fields, `"lock"` for lock steps).
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn owner_type_id(&self) -> TypeId {
+        (self.owner)()
+    }
+
+    pub fn value_type_id(&self) -> TypeId {
+        (self.value)()
+    }
+
+    fn key(&self) -> (TypeId, &'static str, TypeId) {
+        (self.owner_type_id(), self.name, self.value_type_id())
+    }
+}
+
+impl PartialEq for KpSegment {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for KpSegment {}
+
+impl Hash for KpSegment {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+impl PartialOrd for KpSegment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for KpSegment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl fmt::Debug for KpSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", (self.owner_name)(), self.name)
+    }
+}
+
+const INLINE_STEPS: usize = 4;
+
+// Filler for the unused tail of an inline path; never read.
+const NO_SEGMENT: KpSegment = KpSegment::new::<(), ()>("");
+
+#[derive(Clone)]
+enum Path {
+    Inline {
+        len: u8,
+        segments: [KpSegment; INLINE_STEPS],
+    },
+    #[cfg(feature = "alloc")]
+    Many(Arc<[KpSegment]>),
+}
+
+impl Path {
+    const EMPTY: Path = Path::Inline {
+        len: 0,
+        segments: [NO_SEGMENT; INLINE_STEPS],
+    };
+
+    const fn one(segment: KpSegment) -> Path {
+        let mut segments = [NO_SEGMENT; INLINE_STEPS];
+        segments[0] = segment;
+        Path::Inline { len: 1, segments }
+    }
+
+    // `first` followed by `second`; `None` only without `alloc`, when the result is too long.
+    fn concat(first: &[KpSegment], second: &[KpSegment]) -> Option<Path> {
+        let len = first.len() + second.len();
+        if len <= INLINE_STEPS {
+            let mut segments = [NO_SEGMENT; INLINE_STEPS];
+            segments[..first.len()].copy_from_slice(first);
+            segments[first.len()..len].copy_from_slice(second);
+            return Some(Path::Inline {
+                len: len as u8,
+                segments,
+            });
+        }
+        #[cfg(feature = "alloc")]
+        return Some(Path::Many(first.iter().chain(second).copied().collect()));
+        #[cfg(not(feature = "alloc"))]
+        None
+    }
+}
+
+/// Structural identity of a keypath.
+///
+/// Equal ids mean the same root type and the same steps. Ordering is lexicographic by step, so
+/// in a `BTreeMap` every path sorts directly before the paths it is a prefix of.
+///
+/// # Example
+/// ```
+/// use rust_key_paths::{IdKp, KpType, ReadKp};
+/// use std::collections::HashSet;
+///
+/// struct Address { city: String }
+/// struct User { name: String, address: Address }
+///
+/// fn address() -> IdKp<KpType<'static, User, Address>> {
+///     KpType::new(|u: &User| Some(&u.address), |u: &mut User| Some(&mut u.address))
+///         .with_segment("address")
+/// }
+/// fn city() -> IdKp<KpType<'static, Address, String>> {
+///     KpType::new(|a: &Address| Some(&a.city), |a: &mut Address| Some(&mut a.city))
+///         .with_segment("city")
+/// }
+///
+/// let user = User { name: "Ada".into(), address: Address { city: "London".into() } };
+/// let user_city = address().then(city());
+/// assert_eq!(user_city.read(&user).map(String::as_str), Some("London"));
+/// let id = user_city.id().clone();
+/// assert_eq!(id.to_string(), "User.address.city");
+///
+/// let mut dirty = HashSet::new();
+/// dirty.insert(id.clone());
+/// assert!(dirty.contains(address().then(city()).id()));
+///
+/// let prefix = address().id().clone();
+/// assert!(id.starts_with(&prefix));
+/// assert_eq!(id.strip_prefix(&prefix).as_ref(), Some(city().id()));
+/// assert_eq!(id.common_prefix(&prefix), Some(prefix));
+/// ```
+#[derive(Clone)]
+pub struct KpId {
+    root: fn() -> TypeId,
+    root_name: fn() -> &'static str,
+    path: Path,
+}
+
+impl KpId {
+    /// The empty path on `R` (the identity keypath).
+    pub const fn identity<R: ?Sized + 'static>() -> Self {
+        Self {
+            root: TypeId::of::<R> as fn() -> TypeId,
+            root_name: core::any::type_name::<R> as fn() -> &'static str,
+            path: Path::EMPTY,
+        }
+    }
+
+    /// One step from `R` to its field (or variant) `name` of type `V`.
+    pub const fn field<R: ?Sized + 'static, V: ?Sized + 'static>(name: &'static str) -> Self {
+        Self {
+            root: TypeId::of::<R> as fn() -> TypeId,
+            root_name: core::any::type_name::<R> as fn() -> &'static str,
+            path: Path::one(KpSegment::new::<R, V>(name)),
+        }
+    }
+
+    // Sub-path of an existing id, so it always fits wherever the id did.
+    fn from_parts(root: fn() -> TypeId, root_name: fn() -> &'static str, segments: &[KpSegment]) -> Self {
+        Self {
+            root,
+            root_name,
+            path: Path::concat(segments, &[]).expect("sub-path of an existing id"),
+        }
+    }
+
+    pub fn segments(&self) -> &[KpSegment] {
+        match &self.path {
+            Path::Inline { len, segments } => &segments[..*len as usize],
+            #[cfg(feature = "alloc")]
+            Path::Many(segments) => segments,
+        }
+    }
+
+    /// Number of steps.
+    pub fn len(&self) -> usize {
+        self.segments().len()
+    }
+
+    /// `true` for the identity path.
+    pub fn is_empty(&self) -> bool {
+        self.segments().is_empty()
+    }
+
+    pub fn root_type_id(&self) -> TypeId {
+        (self.root)()
+    }
+
+    /// Type of the value the path ends at (the root type for the identity path).
+    pub fn value_type_id(&self) -> TypeId {
+        match self.segments().last() {
+            Some(last) => last.value_type_id(),
+            None => self.root_type_id(),
+        }
+    }
+
+    /// This path followed by `next`. Callers compose only matching types (`self`'s value type
+    /// is `next`'s root type); the keypath `then` methods guarantee it.
+    #[cfg(feature = "alloc")]
+    pub fn then(&self, next: &KpId) -> KpId {
+        self.try_then(next).expect("paths always fit with `alloc`")
+    }
+
+    // `then`, or `None` when the joined path is too long to store without `alloc`.
+    #[cfg(feature = "alloc")]
+    fn try_then(&self, next: &KpId) -> Option<KpId> {
+        debug_assert_eq!(self.value_type_id(), next.root_type_id());
+        Some(match (self.segments(), next.segments()) {
+            (_, []) => self.clone(),
+            ([], _) => next.clone(),
+            (first, second) => Self {
+                root: self.root,
+                root_name: self.root_name,
+                path: Path::concat(first, second)?,
+            },
+        })
+    }
+
+    /// Does this path begin with `prefix` (same root, `prefix`'s steps first)?
+    pub fn starts_with(&self, prefix: &KpId) -> bool {
+        self.root_type_id() == prefix.root_type_id() && self.segments().starts_with(prefix.segments())
+    }
+
+    /// The rest of this path after `prefix`, rooted at `prefix`'s value type.
+    pub fn strip_prefix(&self, prefix: &KpId) -> Option<KpId> {
+        if !self.starts_with(prefix) {
+            return None;
+        }
+        let rest = &self.segments()[prefix.len()..];
+        Some(match rest.first() {
+            Some(first) => Self::from_parts(first.owner, first.owner_name, rest),
+            None => prefix.identity_at_value(),
+        })
+    }
+
+    /// Longest path both ids start with; `None` when the roots differ.
+    pub fn common_prefix(&self, other: &KpId) -> Option<KpId> {
+        if self.root_type_id() != other.root_type_id() {
+            return None;
+        }
+        let shared = self
+            .segments()
+            .iter()
+            .zip(other.segments())
+            .take_while(|(a, b)| a == b)
+            .count();
+        Some(Self::from_parts(self.root, self.root_name, &self.segments()[..shared]))
+    }
+
+    // Identity path on this path's value type.
+    fn identity_at_value(&self) -> KpId {
+        match self.segments().last() {
+            Some(last) => Self {
+                root: last.value,
+                root_name: last.value_name,
+                path: Path::EMPTY,
+            },
+            None => Self {
+                root: self.root,
+                root_name: self.root_name,
+                path: Path::EMPTY,
+            },
+        }
+    }
+}
+
+impl PartialEq for KpId {
+    fn eq(&self, other: &Self) -> bool {
+        self.root_type_id() == other.root_type_id() && self.segments() == other.segments()
+    }
+}
+
+impl Eq for KpId {}
+
+impl Hash for KpId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.root_type_id().hash(state);
+        self.segments().hash(state);
+    }
+}
+
+impl PartialOrd for KpId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for KpId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.root_type_id()
+            .cmp(&other.root_type_id())
+            .then_with(|| self.segments().cmp(other.segments()))
+    }
+}
+
+/// `Root.step.step` with the root's unqualified type name.
+impl fmt::Display for KpId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(short_type_name((self.root_name)()))?;
+        for segment in self.segments() {
+            write!(f, ".{}", segment.name)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for KpId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "KpId({})", self)
+    }
+}
+
+// `alloc::vec::Vec<my_crate::User>` -> `Vec<my_crate::User>`: strip the path before generics.
+fn short_type_name(full: &'static str) -> &'static str {
+    let head = full.split('<').next().unwrap_or(full);
+    match head.rfind("::") {
+        Some(i) => &full[i + 2..],
+        None => full,
+    }
+}
+
+// Id of `first` followed by `second`; anonymous unless both sides are identified.
+#[cfg(feature = "alloc")]
+pub(crate) fn join(first: &Option<KpId>, second: &Option<KpId>) -> Option<KpId> {
+    match (first, second) {
+        (Some(first), Some(second)) => first.try_then(second),
+        _ => None,
+    }
+}
+
+/// A keypath paired with its [KpId].
+///
+/// Derefs to the keypath, so `get` / `get_mut` work as on `K`. Equality, hashing and ordering
+/// go by id, so an `IdKp` can key a map directly.
+#[derive(Clone)]
+pub struct IdKp<K> {
+    kp: K,
+    id: KpId,
+}
+
+impl<K> IdKp<K> {
+    pub fn new(kp: K, id: KpId) -> Self {
+        Self { kp, id }
+    }
+
+    pub fn id(&self) -> &KpId {
+        &self.id
+    }
+
+    /// The plain keypath, dropping the identity.
+    pub fn into_kp(self) -> K {
+        self.kp
+    }
+
+    pub fn into_parts(self) -> (K, KpId) {
+        (self.kp, self.id)
+    }
+
+    /// Continue into `next` as a [crate::Then] (read it with [ReadKp] / [WriteKp]), joining
+    /// the ids.
+    #[cfg(feature = "alloc")]
+    pub fn then<N>(self, next: IdKp<N>) -> IdKp<crate::Then<K, N>> {
+        self.then_with(next, crate::Then::new)
+    }
+
+    /// Compose with `next` through `compose` (e.g. `Kp::then`, or `|lock, kp| lock.then(kp)`
+    /// for a [crate::lock::LockKp]), joining the ids.
+    #[cfg(feature = "alloc")]
+    pub fn then_with<N, Out>(self, next: IdKp<N>, compose: impl FnOnce(K, N) -> Out) -> IdKp<Out> {
+        IdKp {
+            id: self.id.then(&next.id),
+            kp: compose(self.kp, next.kp),
+        }
+    }
+}
+
+impl<K: Lens> Lens for IdKp<K> {
+    type Root = K::Root;
+    type Value = K::Value;
+}
+
+impl<K: ReadKp> ReadKp for IdKp<K> {
+    #[inline(always)]
+    fn read<'a>(&self, root: &'a K::Root) -> Option<&'a K::Value> {
+        self.kp.read(root)
+    }
+}
+
+impl<K: WriteKp> WriteKp for IdKp<K> {
+    #[inline(always)]
+    fn write<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut K::Value> {
+        self.kp.write(root)
+    }
+}
+
+impl<K> Deref for IdKp<K> {
+    type Target = K;
+
+    fn deref(&self) -> &K {
+        &self.kp
+    }
+}
+
+impl<K> PartialEq for IdKp<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<K> Eq for IdKp<K> {}
+
+impl<K> Hash for IdKp<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl<K> PartialOrd for IdKp<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K> Ord for IdKp<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<K> fmt::Debug for IdKp<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IdKp({})", self.id)
+    }
+}
@@ -0,0 +1,129 @@
+//! Newtype pass-through: [Transparent] and the [IsoKp] isomorphism keypath.
+//!
+//! `#[derive(Kp)]` with `#[kp(transparent)]` on a single-field struct (`struct UserId(Uuid)`)
+//! implements [Transparent], `From` in both directions, and generates `UserId::iso()`.
+//! On a field of an outer struct, `#[kp(transparent)]` generates `{field}_inner()`, which goes
+//! straight through the newtype to the inner value.
+
+use crate::{Kp, KpType};
+
+/// A newtype that exposes its single inner value.
+pub trait Transparent {
+    type Inner;
+    fn kp_inner(&self) -> &Self::Inner;
+    fn kp_inner_mut(&mut self) -> &mut Self::Inner;
+}
+
+fn transparent_get<T: Transparent>(outer: &T) -> Option<&T::Inner> {
+    Some(outer.kp_inner())
+}
+
+fn transparent_get_mut<T: Transparent>(outer: &mut T) -> Option<&mut T::Inner> {
+    Some(outer.kp_inner_mut())
+}
+
+/// Keypath from a [Transparent] newtype to its inner value.
+pub fn transparent<'a, T: Transparent>() -> KpType<'a, T, T::Inner> {
+    Kp::new(transparent_get::<T>, transparent_get_mut::<T>)
+}
+
+/// Lossless keypath between a newtype and its inner value.
+///
+/// Reads and writes go through the inner reference like any [KpType]; `wrap` / `unwrap`
+/// convert owned values. Use [IsoKp::to_kp] to compose with [crate::Kp::then].
+///
+/// # Example
+/// ```
+/// use rust_key_paths::{IsoKp, KpType, Transparent};
+///
+/// struct Meters(f64);
+/// impl Transparent for Meters {
+///     type Inner = f64;
+///     fn kp_inner(&self) -> &f64 { &self.0 }
+///     fn kp_inner_mut(&mut self) -> &mut f64 { &mut self.0 }
+/// }
+/// impl From<f64> for Meters { fn from(v: f64) -> Self { Meters(v) } }
+/// impl From<Meters> for f64 { fn from(m: Meters) -> Self { m.0 } }
+///
+/// struct Run { distance: Meters }
+///
+/// let iso = IsoKp::<Meters, f64>::from_into();
+/// assert_eq!(iso.unwrap(iso.wrap(2.5)), 2.5);
+///
+/// let run = Run { distance: Meters(5.0) };
+/// let distance: KpType<'static, Run, Meters> =
+///     KpType::new(|r: &Run| Some(&r.distance), |r: &mut Run| Some(&mut r.distance));
+/// let raw = distance.then(iso.to_kp());
+/// assert_eq!(raw.get(&run), Some(&5.0));
+/// ```
+pub struct IsoKp<Outer, Inner> {
+    get: for<'b> fn(&'b Outer) -> Option<&'b Inner>,
+    get_mut: for<'b> fn(&'b mut Outer) -> Option<&'b mut Inner>,
+    wrap: fn(Inner) -> Outer,
+    unwrap: fn(Outer) -> Inner,
+}
+
+// IsoKp holds only fn pointers; clone/copy never touch Outer or Inner.
+impl<Outer, Inner> Clone for IsoKp<Outer, Inner> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Outer, Inner> Copy for IsoKp<Outer, Inner> {}
+
+impl<Outer, Inner> IsoKp<Outer, Inner> {
+    pub const fn new(
+        get: for<'b> fn(&'b Outer) -> Option<&'b Inner>,
+        get_mut: for<'b> fn(&'b mut Outer) -> Option<&'b mut Inner>,
+        wrap: fn(Inner) -> Outer,
+        unwrap: fn(Outer) -> Inner,
+    ) -> Self {
+        Self {
+            get,
+            get_mut,
+            wrap,
+            unwrap,
+        }
+    }
+
+    /// Build from a [Transparent] newtype and its `From` conversions.
+    pub fn from_into() -> Self
+    where
+        Outer: Transparent<Inner = Inner> + From<Inner>,
+        Inner: From<Outer>,
+    {
+        Self::new(
+            transparent_get::<Outer>,
+            transparent_get_mut::<Outer>,
+            Outer::from,
+            Inner::from,
+        )
+    }
+
+    #[inline]
+    pub fn get<'b>(&self, outer: &'b Outer) -> Option<&'b Inner> {
+        (self.get)(outer)
+    }
+
+    #[inline]
+    pub fn get_mut<'b>(&self, outer: &'b mut Outer) -> Option<&'b mut Inner> {
+        (self.get_mut)(outer)
+    }
+
+    /// Owned conversion inner -> newtype.
+    #[inline]
+    pub fn wrap(&self, inner: Inner) -> Outer {
+        (self.wrap)(inner)
+    }
+
+    /// Owned conversion newtype -> inner.
+    #[inline]
+    pub fn unwrap(&self, outer: Outer) -> Inner {
+        (self.unwrap)(outer)
+    }
+
+    /// The reference keypath, for composition with other keypaths.
+    pub fn to_kp<'a>(&self) -> KpType<'a, Outer, Inner> {
+        Kp::new(self.get, self.get_mut)
+    }
+}
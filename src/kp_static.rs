@@ -0,0 +1,212 @@
+//! `const` keypaths: [KpStatic] is a pair of plain `fn` pointers plus an optional field name, so
+//! it can be built in `const fn`, stored in `static` tables and compared.
+//!
+//! `#[derive(Kp)]` generates an associated constant `{FIELD}_KP` (`F0_KP`, `F1_KP`, ... for tuple
+//! structs) next to every `{field}()` accessor, with the same value type. [KpStatic::then]
+//! composes in `const` context into a [Then]; [KpStatic::to_kp] / `From` give back the
+//! [KpType], and [KpStatic::to_id_kp] pairs it with its identity (see [IdKp]).
+//!
+//! Equality is hand-written (by field name, see [KpStatic]) and the struct holds `fn` pointers, so
+//! a `KpStatic` const cannot be a `match` pattern. Compare with `==`, or in a match guard
+//! (`k if k == NAME`, `matches!(k, k if k == NAME)`).
+//!
+//! # Example
+//! ```
+//! use rust_key_paths::{KpStatic, KpType, ReadKp, Then};
+//!
+//! struct Address { city: String }
+//! struct User { name: String, address: Address }
+//!
+//! const NAME: KpStatic<User, String> =
+//!     KpStatic::named("name", |u: &User| Some(&u.name), |u: &mut User| Some(&mut u.name));
+//! const ADDRESS: KpStatic<User, Address> =
+//!     KpStatic::named("address", |u: &User| Some(&u.address), |u: &mut User| Some(&mut u.address));
+//! const CITY: KpStatic<Address, String> =
+//!     KpStatic::named("city", |a: &Address| Some(&a.city), |a: &mut Address| Some(&mut a.city));
+//!
+//! static COLUMNS: [KpStatic<User, String>; 1] = [NAME];
+//! const USER_CITY: Then<KpStatic<User, Address>, KpStatic<Address, String>> = ADDRESS.then(CITY);
+//!
+//! let user = User { name: "Ada".into(), address: Address { city: "London".into() } };
+//! assert_eq!(COLUMNS[0].get(&user).map(String::as_str), Some("Ada"));
+//! assert_eq!(USER_CITY.read(&user).map(String::as_str), Some("London"));
+//! assert!(COLUMNS.contains(&NAME));
+//!
+//! let column = match COLUMNS[0] {
+//!     k if k == NAME => "name",
+//!     _ => "other",
+//! };
+//! assert_eq!(column, "name");
+//!
+//! let kp: KpType<'static, User, String> = NAME.into();
+//! assert_eq!(kp.get(&user).map(String::as_str), Some("Ada"));
+//! assert_eq!(NAME.to_id_kp().unwrap().id().to_string(), "User.name");
+//! ```
+
+use core::hash::{Hash, Hasher};
+use core::ops::Shr;
+
+use crate::lens::{Lens, ReadKp, Then, WriteKp};
+use crate::vivify::FillKp;
+use crate::{IdKp, Kp, KpId, KpType};
+
+/// A keypath made of `fn` pointers only: `Copy`, `Send + Sync` and `const`-constructible.
+///
+/// Equality and hashing go by field name for named keypaths ([KpStatic::named], the derive)
+/// and by function address for anonymous ones ([KpStatic::new]).
+pub struct KpStatic<R, V> {
+    get: for<'b> fn(&'b R) -> Option<&'b V>,
+    set: for<'b> fn(&'b mut R) -> Option<&'b mut V>,
+    name: Option<&'static str>,
+}
+
+// KpStatic holds only fn pointers and a name; copying never touches R or V.
+impl<R, V> Clone for KpStatic<R, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R, V> Copy for KpStatic<R, V> {}
+
+impl<R, V> KpStatic<R, V> {
+    pub const fn new(
+        get: for<'b> fn(&'b R) -> Option<&'b V>,
+        set: for<'b> fn(&'b mut R) -> Option<&'b mut V>,
+    ) -> Self {
+        Self { get, set, name: None }
+    }
+
+    /// Like [KpStatic::new], identified as the field `name` of `R` (what the derive generates).
+    pub const fn named(
+        name: &'static str,
+        get: for<'b> fn(&'b R) -> Option<&'b V>,
+        set: for<'b> fn(&'b mut R) -> Option<&'b mut V>,
+    ) -> Self {
+        Self {
+            get,
+            set,
+            name: Some(name),
+        }
+    }
+
+    /// The field name, for named keypaths.
+    pub const fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// `R -> V -> W`, usable in `const` items.
+    pub const fn then<W>(self, next: KpStatic<V, W>) -> Then<Self, KpStatic<V, W>> {
+        Then::new(self, next)
+    }
+
+    #[inline(always)]
+    pub fn get<'a>(&self, root: &'a R) -> Option<&'a V> {
+        (self.get)(root)
+    }
+
+    #[inline(always)]
+    pub fn get_mut<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        (self.set)(root)
+    }
+
+    /// Identity of the keypath (see [KpId]); `None` for anonymous ones.
+    pub fn id(&self) -> Option<KpId>
+    where
+        R: 'static,
+        V: 'static,
+    {
+        self.name.map(KpId::field::<R, V>)
+    }
+
+    /// The same keypath as a [KpType].
+    #[inline]
+    pub fn to_kp<'a>(self) -> KpType<'a, R, V> {
+        Kp::new(self.get, self.set)
+    }
+
+    /// The same keypath as a [KpType] paired with its identity; `None` for anonymous ones.
+    #[inline]
+    pub fn to_id_kp<'a>(self) -> Option<IdKp<KpType<'a, R, V>>>
+    where
+        R: 'static,
+        V: 'static,
+    {
+        self.name.map(|name| self.to_kp().with_segment(name))
+    }
+}
+
+impl<'a, R, V> From<KpStatic<R, V>> for KpType<'a, R, V> {
+    #[inline]
+    fn from(kp: KpStatic<R, V>) -> Self {
+        kp.to_kp()
+    }
+}
+
+impl<R, V> PartialEq for KpStatic<R, V> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.name, other.name) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => {
+                core::ptr::fn_addr_eq(self.get, other.get) && core::ptr::fn_addr_eq(self.set, other.set)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<R, V> Eq for KpStatic<R, V> {}
+
+impl<R, V> Hash for KpStatic<R, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.name {
+            Some(name) => name.hash(state),
+            None => (self.get as usize, self.set as usize).hash(state),
+        }
+    }
+}
+
+impl<R, V> core::fmt::Debug for KpStatic<R, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let root = core::any::type_name::<R>();
+        match self.name {
+            Some(name) => write!(f, "KpStatic({root}.{name})"),
+            None => write!(f, "KpStatic({root}.<anonymous>)"),
+        }
+    }
+}
+
+impl<R, V> Lens for KpStatic<R, V> {
+    type Root = R;
+    type Value = V;
+}
+
+impl<R, V> ReadKp for KpStatic<R, V> {
+    #[inline(always)]
+    fn read<'a>(&self, root: &'a R) -> Option<&'a V> {
+        (self.get)(root)
+    }
+}
+
+impl<R, V> WriteKp for KpStatic<R, V> {
+    #[inline(always)]
+    fn write<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        (self.set)(root)
+    }
+}
+
+impl<R, V> FillKp for KpStatic<R, V> {
+    #[inline(always)]
+    fn get_mut_or_default<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        (self.set)(root)
+    }
+}
+
+impl<R, V, N: Lens<Root = V>> Shr<N> for KpStatic<R, V> {
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
@@ -0,0 +1,997 @@
+//! One trait hierarchy over every keypath family: [Lens] names the `Root` and `Value`,
+//! [ReadKp] / [WriteKp] navigate by reference and [AsyncReadKp] navigates through async locks.
+//!
+//! Implemented for [Kp] (so [crate::KpType] and [crate::KpDynamic]), [EnumKp], [LockKp],
+//! [IsoKp], [SliceKp], [PKp] / [SyncPKp], [AKp] / [SyncAKp] and, for async reads,
+//! [AsyncLockKp] and [ComposedAsyncLockKp]. The combinators ([Lens::compose] → [Then],
+//! [Lens::mapped] → [Map], [Lens::filtered] → [Filter], [Lens::in_arc] → [ForArc]) and the
+//! helpers ([ReadKp::read_cloned], [WriteKp::modify], [WriteKp::replace]) are written once
+//! against these traits, so helper code can take `impl ReadKp<Root = Order, Value = f64>`
+//! instead of spelling out eight type parameters.
+//!
+//! `a >> b` is the operator form of `a.compose(b)` for [Kp], [EnumKp], [LockKp], [IsoKp],
+//! [Then] and the const / vivify / copy-on-write keypaths, so long chains read left to right:
+//! `Company::ceo() >> Person::address() >> Address::city()`.
+//!
+//! The `rust-keypaths` crate implements them for its `KeyPath` family behind its `lens` feature.
+//!
+//! Only the `&Root -> Option<&Value>` shapes are covered: keypaths whose getter returns owned
+//! values (e.g. the result of [Kp::map], or a [Map]) keep their inherent API.
+//!
+//! Unlike the inherent `then` / `map` / `filter` / `for_arc`, which return `impl Fn` closures,
+//! the combinator types can be named, so composed keypaths fit in struct fields and type
+//! aliases without boxing:
+//!
+//! ```
+//! use rust_key_paths::{Filter, KpType, Lens, Map, ReadKp, Then};
+//!
+//! struct Address { city: String }
+//! struct Company { hq: Address, staff: u32 }
+//!
+//! type HqKp = KpType<'static, Company, Address>;
+//! type CityKp = KpType<'static, Address, String>;
+//! type StaffKp = KpType<'static, Company, u32>;
+//!
+//! struct Bindings {
+//!     city: Then<HqKp, CityKp>,
+//!     city_len: Map<Then<HqKp, CityKp>, fn(&String) -> usize>,
+//!     large: Filter<StaffKp, fn(&u32) -> bool>,
+//! }
+//!
+//! fn hq() -> HqKp {
+//!     KpType::new(|c: &Company| Some(&c.hq), |c: &mut Company| Some(&mut c.hq))
+//! }
+//! fn city() -> CityKp {
+//!     KpType::new(|a: &Address| Some(&a.city), |a: &mut Address| Some(&mut a.city))
+//! }
+//! let staff: StaffKp =
+//!     KpType::new(|c: &Company| Some(&c.staff), |c: &mut Company| Some(&mut c.staff));
+//!
+//! let bindings = Bindings {
+//!     city: hq().compose(city()),
+//!     city_len: hq().compose(city()).mapped(String::len as fn(&String) -> usize),
+//!     large: staff.filtered(|n: &u32| *n >= 100),
+//! };
+//!
+//! let acme = Company { hq: Address { city: "Oslo".into() }, staff: 40 };
+//! assert_eq!(bindings.city.read(&acme).map(String::as_str), Some("Oslo"));
+//! assert_eq!(bindings.city_len.get(&acme), Some(4));
+//! assert_eq!(bindings.large.read(&acme), None);
+//! ```
+//!
+//! # Example
+//! ```
+//! use rust_key_paths::lens::{Lens, ReadKp, WriteKp};
+//! use rust_key_paths::KpType;
+//!
+//! struct Order { total: f64 }
+//! struct Customer { last_order: Option<Order> }
+//!
+//! fn total_of(kp: &impl ReadKp<Root = Order, Value = f64>, order: &Order) -> f64 {
+//!     kp.read_cloned(order).unwrap_or_default()
+//! }
+//!
+//! let total: KpType<'static, Order, f64> =
+//!     KpType::new(|o: &Order| Some(&o.total), |o: &mut Order| Some(&mut o.total));
+//! let last_order: KpType<'static, Customer, Order> = KpType::new(
+//!     |c: &Customer| c.last_order.as_ref(),
+//!     |c: &mut Customer| c.last_order.as_mut(),
+//! );
+//!
+//! let mut customer = Customer { last_order: Some(Order { total: 12.5 }) };
+//! assert_eq!(total_of(&total, customer.last_order.as_ref().unwrap()), 12.5);
+//!
+//! let last_total = last_order.compose(total);
+//! last_total.modify(&mut customer, |t| *t *= 2.0);
+//! assert_eq!(last_total.read(&customer), Some(&25.0));
+//! ```
+
+#[cfg(feature = "alloc")]
+use core::any::Any;
+use core::ops::Shr;
+
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+use async_trait::async_trait;
+
+#[cfg(feature = "std")]
+use crate::async_lock::{AsyncLockKp, AsyncLockLike, ComposedAsyncLockKp};
+use crate::lock::{LockAccess, LockKp};
+use crate::{EnumKp, IsoKp, Kp, SliceKp};
+#[cfg(feature = "alloc")]
+use crate::{AKp, PKp, SyncAKp, SyncPKp};
+
+/// A keypath from `Root` to `Value`.
+pub trait Lens {
+    type Root: ?Sized;
+    type Value: ?Sized;
+
+    /// Continue into `next`, whose root is this keypath's value.
+    ///
+    /// Named `compose` so it never shadows the inherent `then` methods of the keypath types.
+    fn compose<N>(self, next: N) -> Then<Self, N>
+    where
+        Self: Sized,
+        N: Lens<Root = Self::Value>,
+    {
+        Then {
+            first: self,
+            second: next,
+        }
+    }
+
+    /// Read an owned value computed from this keypath's value (see [Map]).
+    ///
+    /// Named `mapped` so it never shadows the inherent `map` methods of the keypath types.
+    fn mapped<F, W>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Value) -> W,
+    {
+        Map::new(self, f)
+    }
+
+    /// Resolve only while `predicate` holds for the value (see [Filter]).
+    fn filtered<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Self::Value) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// The same keypath rooted at `Arc<Root>` (see [ForArc]).
+    #[cfg(feature = "alloc")]
+    fn in_arc(self) -> ForArc<Self>
+    where
+        Self: Sized,
+    {
+        ForArc::new(self)
+    }
+}
+
+/// A keypath that can be read by shared reference.
+pub trait ReadKp: Lens {
+    fn read<'a>(&self, root: &'a Self::Root) -> Option<&'a Self::Value>;
+
+    /// Clone the value out of the root.
+    fn read_cloned(&self, root: &Self::Root) -> Option<Self::Value>
+    where
+        Self::Value: Sized + Clone,
+    {
+        self.read(root).cloned()
+    }
+}
+
+/// A keypath that can be written by mutable reference.
+pub trait WriteKp: Lens {
+    fn write<'a>(&self, root: &'a mut Self::Root) -> Option<&'a mut Self::Value>;
+
+    /// Run `f` on the value. Returns `false` when the path does not resolve.
+    fn modify<F>(&self, root: &mut Self::Root, f: F) -> bool
+    where
+        F: FnOnce(&mut Self::Value),
+    {
+        self.write(root).map(f).is_some()
+    }
+
+    /// Store `value`, returning the previous one.
+    fn replace(&self, root: &mut Self::Root, value: Self::Value) -> Option<Self::Value>
+    where
+        Self::Value: Sized,
+    {
+        self.write(root).map(|slot| core::mem::replace(slot, value))
+    }
+}
+
+/// A keypath that may cross async locks on the way to the value.
+///
+/// Sync keypaths implement it too, so they compose with async ones through [Then].
+#[cfg(feature = "std")]
+#[async_trait(?Send)]
+pub trait AsyncReadKp: Lens {
+    async fn read_async<'a>(&self, root: &'a Self::Root) -> Option<&'a Self::Value>;
+}
+
+/// Sequential composition of two keypaths (see [Lens::compose]).
+///
+/// Like [Kp::then], reading through it needs the intermediate value type to be `'static`.
+#[derive(Clone, Copy)]
+pub struct Then<A, B> {
+    pub(crate) first: A,
+    pub(crate) second: B,
+}
+
+impl<A, B> Then<A, B> {
+    pub const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Append another step; like [Lens::compose] but usable in `const` items.
+    pub const fn then<C>(self, next: C) -> Then<Self, C> {
+        Then::new(self, next)
+    }
+
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A, B> Lens for Then<A, B>
+where
+    A: Lens,
+    B: Lens<Root = A::Value>,
+{
+    type Root = A::Root;
+    type Value = B::Value;
+}
+
+impl<A, B> ReadKp for Then<A, B>
+where
+    A: ReadKp,
+    A::Value: 'static,
+    B: ReadKp<Root = A::Value>,
+{
+    #[inline]
+    fn read<'a>(&self, root: &'a A::Root) -> Option<&'a B::Value> {
+        self.second.read(self.first.read(root)?)
+    }
+}
+
+impl<A, B> WriteKp for Then<A, B>
+where
+    A: WriteKp,
+    A::Value: 'static,
+    B: WriteKp<Root = A::Value>,
+{
+    #[inline]
+    fn write<'a>(&self, root: &'a mut A::Root) -> Option<&'a mut B::Value> {
+        self.second.write(self.first.write(root)?)
+    }
+}
+
+#[cfg(feature = "std")]
+#[async_trait(?Send)]
+impl<A, B> AsyncReadKp for Then<A, B>
+where
+    A: AsyncReadKp,
+    A::Value: 'static,
+    B: AsyncReadKp<Root = A::Value>,
+{
+    async fn read_async<'a>(&self, root: &'a A::Root) -> Option<&'a B::Value> {
+        let mid = self.first.read_async(root).await?;
+        self.second.read_async(mid).await
+    }
+}
+
+/// A keypath followed by a conversion to an owned value (see [Lens::mapped]).
+///
+/// The named counterpart of [Kp::map]: with a `fn` pointer it can be spelled out in a struct
+/// field, e.g. `Map<KpType<'static, User, String>, fn(&String) -> usize>`. The value is
+/// produced rather than borrowed, so it is read with [Map::get] instead of [ReadKp::read].
+#[derive(Clone, Copy)]
+pub struct Map<K, F> {
+    pub(crate) kp: K,
+    pub(crate) f: F,
+}
+
+impl<K, F> Map<K, F> {
+    pub const fn new(kp: K, f: F) -> Self {
+        Self { kp, f }
+    }
+
+    /// The source keypath.
+    pub fn source(&self) -> &K {
+        &self.kp
+    }
+}
+
+impl<K, F, W> Map<K, F>
+where
+    K: ReadKp,
+    F: Fn(&K::Value) -> W,
+{
+    /// The converted value.
+    #[inline]
+    pub fn get(&self, root: &K::Root) -> Option<W> {
+        self.kp.read(root).map(&self.f)
+    }
+}
+
+impl<K, F, W> Lens for Map<K, F>
+where
+    K: Lens,
+    F: Fn(&K::Value) -> W,
+{
+    type Root = K::Root;
+    type Value = W;
+}
+
+/// A keypath that resolves only while a predicate holds (see [Lens::filtered]).
+///
+/// The named counterpart of [Kp::filter]; reads and writes both check the current value.
+#[derive(Clone, Copy)]
+pub struct Filter<K, P> {
+    pub(crate) kp: K,
+    pub(crate) predicate: P,
+}
+
+impl<K, P> Filter<K, P> {
+    pub const fn new(kp: K, predicate: P) -> Self {
+        Self { kp, predicate }
+    }
+
+    /// The source keypath.
+    pub fn source(&self) -> &K {
+        &self.kp
+    }
+}
+
+impl<K, P> Lens for Filter<K, P>
+where
+    K: Lens,
+    P: Fn(&K::Value) -> bool,
+{
+    type Root = K::Root;
+    type Value = K::Value;
+}
+
+impl<K, P> ReadKp for Filter<K, P>
+where
+    K: ReadKp,
+    P: Fn(&K::Value) -> bool,
+{
+    #[inline]
+    fn read<'a>(&self, root: &'a K::Root) -> Option<&'a K::Value> {
+        self.kp.read(root).filter(|v| (self.predicate)(v))
+    }
+}
+
+impl<K, P> WriteKp for Filter<K, P>
+where
+    K: WriteKp,
+    P: Fn(&K::Value) -> bool,
+{
+    #[inline]
+    fn write<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut K::Value> {
+        self.kp.write(root).filter(|v| (self.predicate)(v))
+    }
+}
+
+/// A keypath rooted at `Arc<Root>` (see [Lens::in_arc]).
+///
+/// The named counterpart of [Kp::for_arc]: reads go through the shared pointer, writes resolve
+/// only while the `Arc` is unique ([Arc::get_mut]).
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy)]
+pub struct ForArc<K> {
+    pub(crate) kp: K,
+}
+
+#[cfg(feature = "alloc")]
+impl<K> ForArc<K> {
+    pub const fn new(kp: K) -> Self {
+        Self { kp }
+    }
+
+    /// The keypath applied behind the `Arc`.
+    pub fn source(&self) -> &K {
+        &self.kp
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Lens> Lens for ForArc<K>
+where
+    K::Root: Sized,
+{
+    type Root = Arc<K::Root>;
+    type Value = K::Value;
+}
+
+#[cfg(feature = "alloc")]
+impl<K: ReadKp> ReadKp for ForArc<K>
+where
+    K::Root: Sized,
+{
+    #[inline]
+    fn read<'a>(&self, root: &'a Arc<K::Root>) -> Option<&'a K::Value> {
+        self.kp.read(root)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: WriteKp> WriteKp for ForArc<K>
+where
+    K::Root: Sized,
+{
+    #[inline]
+    fn write<'a>(&self, root: &'a mut Arc<K::Root>) -> Option<&'a mut K::Value> {
+        self.kp.write(Arc::get_mut(root)?)
+    }
+}
+
+// ========== references ==========
+
+// A borrowed keypath works wherever an owned one does, e.g. `MapLens::new(&kp, ..)`.
+impl<K: Lens + ?Sized> Lens for &K {
+    type Root = K::Root;
+    type Value = K::Value;
+}
+
+impl<K: ReadKp + ?Sized> ReadKp for &K {
+    #[inline]
+    fn read<'a>(&self, root: &'a K::Root) -> Option<&'a K::Value> {
+        (**self).read(root)
+    }
+}
+
+impl<K: WriteKp + ?Sized> WriteKp for &K {
+    #[inline]
+    fn write<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut K::Value> {
+        (**self).write(root)
+    }
+}
+
+// ========== Kp ==========
+
+impl<'x, R, V, G, S> Lens for Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+{
+    type Root = R;
+    type Value = V;
+}
+
+impl<'x, R, V, G, S> ReadKp for Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+{
+    #[inline]
+    fn read<'a>(&self, root: &'a R) -> Option<&'a V> {
+        (self.get)(root)
+    }
+}
+
+impl<'x, R, V, G, S> WriteKp for Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+{
+    #[inline]
+    fn write<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        (self.set)(root)
+    }
+}
+
+#[cfg(feature = "std")]
+#[async_trait(?Send)]
+impl<'x, R, V, G, S> AsyncReadKp for Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+{
+    async fn read_async<'a>(&self, root: &'a R) -> Option<&'a V> {
+        (self.get)(root)
+    }
+}
+
+// ========== EnumKp ==========
+
+impl<'x, Enum, Variant, G, S, E> Lens
+    for EnumKp<Enum, Variant, &'x Enum, &'x Variant, &'x mut Enum, &'x mut Variant, G, S, E>
+where
+    G: for<'b> Fn(&'b Enum) -> Option<&'b Variant>,
+    S: for<'b> Fn(&'b mut Enum) -> Option<&'b mut Variant>,
+    E: Fn(Variant) -> Enum,
+{
+    type Root = Enum;
+    type Value = Variant;
+}
+
+impl<'x, Enum, Variant, G, S, E> ReadKp
+    for EnumKp<Enum, Variant, &'x Enum, &'x Variant, &'x mut Enum, &'x mut Variant, G, S, E>
+where
+    G: for<'b> Fn(&'b Enum) -> Option<&'b Variant>,
+    S: for<'b> Fn(&'b mut Enum) -> Option<&'b mut Variant>,
+    E: Fn(Variant) -> Enum,
+{
+    #[inline]
+    fn read<'a>(&self, root: &'a Enum) -> Option<&'a Variant> {
+        (self.extractor.get)(root)
+    }
+}
+
+impl<'x, Enum, Variant, G, S, E> WriteKp
+    for EnumKp<Enum, Variant, &'x Enum, &'x Variant, &'x mut Enum, &'x mut Variant, G, S, E>
+where
+    G: for<'b> Fn(&'b Enum) -> Option<&'b Variant>,
+    S: for<'b> Fn(&'b mut Enum) -> Option<&'b mut Variant>,
+    E: Fn(Variant) -> Enum,
+{
+    #[inline]
+    fn write<'a>(&self, root: &'a mut Enum) -> Option<&'a mut Variant> {
+        (self.extractor.set)(root)
+    }
+}
+
+#[cfg(feature = "std")]
+#[async_trait(?Send)]
+impl<'x, Enum, Variant, G, S, E> AsyncReadKp
+    for EnumKp<Enum, Variant, &'x Enum, &'x Variant, &'x mut Enum, &'x mut Variant, G, S, E>
+where
+    G: for<'b> Fn(&'b Enum) -> Option<&'b Variant>,
+    S: for<'b> Fn(&'b mut Enum) -> Option<&'b mut Variant>,
+    E: Fn(Variant) -> Enum,
+{
+    async fn read_async<'a>(&self, root: &'a Enum) -> Option<&'a Variant> {
+        (self.extractor.get)(root)
+    }
+}
+
+// ========== LockKp ==========
+
+impl<'x, R, Lock, Mid, V, G1, S1, L, G2, S2> Lens
+    for LockKp<
+        R,
+        Lock,
+        Mid,
+        V,
+        &'x R,
+        &'x Lock,
+        &'x Mid,
+        &'x V,
+        &'x mut R,
+        &'x mut Lock,
+        &'x mut Mid,
+        &'x mut V,
+        G1,
+        S1,
+        L,
+        G2,
+        S2,
+    >
+where
+    G1: for<'b> Fn(&'b R) -> Option<&'b Lock>,
+    S1: for<'b> Fn(&'b mut R) -> Option<&'b mut Lock>,
+    L: for<'b> LockAccess<Lock, &'b Mid> + for<'b> LockAccess<Lock, &'b mut Mid>,
+    G2: for<'b> Fn(&'b Mid) -> Option<&'b V>,
+    S2: for<'b> Fn(&'b mut Mid) -> Option<&'b mut V>,
+{
+    type Root = R;
+    type Value = V;
+}
+
+impl<'x, R, Lock, Mid, V, G1, S1, L, G2, S2> ReadKp
+    for LockKp<
+        R,
+        Lock,
+        Mid,
+        V,
+        &'x R,
+        &'x Lock,
+        &'x Mid,
+        &'x V,
+        &'x mut R,
+        &'x mut Lock,
+        &'x mut Mid,
+        &'x mut V,
+        G1,
+        S1,
+        L,
+        G2,
+        S2,
+    >
+where
+    Mid: 'static,
+    G1: for<'b> Fn(&'b R) -> Option<&'b Lock>,
+    S1: for<'b> Fn(&'b mut R) -> Option<&'b mut Lock>,
+    L: for<'b> LockAccess<Lock, &'b Mid> + for<'b> LockAccess<Lock, &'b mut Mid>,
+    G2: for<'b> Fn(&'b Mid) -> Option<&'b V>,
+    S2: for<'b> Fn(&'b mut Mid) -> Option<&'b mut V>,
+{
+    /// Same semantics as [LockKp::get]: the lock is released before the reference is returned.
+    #[inline]
+    fn read<'a>(&self, root: &'a R) -> Option<&'a V> {
+        let lock = (self.prev.get)(root)?;
+        let mid: &'a Mid = self.mid.lock_read(lock)?;
+        (self.next.get)(mid)
+    }
+}
+
+impl<'x, R, Lock, Mid, V, G1, S1, L, G2, S2> WriteKp
+    for LockKp<
+        R,
+        Lock,
+        Mid,
+        V,
+        &'x R,
+        &'x Lock,
+        &'x Mid,
+        &'x V,
+        &'x mut R,
+        &'x mut Lock,
+        &'x mut Mid,
+        &'x mut V,
+        G1,
+        S1,
+        L,
+        G2,
+        S2,
+    >
+where
+    Mid: 'static,
+    G1: for<'b> Fn(&'b R) -> Option<&'b Lock>,
+    S1: for<'b> Fn(&'b mut R) -> Option<&'b mut Lock>,
+    L: for<'b> LockAccess<Lock, &'b Mid> + for<'b> LockAccess<Lock, &'b mut Mid>,
+    G2: for<'b> Fn(&'b Mid) -> Option<&'b V>,
+    S2: for<'b> Fn(&'b mut Mid) -> Option<&'b mut V>,
+{
+    /// Same semantics as [LockKp::get_mut].
+    #[inline]
+    fn write<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        let lock = (self.prev.set)(root)?;
+        let mid: &'a mut Mid = self.mid.lock_write(lock)?;
+        (self.next.set)(mid)
+    }
+}
+
+#[cfg(feature = "std")]
+#[async_trait(?Send)]
+impl<'x, R, Lock, Mid, V, G1, S1, L, G2, S2> AsyncReadKp
+    for LockKp<
+        R,
+        Lock,
+        Mid,
+        V,
+        &'x R,
+        &'x Lock,
+        &'x Mid,
+        &'x V,
+        &'x mut R,
+        &'x mut Lock,
+        &'x mut Mid,
+        &'x mut V,
+        G1,
+        S1,
+        L,
+        G2,
+        S2,
+    >
+where
+    Mid: 'static,
+    G1: for<'b> Fn(&'b R) -> Option<&'b Lock>,
+    S1: for<'b> Fn(&'b mut R) -> Option<&'b mut Lock>,
+    L: for<'b> LockAccess<Lock, &'b Mid> + for<'b> LockAccess<Lock, &'b mut Mid>,
+    G2: for<'b> Fn(&'b Mid) -> Option<&'b V>,
+    S2: for<'b> Fn(&'b mut Mid) -> Option<&'b mut V>,
+{
+    async fn read_async<'a>(&self, root: &'a R) -> Option<&'a V> {
+        self.read(root)
+    }
+}
+
+// ========== AsyncLockKp / ComposedAsyncLockKp ==========
+
+#[cfg(feature = "std")]
+impl<'x, R, Lock, Mid, V, G1, S1, L, G2, S2> Lens
+    for AsyncLockKp<
+        R,
+        Lock,
+        Mid,
+        V,
+        &'x R,
+        &'x Lock,
+        &'x Mid,
+        &'x V,
+        &'x mut R,
+        &'x mut Lock,
+        &'x mut Mid,
+        &'x mut V,
+        G1,
+        S1,
+        L,
+        G2,
+        S2,
+    >
+where
+    G1: for<'b> Fn(&'b R) -> Option<&'b Lock> + Clone,
+    S1: for<'b> Fn(&'b mut R) -> Option<&'b mut Lock> + Clone,
+    L: for<'b> AsyncLockLike<Lock, &'b Mid> + for<'b> AsyncLockLike<Lock, &'b mut Mid> + Clone,
+    G2: for<'b> Fn(&'b Mid) -> Option<&'b V> + Clone,
+    S2: for<'b> Fn(&'b mut Mid) -> Option<&'b mut V> + Clone,
+{
+    type Root = R;
+    type Value = V;
+}
+
+#[cfg(feature = "std")]
+#[async_trait(?Send)]
+impl<'x, R, Lock, Mid, V, G1, S1, L, G2, S2> AsyncReadKp
+    for AsyncLockKp<
+        R,
+        Lock,
+        Mid,
+        V,
+        &'x R,
+        &'x Lock,
+        &'x Mid,
+        &'x V,
+        &'x mut R,
+        &'x mut Lock,
+        &'x mut Mid,
+        &'x mut V,
+        G1,
+        S1,
+        L,
+        G2,
+        S2,
+    >
+where
+    Mid: 'static,
+    Lock: Clone,
+    G1: for<'b> Fn(&'b R) -> Option<&'b Lock> + Clone,
+    S1: for<'b> Fn(&'b mut R) -> Option<&'b mut Lock> + Clone,
+    L: for<'b> AsyncLockLike<Lock, &'b Mid> + for<'b> AsyncLockLike<Lock, &'b mut Mid> + Clone,
+    G2: for<'b> Fn(&'b Mid) -> Option<&'b V> + Clone,
+    S2: for<'b> Fn(&'b mut Mid) -> Option<&'b mut V> + Clone,
+{
+    /// Same semantics as [AsyncLockKp::get].
+    async fn read_async<'a>(&self, root: &'a R) -> Option<&'a V> {
+        // SHALLOW CLONE: for Arc<Mutex<T>> only the refcount changes
+        let lock = (self.prev.get)(root)?.clone();
+        let mid: &'a Mid = self.mid.lock_read(&lock).await?;
+        (self.next.get)(mid)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, V2, Root, Value2, MutRoot, MutValue2, First, Second> Lens
+    for ComposedAsyncLockKp<R, V2, Root, Value2, MutRoot, MutValue2, First, Second>
+where
+    First: Lens,
+    Second: Lens<Root = First::Value>,
+{
+    type Root = First::Root;
+    type Value = Second::Value;
+}
+
+#[cfg(feature = "std")]
+#[async_trait(?Send)]
+impl<R, V2, Root, Value2, MutRoot, MutValue2, First, Second> AsyncReadKp
+    for ComposedAsyncLockKp<R, V2, Root, Value2, MutRoot, MutValue2, First, Second>
+where
+    First: AsyncReadKp,
+    First::Value: 'static,
+    Second: AsyncReadKp<Root = First::Value>,
+{
+    async fn read_async<'a>(&self, root: &'a First::Root) -> Option<&'a Second::Value> {
+        let mid = self.first.read_async(root).await?;
+        self.second.read_async(mid).await
+    }
+}
+
+// ========== fn-pointer keypaths ==========
+
+impl<Outer, Inner> Lens for IsoKp<Outer, Inner> {
+    type Root = Outer;
+    type Value = Inner;
+}
+
+impl<Outer, Inner> ReadKp for IsoKp<Outer, Inner> {
+    #[inline]
+    fn read<'a>(&self, root: &'a Outer) -> Option<&'a Inner> {
+        self.get(root)
+    }
+}
+
+impl<Outer, Inner> WriteKp for IsoKp<Outer, Inner> {
+    #[inline]
+    fn write<'a>(&self, root: &'a mut Outer) -> Option<&'a mut Inner> {
+        self.get_mut(root)
+    }
+}
+
+impl<R, T> Lens for SliceKp<R, T> {
+    type Root = R;
+    type Value = [T];
+}
+
+impl<R, T> ReadKp for SliceKp<R, T> {
+    #[inline]
+    fn read<'a>(&self, root: &'a R) -> Option<&'a [T]> {
+        self.get(root)
+    }
+}
+
+impl<R, T> WriteKp for SliceKp<R, T> {
+    #[inline]
+    fn write<'a>(&self, root: &'a mut R) -> Option<&'a mut [T]> {
+        self.get_mut(root)
+    }
+}
+
+// ========== type-erased keypaths ==========
+
+#[cfg(feature = "alloc")]
+macro_rules! erased_lens {
+    ($kp:ident<$root:ident>) => {
+        impl<$root: 'static> Lens for $kp<$root> {
+            type Root = $root;
+            type Value = dyn Any;
+        }
+
+        impl<$root: 'static> ReadKp for $kp<$root> {
+            #[inline]
+            fn read<'a>(&self, root: &'a $root) -> Option<&'a dyn Any> {
+                self.get(root)
+            }
+        }
+
+        impl<$root: 'static> WriteKp for $kp<$root> {
+            #[inline]
+            fn write<'a>(&self, root: &'a mut $root) -> Option<&'a mut dyn Any> {
+                self.get_mut(root)
+            }
+        }
+    };
+    ($kp:ident) => {
+        impl Lens for $kp {
+            type Root = dyn Any;
+            type Value = dyn Any;
+        }
+
+        impl ReadKp for $kp {
+            #[inline]
+            fn read<'a>(&self, root: &'a dyn Any) -> Option<&'a dyn Any> {
+                self.get(root)
+            }
+        }
+
+        impl WriteKp for $kp {
+            #[inline]
+            fn write<'a>(&self, root: &'a mut dyn Any) -> Option<&'a mut dyn Any> {
+                self.get_mut(root)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+erased_lens!(PKp<Root>);
+#[cfg(feature = "alloc")]
+erased_lens!(SyncPKp<Root>);
+#[cfg(feature = "alloc")]
+erased_lens!(AKp);
+#[cfg(feature = "alloc")]
+erased_lens!(SyncAKp);
+
+// ========== `>>` ==========
+//
+// `a >> b` is `a.compose(b)`: stable operator chaining that names its result (`Then<A, B>`)
+// instead of returning `impl Fn`. `>>` is left-associative, so `a >> b >> c` nests as
+// `Then<Then<A, B>, C>`.
+
+impl<'x, R, V, G, S, N> Shr<N> for Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+    N: Lens<Root = V>,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
+
+impl<'x, Enum, Variant, G, S, E, N> Shr<N>
+    for EnumKp<Enum, Variant, &'x Enum, &'x Variant, &'x mut Enum, &'x mut Variant, G, S, E>
+where
+    G: for<'b> Fn(&'b Enum) -> Option<&'b Variant>,
+    S: for<'b> Fn(&'b mut Enum) -> Option<&'b mut Variant>,
+    E: Fn(Variant) -> Enum,
+    N: Lens<Root = Variant>,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
+
+impl<'x, R, Lock, Mid, V, G1, S1, L, G2, S2, N> Shr<N>
+    for LockKp<
+        R,
+        Lock,
+        Mid,
+        V,
+        &'x R,
+        &'x Lock,
+        &'x Mid,
+        &'x V,
+        &'x mut R,
+        &'x mut Lock,
+        &'x mut Mid,
+        &'x mut V,
+        G1,
+        S1,
+        L,
+        G2,
+        S2,
+    >
+where
+    G1: for<'b> Fn(&'b R) -> Option<&'b Lock>,
+    S1: for<'b> Fn(&'b mut R) -> Option<&'b mut Lock>,
+    L: for<'b> LockAccess<Lock, &'b Mid> + for<'b> LockAccess<Lock, &'b mut Mid>,
+    G2: for<'b> Fn(&'b Mid) -> Option<&'b V>,
+    S2: for<'b> Fn(&'b mut Mid) -> Option<&'b mut V>,
+    N: Lens<Root = V>,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
+
+impl<A, B, N> Shr<N> for Then<A, B>
+where
+    A: Lens,
+    B: Lens<Root = A::Value>,
+    N: Lens<Root = B::Value>,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
+
+impl<K, P, N> Shr<N> for Filter<K, P>
+where
+    K: Lens,
+    P: Fn(&K::Value) -> bool,
+    N: Lens<Root = K::Value>,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Lens, N: Lens<Root = K::Value>> Shr<N> for ForArc<K>
+where
+    K::Root: Sized,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
+
+impl<Outer, Inner, N: Lens<Root = Inner>> Shr<N> for IsoKp<Outer, Inner> {
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
@@ -10,14 +10,33 @@
 // type Getter<R, V, Root, Value> where Root: std::borrow::Borrow<R>, Value: std::borrow::Borrow<V> = fn(Root) -> Option<Value>;
 // type Setter<R, V> = fn(&'r mut R) -> Option<&'r mut V>;
 
-use std::sync::{Arc};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 // Export the lock module
 pub mod lock;
-pub use lock::{
-    ArcMutexAccess, ArcRwLockAccess, LockAccess, LockKp, LockKpType, RcRefCellAccess,
-    StdMutexAccess, StdRwLockAccess,
-};
+pub use lock::{LockAccess, LockKp, ScopedLockAccess, ScopedLockKp};
+#[cfg(feature = "alloc")]
+pub use lock::RcRefCellAccess;
+#[cfg(feature = "std")]
+pub use lock::{ArcMutexAccess, ArcRwLockAccess, LockKpType, StdMutexAccess, StdRwLockAccess};
+#[cfg(feature = "spin")]
+pub use lock::{SpinMutexAccess, SpinRwLockAccess};
+#[cfg(feature = "critical-section")]
+pub use lock::CriticalSectionAccess;
 
 #[cfg(feature = "parking_lot")]
 pub use lock::{
@@ -26,57 +45,104 @@ pub use lock::{
 };
 
 // Export the async_lock module
+#[cfg(feature = "std")]
 pub mod async_lock;
 
-
-
-// pub struct KpStatic<R, V> {
-//     pub get: fn(&R) -> Option<&V>,
-//     pub set: fn(&mut R) -> Option<&mut V>,
-// }
-//
-// // KpStatic holds only fn pointers; it is a functional component with no owned data.
-// unsafe impl<R, V> Send for KpStatic<R, V> {}
-// unsafe impl<R, V> Sync for KpStatic<R, V> {}
-//
-// impl<R, V> KpStatic<R, V> {
-//     pub const fn new(
-//         get: fn(&R) -> Option<&V>,
-//         set: fn(&mut R) -> Option<&mut V>,
-//     ) -> Self {
-//         Self { get, set }
-//     }
-//
-//     #[inline(always)]
-//     pub fn get<'a>(&self, root: &'a R) -> Option<&'a V> {
-//         (self.get)(root)
-//     }
-//
-//     #[inline(always)]
-//     pub fn set<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
-//         (self.set)(root)
-//     }
-// }
-
-// // Macro generates:
-// #[inline(always)]
-// fn __get_static_str_field(x: &AllContainersTest) -> Option<&'static str> {
-//     Some(&x.static_str_field)
-// }
-//
-// #[inline(always)]
-// fn __set_static_str_field(x: &mut AllContainersTest) -> Option<&mut &'static str> {
-//     Some(&mut x.static_str_field)
-// }
-//
-// pub static STATIC_STR_FIELD_KP: KpStatic<AllContainersTest, &'static str> =
-//     KpStatic::new(__get_static_str_field, __set_static_str_field);
-
-
+// Cell / OnceLock keypaths (copy in/out, initialize through the path)
+pub mod cell;
+pub use cell::{CellKp, OnceAccess, OnceKp};
+
+// Range / window keypaths over arrays and slices
+pub mod slice;
+pub use slice::SliceKp;
+
+// Custom containers: KpContainer trait and #[kp(via = ...)] helpers
+pub mod container;
+pub use container::{IntoKpOption, KpContainer, KpEntry};
+
+// Newtype pass-through: Transparent trait and From/Into-backed IsoKp
+pub mod iso;
+pub use iso::{IsoKp, Transparent, transparent};
+
+// Send + Sync counterparts of PKp / AKp
+#[cfg(feature = "alloc")]
+pub mod sync_erased;
+#[cfg(feature = "alloc")]
+pub use sync_erased::{SyncAKp, SyncPKp};
+
+// PKp that keeps the leaf's Debug / PartialEq / Hash / Clone / Serialize impls
+#[cfg(feature = "std")]
+pub mod caps;
+#[cfg(feature = "std")]
+pub use caps::{CapPKp, CapPKpBuilder};
+
+// ReadKp / WriteKp / AsyncReadKp: one trait hierarchy over every keypath family
+pub mod lens;
+#[cfg(feature = "std")]
+pub use lens::AsyncReadKp;
+pub use lens::{Filter, Lens, Map, ReadKp, Then, WriteKp};
+#[cfg(feature = "alloc")]
+pub use lens::ForArc;
+
+// Multi-focus keypaths over every element of a collection
+#[cfg(feature = "alloc")]
+pub mod traversal;
+#[cfg(feature = "alloc")]
+pub use traversal::{Traversable, Traversal};
+
+// Writable mapped keypaths (to / from conversions) and read-only computed values
+#[cfg(feature = "std")]
+pub mod mapped;
+#[cfg(feature = "std")]
+pub use mapped::{Computed, MapLens, MapLensGuard};
+
+// Writes that create missing intermediates (empty options, map entries, short vectors)
+pub mod vivify;
+pub use vivify::{Entry, FillKp, OrDefault};
+
+// Copy-on-write updates of Arc / Rc snapshots that share every untouched subtree
+#[cfg(feature = "alloc")]
+pub mod cow;
+#[cfg(feature = "alloc")]
+pub use cow::{CowKp, MakeMut, SharedPtr};
+
+// Plain field paths as byte offsets (offset_of!), composed in const fns
+pub mod offset;
+pub use offset::OffsetKp;
+
+// Type-changing, by-value keypaths (Config<String> -> Config<Url>)
+pub mod poly;
+pub use poly::{PolyGet, PolyKp, PolyMap, PolyOver};
+
+// Keypath identity (Eq / Hash / Ord, prefix queries) carried through composition
+pub mod id;
+pub use id::{IdKp, KpId, KpSegment};
+
+// Guard-based keypaths for DashMap / ArcSwap (features `dashmap`, `arc_swap`)
+#[cfg(any(feature = "dashmap", feature = "arc_swap"))]
+pub mod concurrent;
+#[cfg(feature = "dashmap")]
+pub use concurrent::DashMapKp;
+#[cfg(feature = "arc_swap")]
+pub use concurrent::ArcSwapKp;
+
+// Const keypaths of fn pointers: static tables, const composition, comparable
+pub mod kp_static;
+pub use kp_static::KpStatic;
 
 #[cfg(feature = "pin_project")]
 pub mod pin;
 
+// Alloc types named by `#[derive(Kp)]` output, so it builds in `no_std` crates too.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::boxed::Box;
+    pub use alloc::rc::Rc;
+    pub use alloc::string::String;
+    pub use alloc::sync::Arc;
+}
+
 /// Used so that `then_async` can infer `V2` from `AsyncKp::Value` without ambiguity
 /// (e.g. `&i32` has both `Borrow<i32>` and `Borrow<&i32>`; this picks the referent).
 /// Implemented only for reference types so there is no overlap with the blanket impl.
@@ -130,6 +196,7 @@ macro_rules! get_or_else {
     };
 }
 
+#[cfg(feature = "alloc")]
 pub type KpDynamic<R, V> = Kp<
     R,
     V,
@@ -141,6 +208,7 @@ pub type KpDynamic<R, V> = Kp<
     Box<dyn for<'a> Fn(&'a mut R) -> Option<&'a mut V> + Send + Sync>,
 >;
 
+#[cfg(feature = "alloc")]
 pub type KpBox<'a, R, V> = Kp<
     R, V,
     &'a R, &'a V,
@@ -149,6 +217,7 @@ pub type KpBox<'a, R, V> = Kp<
     Box<dyn Fn(&'a mut R) -> Option<&'a mut V> + 'a>,
 >;
 
+#[cfg(feature = "alloc")]
 pub type KpArc<'a, R, V> = Kp<
     R, V,
     &'a R, &'a V,
@@ -169,6 +238,7 @@ pub type KpType<'a, R, V> = Kp<
     for<'b> fn(&'b mut R) -> Option<&'b mut V>,
 >;
 
+#[cfg(feature = "alloc")]
 impl<'a, R, V> KpType<'a, R, V>
 where
     'a: 'static,
@@ -181,6 +251,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, R, V> From<KpType<'a, R, V>> for KpDynamic<R, V>
 where
     'a: 'static,
@@ -198,6 +269,43 @@ where
     }
 }
 
+/// Keypath whose value is produced rather than borrowed (e.g. `Weak::upgrade` yields an owned
+/// `Arc<T>`), so both the getter and setter path return `V` by value.
+pub type KpOwned<'a, R, V> = Kp<
+    R,
+    V,
+    &'a R,
+    V,
+    &'a mut R,
+    V,
+    for<'b> fn(&'b R) -> Option<V>,
+    for<'b> fn(&'b mut R) -> Option<V>,
+>;
+
+/// Weak pointers that can be upgraded to their strong counterpart. Lets the Kp derive name the
+/// value type of `{field}_upgrade()` for both [alloc::sync::Weak] (`Arc<T>`) and [alloc::rc::Weak] (`Rc<T>`).
+#[cfg(feature = "alloc")]
+pub trait WeakUpgrade {
+    type Strong;
+    fn upgrade(&self) -> Option<Self::Strong>;
+}
+#[cfg(feature = "alloc")]
+impl<T> WeakUpgrade for alloc::sync::Weak<T> {
+    type Strong = Arc<T>;
+    #[inline]
+    fn upgrade(&self) -> Option<Arc<T>> {
+        alloc::sync::Weak::upgrade(self)
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> WeakUpgrade for alloc::rc::Weak<T> {
+    type Strong = alloc::rc::Rc<T>;
+    #[inline]
+    fn upgrade(&self) -> Option<alloc::rc::Rc<T>> {
+        alloc::rc::Weak::upgrade(self)
+    }
+}
+
 // pub type KpType<R, V> = Kp<
 //     R,
 //     V,
@@ -233,6 +341,7 @@ where
 // }
 
 // New type alias for composed/transformed keypaths
+#[cfg(feature = "alloc")]
 pub type KpComposed<R, V> = Kp<
     R,
     V,
@@ -244,6 +353,7 @@ pub type KpComposed<R, V> = Kp<
     Box<dyn for<'b> Fn(&'b mut R) -> Option<&'b mut V> + Send + Sync>,
 >;
 
+#[cfg(feature = "alloc")]
 impl<R, V> Kp<
     R,
     V,
@@ -265,12 +375,96 @@ impl<R, V> Kp<
     }
 }
 
+/// Error from writing through a type-erased keypath ([AKp::set_any], [PKp::set_any]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KpError {
+    /// The root is not the keypath's root type.
+    RootTypeMismatch { expected: TypeId, found: TypeId },
+    /// The value is not the keypath's value type.
+    ValueTypeMismatch { expected: TypeId, found: TypeId },
+    /// The path did not resolve (None, other enum variant, shared `Arc`/`Rc`, read-only path).
+    NotFound,
+}
+
+impl core::fmt::Display for KpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            KpError::RootTypeMismatch { expected, found } => {
+                write!(f, "root type mismatch: expected {:?}, found {:?}", expected, found)
+            }
+            KpError::ValueTypeMismatch { expected, found } => {
+                write!(f, "value type mismatch: expected {:?}, found {:?}", expected, found)
+            }
+            KpError::NotFound => write!(f, "keypath did not resolve"),
+        }
+    }
+}
+
+impl core::error::Error for KpError {}
+
+/// Error from composing type-erased keypaths whose types do not line up ([AKp::then],
+/// [PKp::then_pkp]): the first keypath's value type is not the next keypath's root type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeMismatch {
+    /// Root type the next keypath expects
+    pub expected: TypeId,
+    /// Value type the first keypath produces
+    pub found: TypeId,
+}
+
+impl core::fmt::Display for TypeMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "cannot compose keypaths: next keypath expects root {:?}, found value {:?}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl core::error::Error for TypeMismatch {}
+
+/// Erased getter / setter of an [AKp].
+#[cfg(feature = "alloc")]
+type AKpGetter = Rc<dyn for<'r> Fn(&'r dyn Any) -> Option<&'r dyn Any>>;
+#[cfg(feature = "alloc")]
+type AKpSetter = Rc<dyn for<'r> Fn(&'r mut dyn Any) -> Option<&'r mut dyn Any>>;
+
+/// Erased getter / setter of a [PKp].
+#[cfg(feature = "alloc")]
+type PKpGetter<Root> = Rc<dyn for<'r> Fn(&'r Root) -> Option<&'r dyn Any>>;
+#[cfg(feature = "alloc")]
+type PKpSetter<Root> = Rc<dyn for<'r> Fn(&'r mut Root) -> Option<&'r mut dyn Any>>;
+
+/// Moves a boxed value into an erased value slot after checking its type.
+#[cfg(feature = "alloc")]
+type AnyAssign = Rc<dyn Fn(&mut dyn Any, Box<dyn Any>) -> Result<(), KpError>>;
+
+#[cfg(feature = "alloc")]
+fn any_assign<V: Any>() -> AnyAssign {
+    Rc::new(|slot: &mut dyn Any, value: Box<dyn Any>| {
+        let found = (*value).type_id();
+        let value = value.downcast::<V>().map_err(|_| KpError::ValueTypeMismatch {
+            expected: TypeId::of::<V>(),
+            found,
+        })?;
+        let slot = slot.downcast_mut::<V>().ok_or(KpError::NotFound)?;
+        *slot = *value;
+        Ok(())
+    })
+}
+
+#[cfg(feature = "alloc")]
 pub struct AKp {
-    getter: Rc<dyn for<'r> Fn(&'r dyn Any) -> Option<&'r dyn Any>>,
+    getter: AKpGetter,
+    setter: AKpSetter,
+    assign: AnyAssign,
     root_type_id: TypeId,
     value_type_id: TypeId,
+    id: Option<KpId>,
 }
 
+#[cfg(feature = "alloc")]
 impl AKp {
     /// Create a new AKp from a KpType (the common reference-based keypath)
     pub fn new<'a, R, V>(keypath: KpType<'a, R, V>) -> Self
@@ -281,6 +475,7 @@ impl AKp {
         let root_type_id = TypeId::of::<R>();
         let value_type_id = TypeId::of::<V>();
         let getter_fn = keypath.get;
+        let setter_fn = keypath.set;
 
         Self {
             getter: Rc::new(move |any: &dyn Any| {
@@ -290,8 +485,17 @@ impl AKp {
                     None
                 }
             }),
+            setter: Rc::new(move |any: &mut dyn Any| {
+                if let Some(root) = any.downcast_mut::<R>() {
+                    setter_fn(root).map(|value: &mut V| value as &mut dyn Any)
+                } else {
+                    None
+                }
+            }),
+            assign: any_assign::<V>(),
             root_type_id,
             value_type_id,
+            id: None,
         }
     }
 
@@ -309,6 +513,72 @@ impl AKp {
         (self.getter)(root)
     }
 
+    /// Get the value mutably as a trait object (with root type checking)
+    pub fn get_mut<'r>(&self, root: &'r mut dyn Any) -> Option<&'r mut dyn Any> {
+        (self.setter)(root)
+    }
+
+    /// Replace the value, checking both the root and the value type.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_key_paths::{AKp, KpError, KpType};
+    /// struct User { age: u32 }
+    /// let age = AKp::new(KpType::new(|u: &User| Some(&u.age), |u: &mut User| Some(&mut u.age)));
+    /// let mut user = User { age: 1 };
+    /// age.set_any(&mut user, Box::new(2u32)).unwrap();
+    /// assert_eq!(user.age, 2);
+    /// assert!(matches!(age.set_any(&mut user, Box::new("2")), Err(KpError::ValueTypeMismatch { .. })));
+    /// ```
+    pub fn set_any(&self, root: &mut dyn Any, value: Box<dyn Any>) -> Result<(), KpError> {
+        let found = (*root).type_id();
+        if found != self.root_type_id {
+            return Err(KpError::RootTypeMismatch {
+                expected: self.root_type_id,
+                found,
+            });
+        }
+        let slot = (self.setter)(root).ok_or(KpError::NotFound)?;
+        (self.assign)(slot, value)
+    }
+
+    /// Compose with `next`, checking at construction that this keypath's value type is
+    /// `next`'s root type.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_key_paths::{AKp, KpType};
+    /// struct User { address: Address }
+    /// struct Address { city: String }
+    ///
+    /// let address = AKp::new(KpType::new(|u: &User| Some(&u.address), |u: &mut User| Some(&mut u.address)));
+    /// let city = AKp::new(KpType::new(|a: &Address| Some(&a.city), |a: &mut Address| Some(&mut a.city)));
+    ///
+    /// let user_city = address.then(&city).unwrap();
+    /// let user = User { address: Address { city: "Oslo".to_string() } };
+    /// assert_eq!(user_city.get_as::<User, String>(&user), Some(Some(&"Oslo".to_string())));
+    /// assert!(city.then(&address).is_err());
+    /// ```
+    pub fn then(&self, next: &AKp) -> Result<AKp, TypeMismatch> {
+        if self.value_type_id != next.root_type_id {
+            return Err(TypeMismatch {
+                expected: next.root_type_id,
+                found: self.value_type_id,
+            });
+        }
+        let (first_get, next_get) = (self.getter.clone(), next.getter.clone());
+        let (first_set, next_set) = (self.setter.clone(), next.setter.clone());
+
+        Ok(AKp {
+            getter: Rc::new(move |root: &dyn Any| first_get(root).and_then(|mid| next_get(mid))),
+            setter: Rc::new(move |root: &mut dyn Any| first_set(root).and_then(|mid| next_set(mid))),
+            assign: next.assign.clone(),
+            root_type_id: self.root_type_id,
+            value_type_id: next.value_type_id,
+            id: id::join(&self.id, &next.id),
+        })
+    }
+
     /// Get the TypeId of the Root type
     pub fn root_type_id(&self) -> TypeId {
         self.root_type_id
@@ -319,6 +589,18 @@ impl AKp {
         self.value_type_id
     }
 
+    /// Identity attached with `with_id` (see [KpId]). `then` keeps it when both sides are
+    /// identified, `filter` always does; `map` and the `for_*` root adapters drop it.
+    pub fn id(&self) -> Option<&KpId> {
+        self.id.as_ref()
+    }
+
+    /// Attach an identity, replacing any existing one.
+    pub fn with_id(mut self, id: KpId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Try to get the value with full type checking
     pub fn get_as<'a, Root: Any, Value: Any>(&self, root: &'a Root) -> Option<Option<&'a Value>> {
         if self.root_type_id == TypeId::of::<Root>() && self.value_type_id == TypeId::of::<Value>()
@@ -332,6 +614,22 @@ impl AKp {
         }
     }
 
+    /// Try to get the value mutably with full type checking
+    pub fn get_mut_as<'a, Root: Any, Value: Any>(
+        &self,
+        root: &'a mut Root,
+    ) -> Option<Option<&'a mut Value>> {
+        if self.root_type_id == TypeId::of::<Root>() && self.value_type_id == TypeId::of::<Value>()
+        {
+            Some(
+                self.get_mut(root as &mut dyn Any)
+                    .and_then(|any| any.downcast_mut::<Value>()),
+            )
+        } else {
+            None
+        }
+    }
+
     /// Get a human-readable name for the value type
     pub fn kind_name(&self) -> String {
         format!("{:?}", self.value_type_id)
@@ -349,6 +647,7 @@ impl AKp {
     {
         let value_type_id = self.value_type_id;
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
 
         AKp {
             getter: Rc::new(move |any: &dyn Any| {
@@ -358,8 +657,17 @@ impl AKp {
                     None
                 }
             }),
+            setter: Rc::new(move |any: &mut dyn Any| {
+                if let Some(arc) = any.downcast_mut::<Arc<Root>>() {
+                    Arc::get_mut(arc).and_then(|root| setter(root as &mut dyn Any))
+                } else {
+                    None
+                }
+            }),
+            assign: self.assign.clone(),
             root_type_id: TypeId::of::<Arc<Root>>(),
             value_type_id,
+            id: None,
         }
     }
 
@@ -370,6 +678,7 @@ impl AKp {
     {
         let value_type_id = self.value_type_id;
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
 
         AKp {
             getter: Rc::new(move |any: &dyn Any| {
@@ -379,8 +688,17 @@ impl AKp {
                     None
                 }
             }),
+            setter: Rc::new(move |any: &mut dyn Any| {
+                if let Some(boxed) = any.downcast_mut::<Box<Root>>() {
+                    setter(boxed.as_mut() as &mut dyn Any)
+                } else {
+                    None
+                }
+            }),
+            assign: self.assign.clone(),
             root_type_id: TypeId::of::<Box<Root>>(),
             value_type_id,
+            id: None,
         }
     }
 
@@ -391,6 +709,7 @@ impl AKp {
     {
         let value_type_id = self.value_type_id;
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
 
         AKp {
             getter: Rc::new(move |any: &dyn Any| {
@@ -400,8 +719,17 @@ impl AKp {
                     None
                 }
             }),
+            setter: Rc::new(move |any: &mut dyn Any| {
+                if let Some(rc) = any.downcast_mut::<Rc<Root>>() {
+                    Rc::get_mut(rc).and_then(|root| setter(root as &mut dyn Any))
+                } else {
+                    None
+                }
+            }),
+            assign: self.assign.clone(),
             root_type_id: TypeId::of::<Rc<Root>>(),
             value_type_id,
+            id: None,
         }
     }
 
@@ -412,6 +740,7 @@ impl AKp {
     {
         let value_type_id = self.value_type_id;
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
 
         AKp {
             getter: Rc::new(move |any: &dyn Any| {
@@ -421,8 +750,17 @@ impl AKp {
                     None
                 }
             }),
+            setter: Rc::new(move |any: &mut dyn Any| {
+                if let Some(opt) = any.downcast_mut::<Option<Root>>() {
+                    opt.as_mut().and_then(|root| setter(root as &mut dyn Any))
+                } else {
+                    None
+                }
+            }),
+            assign: self.assign.clone(),
             root_type_id: TypeId::of::<Option<Root>>(),
             value_type_id,
+            id: None,
         }
     }
 
@@ -434,6 +772,7 @@ impl AKp {
     {
         let value_type_id = self.value_type_id;
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
 
         AKp {
             getter: Rc::new(move |any: &dyn Any| {
@@ -446,8 +785,20 @@ impl AKp {
                     None
                 }
             }),
+            setter: Rc::new(move |any: &mut dyn Any| {
+                if let Some(result) = any.downcast_mut::<Result<Root, E>>() {
+                    result
+                        .as_mut()
+                        .ok()
+                        .and_then(|root| setter(root as &mut dyn Any))
+                } else {
+                    None
+                }
+            }),
+            assign: self.assign.clone(),
             root_type_id: TypeId::of::<Result<Root, E>>(),
             value_type_id,
+            id: None,
         }
     }
 
@@ -495,8 +846,12 @@ impl AKp {
                     None
                 }
             }),
+            // Mapped values are computed copies: nothing to write back to
+            setter: Rc::new(|_: &mut dyn Any| None),
+            assign: any_assign::<MappedValue>(),
             root_type_id: orig_root_type_id,
             value_type_id: mapped_type_id,
+            id: None,
         }
     }
 
@@ -521,6 +876,9 @@ impl AKp {
         let orig_root_type_id = self.root_type_id;
         let orig_value_type_id = self.value_type_id;
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
+        let predicate = Rc::new(predicate);
+        let set_predicate = predicate.clone();
 
         AKp {
             getter: Rc::new(move |any_root: &dyn Any| {
@@ -541,17 +899,38 @@ impl AKp {
                     None
                 }
             }),
+            setter: Rc::new(move |any_root: &mut dyn Any| {
+                if (*any_root).type_id() == orig_root_type_id
+                    && orig_value_type_id == TypeId::of::<Value>()
+                {
+                    setter(any_root).filter(|any_value| {
+                        any_value
+                            .downcast_ref::<Value>()
+                            .map(|val| set_predicate(val))
+                            .unwrap_or(false)
+                    })
+                } else {
+                    None
+                }
+            }),
+            assign: self.assign.clone(),
             root_type_id: orig_root_type_id,
             value_type_id: orig_value_type_id,
+            id: self.id.clone(),
         }
     }
 }
+#[cfg(feature = "alloc")]
 pub struct PKp<Root> {
-    getter: Rc<dyn for<'r> Fn(&'r Root) -> Option<&'r dyn Any>>,
+    getter: PKpGetter<Root>,
+    setter: PKpSetter<Root>,
+    assign: AnyAssign,
     value_type_id: TypeId,
-    _phantom: std::marker::PhantomData<Root>,
+    id: Option<KpId>,
+    _phantom: core::marker::PhantomData<Root>,
 }
 
+#[cfg(feature = "alloc")]
 impl<Root> PKp<Root>
 where
     Root: 'static,
@@ -563,11 +942,17 @@ where
     {
         let value_type_id = TypeId::of::<V>();
         let getter_fn = keypath.get;
+        let setter_fn = keypath.set;
 
         Self {
             getter: Rc::new(move |root: &Root| getter_fn(root).map(|val: &V| val as &dyn Any)),
+            setter: Rc::new(move |root: &mut Root| {
+                setter_fn(root).map(|val: &mut V| val as &mut dyn Any)
+            }),
+            assign: any_assign::<V>(),
             value_type_id,
-            _phantom: std::marker::PhantomData,
+            id: None,
+            _phantom: core::marker::PhantomData,
         }
     }
 
@@ -584,11 +969,90 @@ where
         (self.getter)(root)
     }
 
+    /// Get the value mutably as a trait object
+    pub fn get_mut<'r>(&self, root: &'r mut Root) -> Option<&'r mut dyn Any> {
+        (self.setter)(root)
+    }
+
+    /// Replace the value, checking the value type.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_key_paths::{KpType, PKp};
+    /// struct User { name: String }
+    /// let name = PKp::new(KpType::new(|u: &User| Some(&u.name), |u: &mut User| Some(&mut u.name)));
+    /// let mut user = User { name: "a".to_string() };
+    /// name.set_any(&mut user, Box::new("b".to_string())).unwrap();
+    /// assert_eq!(user.name, "b");
+    /// assert!(name.set_any(&mut user, Box::new(1)).is_err());
+    /// ```
+    pub fn set_any(&self, root: &mut Root, value: Box<dyn Any>) -> Result<(), KpError> {
+        let slot = (self.setter)(root).ok_or(KpError::NotFound)?;
+        (self.assign)(slot, value)
+    }
+
+    /// Compose with a keypath rooted at this keypath's value type `V`, checking the types at
+    /// construction.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_key_paths::{KpType, PKp};
+    /// struct User { address: Address }
+    /// struct Address { city: String }
+    ///
+    /// let address = PKp::new(KpType::new(|u: &User| Some(&u.address), |u: &mut User| Some(&mut u.address)));
+    /// let city = PKp::new(KpType::new(|a: &Address| Some(&a.city), |a: &mut Address| Some(&mut a.city)));
+    ///
+    /// let mut user = User { address: Address { city: "Oslo".to_string() } };
+    /// let user_city = address.then_pkp(&city).unwrap();
+    /// user_city.set_any(&mut user, Box::new("Bergen".to_string())).unwrap();
+    /// assert_eq!(user.address.city, "Bergen");
+    /// ```
+    pub fn then_pkp<V: Any>(&self, next: &PKp<V>) -> Result<PKp<Root>, TypeMismatch> {
+        if self.value_type_id != TypeId::of::<V>() {
+            return Err(TypeMismatch {
+                expected: TypeId::of::<V>(),
+                found: self.value_type_id,
+            });
+        }
+        let (first_get, next_get) = (self.getter.clone(), next.getter.clone());
+        let (first_set, next_set) = (self.setter.clone(), next.setter.clone());
+
+        Ok(PKp {
+            getter: Rc::new(move |root: &Root| {
+                first_get(root)
+                    .and_then(|mid| mid.downcast_ref::<V>())
+                    .and_then(|mid| next_get(mid))
+            }),
+            setter: Rc::new(move |root: &mut Root| {
+                first_set(root)
+                    .and_then(|mid| mid.downcast_mut::<V>())
+                    .and_then(|mid| next_set(mid))
+            }),
+            assign: next.assign.clone(),
+            value_type_id: next.value_type_id,
+            id: id::join(&self.id, &next.id),
+            _phantom: core::marker::PhantomData,
+        })
+    }
+
     /// Get the TypeId of the Value type
     pub fn value_type_id(&self) -> TypeId {
         self.value_type_id
     }
 
+    /// Identity attached with `with_id` (see [KpId]). `then` keeps it when both sides are
+    /// identified, `filter` always does; `map` and the `for_*` root adapters drop it.
+    pub fn id(&self) -> Option<&KpId> {
+        self.id.as_ref()
+    }
+
+    /// Attach an identity, replacing any existing one.
+    pub fn with_id(mut self, id: KpId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Try to downcast the result to a specific type
     pub fn get_as<'a, Value: Any>(&self, root: &'a Root) -> Option<&'a Value> {
         if self.value_type_id == TypeId::of::<Value>() {
@@ -598,6 +1062,15 @@ where
         }
     }
 
+    /// Try to downcast the mutable result to a specific type
+    pub fn get_mut_as<'a, Value: Any>(&self, root: &'a mut Root) -> Option<&'a mut Value> {
+        if self.value_type_id == TypeId::of::<Value>() {
+            self.get_mut(root).and_then(|any| any.downcast_mut::<Value>())
+        } else {
+            None
+        }
+    }
+
     /// Get a human-readable name for the value type
     pub fn kind_name(&self) -> String {
         format!("{:?}", self.value_type_id)
@@ -606,48 +1079,64 @@ where
     /// Adapt this keypath to work with Arc<Root> instead of Root
     pub fn for_arc(&self) -> PKp<Arc<Root>> {
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
         let value_type_id = self.value_type_id;
 
         PKp {
             getter: Rc::new(move |arc: &Arc<Root>| getter(arc.as_ref())),
+            setter: Rc::new(move |arc: &mut Arc<Root>| Arc::get_mut(arc).and_then(|root| setter(root))),
+            assign: self.assign.clone(),
             value_type_id,
-            _phantom: std::marker::PhantomData,
+            id: None,
+            _phantom: core::marker::PhantomData,
         }
     }
 
     /// Adapt this keypath to work with Box<Root> instead of Root
     pub fn for_box(&self) -> PKp<Box<Root>> {
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
         let value_type_id = self.value_type_id;
 
         PKp {
             getter: Rc::new(move |boxed: &Box<Root>| getter(boxed.as_ref())),
+            setter: Rc::new(move |boxed: &mut Box<Root>| setter(boxed.as_mut())),
+            assign: self.assign.clone(),
             value_type_id,
-            _phantom: std::marker::PhantomData,
+            id: None,
+            _phantom: core::marker::PhantomData,
         }
     }
 
     /// Adapt this keypath to work with Rc<Root> instead of Root
     pub fn for_rc(&self) -> PKp<Rc<Root>> {
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
         let value_type_id = self.value_type_id;
 
         PKp {
             getter: Rc::new(move |rc: &Rc<Root>| getter(rc.as_ref())),
+            setter: Rc::new(move |rc: &mut Rc<Root>| Rc::get_mut(rc).and_then(|root| setter(root))),
+            assign: self.assign.clone(),
             value_type_id,
-            _phantom: std::marker::PhantomData,
+            id: None,
+            _phantom: core::marker::PhantomData,
         }
     }
 
     /// Adapt this keypath to work with Option<Root> instead of Root
     pub fn for_option(&self) -> PKp<Option<Root>> {
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
         let value_type_id = self.value_type_id;
 
         PKp {
             getter: Rc::new(move |opt: &Option<Root>| opt.as_ref().and_then(|root| getter(root))),
+            setter: Rc::new(move |opt: &mut Option<Root>| opt.as_mut().and_then(|root| setter(root))),
+            assign: self.assign.clone(),
             value_type_id,
-            _phantom: std::marker::PhantomData,
+            id: None,
+            _phantom: core::marker::PhantomData,
         }
     }
 
@@ -657,14 +1146,20 @@ where
         E: 'static,
     {
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
         let value_type_id = self.value_type_id;
 
         PKp {
             getter: Rc::new(move |result: &Result<Root, E>| {
                 result.as_ref().ok().and_then(|root| getter(root))
             }),
+            setter: Rc::new(move |result: &mut Result<Root, E>| {
+                result.as_mut().ok().and_then(|root| setter(root))
+            }),
+            assign: self.assign.clone(),
             value_type_id,
-            _phantom: std::marker::PhantomData,
+            id: None,
+            _phantom: core::marker::PhantomData,
         }
     }
 
@@ -707,8 +1202,12 @@ where
                     }
                 })
             }),
+            // Mapped values are computed copies: nothing to write back to
+            setter: Rc::new(|_: &mut Root| None),
+            assign: any_assign::<MappedValue>(),
             value_type_id: mapped_type_id,
-            _phantom: std::marker::PhantomData,
+            id: None,
+            _phantom: core::marker::PhantomData,
         }
     }
 
@@ -732,6 +1231,9 @@ where
     {
         let orig_type_id = self.value_type_id;
         let getter = self.getter.clone();
+        let setter = self.setter.clone();
+        let predicate = Rc::new(predicate);
+        let set_predicate = predicate.clone();
 
         PKp {
             getter: Rc::new(move |root: &Root| {
@@ -747,8 +1249,22 @@ where
                     }
                 })
             }),
+            setter: Rc::new(move |root: &mut Root| {
+                if orig_type_id == TypeId::of::<Value>() {
+                    setter(root).filter(|any_value| {
+                        any_value
+                            .downcast_ref::<Value>()
+                            .map(|val| set_predicate(val))
+                            .unwrap_or(false)
+                    })
+                } else {
+                    None
+                }
+            }),
+            assign: self.assign.clone(),
             value_type_id: orig_type_id,
-            _phantom: std::marker::PhantomData,
+            id: self.id.clone(),
+            _phantom: core::marker::PhantomData,
         }
     }
 }
@@ -769,9 +1285,9 @@ where
 #[derive(Clone)]
 pub struct Kp<R, V, Root, Value, MutRoot, MutValue, G, S>
 where
-    Root: std::borrow::Borrow<R>,
-    MutRoot: std::borrow::BorrowMut<R>,
-    MutValue: std::borrow::BorrowMut<V>,
+    Root: core::borrow::Borrow<R>,
+    MutRoot: core::borrow::BorrowMut<R>,
+    MutValue: core::borrow::BorrowMut<V>,
     G: Fn(Root) -> Option<Value>,
     S: Fn(MutRoot) -> Option<MutValue>,
 {
@@ -779,24 +1295,24 @@ where
     pub(crate) get: G,
     /// Setter closure: used by [Kp::get_mut] for mutation.
     pub(crate) set: S,
-    _p: std::marker::PhantomData<(R, V, Root, Value, MutRoot, MutValue)>,
+    _p: core::marker::PhantomData<(R, V, Root, Value, MutRoot, MutValue)>,
 }
 
 // Kp is a functional component (get/set) with no owned data; Send/Sync follow from G and S.
 unsafe impl<R, V, Root, Value, MutRoot, MutValue, G, S> Send for Kp<R, V, Root, Value, MutRoot, MutValue, G, S>
 where
-    Root: std::borrow::Borrow<R>,
-    MutRoot: std::borrow::BorrowMut<R>,
-    MutValue: std::borrow::BorrowMut<V>,
+    Root: core::borrow::Borrow<R>,
+    MutRoot: core::borrow::BorrowMut<R>,
+    MutValue: core::borrow::BorrowMut<V>,
     G: Fn(Root) -> Option<Value> + Send,
     S: Fn(MutRoot) -> Option<MutValue> + Send,
 {
 }
 unsafe impl<R, V, Root, Value, MutRoot, MutValue, G, S> Sync for Kp<R, V, Root, Value, MutRoot, MutValue, G, S>
 where
-    Root: std::borrow::Borrow<R>,
-    MutRoot: std::borrow::BorrowMut<R>,
-    MutValue: std::borrow::BorrowMut<V>,
+    Root: core::borrow::Borrow<R>,
+    MutRoot: core::borrow::BorrowMut<R>,
+    MutValue: core::borrow::BorrowMut<V>,
     G: Fn(Root) -> Option<Value> + Sync,
     S: Fn(MutRoot) -> Option<MutValue> + Sync,
 {
@@ -804,10 +1320,10 @@ where
 
 impl<R, V, Root, Value, MutRoot, MutValue, G, S> Kp<R, V, Root, Value, MutRoot, MutValue, G, S>
 where
-    Root: std::borrow::Borrow<R>,
-    Value: std::borrow::Borrow<V>,
-    MutRoot: std::borrow::BorrowMut<R>,
-    MutValue: std::borrow::BorrowMut<V>,
+    Root: core::borrow::Borrow<R>,
+    Value: core::borrow::Borrow<V>,
+    MutRoot: core::borrow::BorrowMut<R>,
+    MutValue: core::borrow::BorrowMut<V>,
     G: Fn(Root) -> Option<Value>,
     S: Fn(MutRoot) -> Option<MutValue>,
 {
@@ -815,7 +1331,7 @@ where
         Self {
             get,
             set,
-            _p: std::marker::PhantomData,
+            _p: core::marker::PhantomData,
         }
     }
 
@@ -823,10 +1339,28 @@ where
         Self {
             get,
             set,
-            _p: std::marker::PhantomData,
+            _p: core::marker::PhantomData,
         }
     }
 
+    /// The getter and setter, e.g. to hand them to another keypath library.
+    pub fn into_parts(self) -> (G, S) {
+        (self.get, self.set)
+    }
+
+    /// Pair this keypath with an identity (see [KpId]).
+    pub fn with_id(self, id: KpId) -> IdKp<Self> {
+        IdKp::new(self, id)
+    }
+
+    /// Identify this keypath as the single step `name` from `R` to `V`.
+    pub fn with_segment(self, name: &'static str) -> IdKp<Self>
+    where
+        R: 'static,
+        V: 'static,
+    {
+        self.with_id(KpId::field::<R, V>(name))
+    }
 
     #[inline]
     pub fn get(&self, root: Root) -> Option<Value> {
@@ -877,8 +1411,8 @@ where
         impl Fn(MutRoot) -> Option<MutSubValue> + use<SV, SubValue, MutSubValue, G2, S2, R, V, Root, Value, MutRoot, MutValue, G, S>,
     >
     where
-        SubValue: std::borrow::Borrow<SV>,
-        MutSubValue: std::borrow::BorrowMut<SV>,
+        SubValue: core::borrow::Borrow<SV>,
+        MutSubValue: core::borrow::BorrowMut<SV>,
         G2: Fn(Value) -> Option<SubValue>,
         S2: Fn(MutValue) -> Option<MutSubValue>,
         V: 'static,
@@ -890,6 +1424,7 @@ where
     }
 
     /// Chain with a sync [crate::lock::LockKp]. Use `.get(root)` / `.get_mut(root)` on the returned keypath.
+    #[cfg(feature = "std")]
     pub fn then_lock<
         Lock,
         Mid,
@@ -930,14 +1465,14 @@ where
     where
         V: 'static + Clone,
         V2: 'static,
-        Value: std::borrow::Borrow<V>,
-        Value2: std::borrow::Borrow<V2>,
-        MutValue: std::borrow::BorrowMut<V>,
-        MutValue2: std::borrow::BorrowMut<V2>,
-        LockValue: std::borrow::Borrow<Lock>,
-        MidValue: std::borrow::Borrow<Mid>,
-        MutLock: std::borrow::BorrowMut<Lock>,
-        MutMid: std::borrow::BorrowMut<Mid>,
+        Value: core::borrow::Borrow<V>,
+        Value2: core::borrow::Borrow<V2>,
+        MutValue: core::borrow::BorrowMut<V>,
+        MutValue2: core::borrow::BorrowMut<V2>,
+        LockValue: core::borrow::Borrow<Lock>,
+        MidValue: core::borrow::Borrow<Mid>,
+        MutLock: core::borrow::BorrowMut<Lock>,
+        MutMid: core::borrow::BorrowMut<Mid>,
         G1: Fn(Value) -> Option<LockValue>,
         S1: Fn(MutValue) -> Option<MutLock>,
         L: crate::lock::LockAccess<Lock, MidValue> + crate::lock::LockAccess<Lock, MutMid>,
@@ -947,7 +1482,7 @@ where
         crate::lock::KpThenLockKp {
             first: self,
             second: lock_kp,
-            _p: std::marker::PhantomData,
+            _p: core::marker::PhantomData,
         }
     }
 
@@ -972,19 +1507,20 @@ where
         V: 'static,
         Struct: Unpin + 'static,
         Output: 'static,
-        Value: std::borrow::Borrow<Struct>,
-        MutValue: std::borrow::BorrowMut<Struct>,
+        Value: core::borrow::Borrow<Struct>,
+        MutValue: core::borrow::BorrowMut<Struct>,
         L: crate::pin::PinFutureAwaitLike<Struct, Output> + Sync,
     {
         crate::pin::KpThenPinFuture {
             first: self,
             second: pin_fut,
-            _p: std::marker::PhantomData,
+            _p: core::marker::PhantomData,
         }
     }
 
     /// Chain with an async keypath (e.g. [crate::async_lock::AsyncLockKp]). Use `.get(&root).await` on the returned keypath.
     /// When `AsyncKp::Value` is a reference type (`&T` / `&mut T`), `V2` is inferred as `T` via [KeyPathValueTarget].
+    #[cfg(feature = "std")]
     pub fn then_async<AsyncKp>(
         self,
         async_kp: AsyncKp,
@@ -1003,23 +1539,24 @@ where
     >
     where
         V: 'static,
-        Value: std::borrow::Borrow<V>,
-        MutValue: std::borrow::BorrowMut<V>,
+        Value: core::borrow::Borrow<V>,
+        MutValue: core::borrow::BorrowMut<V>,
         AsyncKp: crate::async_lock::AsyncKeyPathLike<Value, MutValue>,
         AsyncKp::Value: KeyPathValueTarget
-            + std::borrow::Borrow<<AsyncKp::Value as KeyPathValueTarget>::Target>,
-        AsyncKp::MutValue: std::borrow::BorrowMut<<AsyncKp::Value as KeyPathValueTarget>::Target>,
+            + core::borrow::Borrow<<AsyncKp::Value as KeyPathValueTarget>::Target>,
+        AsyncKp::MutValue: core::borrow::BorrowMut<<AsyncKp::Value as KeyPathValueTarget>::Target>,
         <AsyncKp::Value as KeyPathValueTarget>::Target: 'static,
     {
         crate::async_lock::KpThenAsyncKeyPath {
             first: self,
             second: async_kp,
-            _p: std::marker::PhantomData,
+            _p: core::marker::PhantomData,
         }
     }
 
     /// Map the value through a transformation function
     /// Returns a new keypath that transforms the value when accessed
+    /// (read-only; [Kp::map_lens] also writes back through an inverse conversion)
     ///
     /// # Example
     /// ```
@@ -1172,6 +1709,7 @@ where
     /// let tags_kp = KpType::new(|u: &User| Some(&u.tags), |_| None);
     /// // Use with a closure that returns an iterator
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn flat_map<I, Item, F>(&self, mapper: F) -> impl Fn(Root) -> Vec<Item>
     where
         // No Copy needed - mapper is only captured once by the returned closure
@@ -1558,8 +2096,8 @@ where
         impl Fn(MutRoot) -> Option<MutSubValue>,
     >
     where
-        SubValue: std::borrow::Borrow<SV>,
-        MutSubValue: std::borrow::BorrowMut<SV>,
+        SubValue: core::borrow::Borrow<SV>,
+        MutSubValue: core::borrow::BorrowMut<SV>,
         G2: Fn(Value) -> Option<SubValue>,
         S2: Fn(MutValue) -> Option<MutSubValue>,
         V: 'static,
@@ -1567,17 +2105,18 @@ where
         self.then(next)
     }
 
+    #[cfg(feature = "alloc")]
     pub fn for_arc<'b>(
         &self,
     ) -> Kp<
-        std::sync::Arc<R>,
+        alloc::sync::Arc<R>,
         V,
-        std::sync::Arc<R>,
+        alloc::sync::Arc<R>,
         Value,
-        std::sync::Arc<R>,
+        alloc::sync::Arc<R>,
         MutValue,
-        impl Fn(std::sync::Arc<R>) -> Option<Value>,
-        impl Fn(std::sync::Arc<R>) -> Option<MutValue>,
+        impl Fn(alloc::sync::Arc<R>) -> Option<Value>,
+        impl Fn(alloc::sync::Arc<R>) -> Option<MutValue>,
     >
     where
         R: 'b,
@@ -1586,18 +2125,19 @@ where
         MutRoot: for<'a> From<&'a mut R>,
     {
         Kp::new(
-            move |arc_root: std::sync::Arc<R>| {
+            move |arc_root: alloc::sync::Arc<R>| {
                 let r_ref: &R = &*arc_root;
                 (&self.get)(Root::from(r_ref))
             },
-            move |mut arc_root: std::sync::Arc<R>| {
+            move |mut arc_root: alloc::sync::Arc<R>| {
                 // Get mutable reference only if we have exclusive ownership
-                std::sync::Arc::get_mut(&mut arc_root)
+                alloc::sync::Arc::get_mut(&mut arc_root)
                     .and_then(|r_mut| (&self.set)(MutRoot::from(r_mut)))
             },
         )
     }
 
+    #[cfg(feature = "alloc")]
     pub fn for_box<'a>(
         &self,
     ) -> Kp<
@@ -1660,8 +2200,8 @@ where
 
 impl<R, Root, MutRoot, G, S> Kp<R, R, Root, Root, MutRoot, MutRoot, G, S>
 where
-    Root: std::borrow::Borrow<R>,
-    MutRoot: std::borrow::BorrowMut<R>,
+    Root: core::borrow::Borrow<R>,
+    MutRoot: core::borrow::BorrowMut<R>,
     G: Fn(Root) -> Option<Root>,
     S: Fn(MutRoot) -> Option<MutRoot>,
 {
@@ -1693,10 +2233,10 @@ where
 /// 2. As a namespace for static factory methods: `EnumKp::for_ok()`, `EnumKp::for_some()`, etc.
 pub struct EnumKp<Enum, Variant, Root, Value, MutRoot, MutValue, G, S, E>
 where
-    Root: std::borrow::Borrow<Enum>,
-    Value: std::borrow::Borrow<Variant>,
-    MutRoot: std::borrow::BorrowMut<Enum>,
-    MutValue: std::borrow::BorrowMut<Variant>,
+    Root: core::borrow::Borrow<Enum>,
+    Value: core::borrow::Borrow<Variant>,
+    MutRoot: core::borrow::BorrowMut<Enum>,
+    MutValue: core::borrow::BorrowMut<Variant>,
     G: Fn(Root) -> Option<Value>,
     S: Fn(MutRoot) -> Option<MutValue>,
     E: Fn(Variant) -> Enum,
@@ -1709,10 +2249,10 @@ where
 unsafe impl<Enum, Variant, Root, Value, MutRoot, MutValue, G, S, E> Send
     for EnumKp<Enum, Variant, Root, Value, MutRoot, MutValue, G, S, E>
 where
-    Root: std::borrow::Borrow<Enum>,
-    Value: std::borrow::Borrow<Variant>,
-    MutRoot: std::borrow::BorrowMut<Enum>,
-    MutValue: std::borrow::BorrowMut<Variant>,
+    Root: core::borrow::Borrow<Enum>,
+    Value: core::borrow::Borrow<Variant>,
+    MutRoot: core::borrow::BorrowMut<Enum>,
+    MutValue: core::borrow::BorrowMut<Variant>,
     G: Fn(Root) -> Option<Value> + Send,
     S: Fn(MutRoot) -> Option<MutValue> + Send,
     E: Fn(Variant) -> Enum + Send,
@@ -1721,10 +2261,10 @@ where
 unsafe impl<Enum, Variant, Root, Value, MutRoot, MutValue, G, S, E> Sync
     for EnumKp<Enum, Variant, Root, Value, MutRoot, MutValue, G, S, E>
 where
-    Root: std::borrow::Borrow<Enum>,
-    Value: std::borrow::Borrow<Variant>,
-    MutRoot: std::borrow::BorrowMut<Enum>,
-    MutValue: std::borrow::BorrowMut<Variant>,
+    Root: core::borrow::Borrow<Enum>,
+    Value: core::borrow::Borrow<Variant>,
+    MutRoot: core::borrow::BorrowMut<Enum>,
+    MutValue: core::borrow::BorrowMut<Variant>,
     G: Fn(Root) -> Option<Value> + Sync,
     S: Fn(MutRoot) -> Option<MutValue> + Sync,
     E: Fn(Variant) -> Enum + Sync,
@@ -1734,10 +2274,10 @@ where
 impl<Enum, Variant, Root, Value, MutRoot, MutValue, G, S, E>
     EnumKp<Enum, Variant, Root, Value, MutRoot, MutValue, G, S, E>
 where
-    Root: std::borrow::Borrow<Enum>,
-    Value: std::borrow::Borrow<Variant>,
-    MutRoot: std::borrow::BorrowMut<Enum>,
-    MutValue: std::borrow::BorrowMut<Variant>,
+    Root: core::borrow::Borrow<Enum>,
+    Value: core::borrow::Borrow<Variant>,
+    MutRoot: core::borrow::BorrowMut<Enum>,
+    MutValue: core::borrow::BorrowMut<Variant>,
     G: Fn(Root) -> Option<Value>,
     S: Fn(MutRoot) -> Option<MutValue>,
     E: Fn(Variant) -> Enum,
@@ -1992,6 +2532,7 @@ pub fn variant_of<'a, Enum, Variant>(
 /// let kp = kp_box();
 /// assert_eq!(kp.get(&boxed), Some(&"value".to_string()));
 /// ```
+#[cfg(feature = "alloc")]
 pub fn kp_box<'a, T>() -> KpType<'a, Box<T>, T> {
     Kp::new(
         |b: &Box<T>| Some(b.as_ref()),
@@ -2009,6 +2550,7 @@ pub fn kp_box<'a, T>() -> KpType<'a, Box<T>, T> {
 /// let kp = kp_arc();
 /// assert_eq!(kp.get(&arc), Some(&"value".to_string()));
 /// ```
+#[cfg(feature = "alloc")]
 pub fn kp_arc<'a, T>() -> Kp<
     Arc<T>,
     T,
@@ -2035,26 +2577,30 @@ pub fn kp_arc<'a, T>() -> Kp<
 /// let kp = kp_rc();
 /// assert_eq!(kp.get(&rc), Some(&"value".to_string()));
 /// ```
+#[cfg(feature = "alloc")]
 pub fn kp_rc<'a, T>() -> Kp<
-    std::rc::Rc<T>,
+    alloc::rc::Rc<T>,
     T,
-    &'a std::rc::Rc<T>,
+    &'a alloc::rc::Rc<T>,
     &'a T,
-    &'a mut std::rc::Rc<T>,
+    &'a mut alloc::rc::Rc<T>,
     &'a mut T,
-    for<'b> fn(&'b std::rc::Rc<T>) -> Option<&'b T>,
-    for<'b> fn(&'b mut std::rc::Rc<T>) -> Option<&'b mut T>,
+    for<'b> fn(&'b alloc::rc::Rc<T>) -> Option<&'b T>,
+    for<'b> fn(&'b mut alloc::rc::Rc<T>) -> Option<&'b mut T>,
 > {
     Kp::new(
-        |rc: &std::rc::Rc<T>| Some(rc.as_ref()),
-        |rc: &mut std::rc::Rc<T>| std::rc::Rc::get_mut(rc),
+        |rc: &alloc::rc::Rc<T>| Some(rc.as_ref()),
+        |rc: &mut alloc::rc::Rc<T>| alloc::rc::Rc::get_mut(rc),
     )
 }
 
 // ========== PARTIAL KEYPATHS (Hide Value Type) ==========
 
-use std::any::{Any, TypeId};
-use std::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use core::any::Any;
+use core::any::TypeId;
 
 /// PKp (PartialKeyPath) - Hides the Value type but keeps Root visible
 /// Useful for storing keypaths in collections without knowing the exact Value type
@@ -2797,6 +3343,379 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pkp_get_mut_and_set_any() {
+        struct User {
+            name: String,
+            age: u32,
+        }
+
+        let mut user = User {
+            name: "Eve".to_string(),
+            age: 20,
+        };
+        let fields = vec![
+            PKp::new(KpType::new(|u: &User| Some(&u.name), |u: &mut User| Some(&mut u.name))),
+            PKp::new(KpType::new(|u: &User| Some(&u.age), |u: &mut User| Some(&mut u.age))),
+        ];
+
+        *fields[1].get_mut_as::<u32>(&mut user).unwrap() += 1;
+        assert_eq!(user.age, 21);
+        assert!(fields[1].get_mut_as::<String>(&mut user).is_none());
+
+        fields[0].get_mut(&mut user).unwrap().downcast_mut::<String>().unwrap().push('!');
+        assert_eq!(user.name, "Eve!");
+
+        fields[0]
+            .set_any(&mut user, Box::new("Mallory".to_string()))
+            .unwrap();
+        assert_eq!(user.name, "Mallory");
+        assert_eq!(
+            fields[1].set_any(&mut user, Box::new("30")),
+            Err(KpError::ValueTypeMismatch {
+                expected: TypeId::of::<u32>(),
+                found: TypeId::of::<&str>(),
+            })
+        );
+        assert_eq!(user.age, 21);
+
+        // Read-only keypath
+        let ro = PKp::new(KpType::new(|u: &User| Some(&u.age), |_: &mut User| None));
+        assert_eq!(ro.set_any(&mut user, Box::new(1u32)), Err(KpError::NotFound));
+
+        // Mapped keypaths are read-only, filtered ones write only when the predicate holds
+        let len = fields[0].map::<String, usize, _>(|s| s.len());
+        assert!(len.get_mut(&mut user).is_none());
+        let adult = fields[1].filter::<u32, _>(|age| *age >= 18);
+        assert!(adult.set_any(&mut user, Box::new(5u32)).is_ok());
+        assert_eq!(adult.set_any(&mut user, Box::new(6u32)), Err(KpError::NotFound));
+        assert_eq!(user.age, 5);
+    }
+
+    #[test]
+    fn test_pkp_write_adapters() {
+        struct User {
+            name: String,
+        }
+
+        let name = PKp::new(KpType::new(|u: &User| Some(&u.name), |u: &mut User| Some(&mut u.name)));
+
+        let mut boxed = Box::new(User { name: "a".to_string() });
+        name.for_box().set_any(&mut boxed, Box::new("b".to_string())).unwrap();
+        assert_eq!(boxed.name, "b");
+
+        let mut opt = Some(User { name: "a".to_string() });
+        *name.for_option().get_mut_as::<String>(&mut opt).unwrap() = "c".to_string();
+        assert_eq!(opt.unwrap().name, "c");
+        let mut none: Option<User> = None;
+        assert!(name.for_option().get_mut(&mut none).is_none());
+
+        let mut res: Result<User, ()> = Ok(User { name: "a".to_string() });
+        name.for_result::<()>().set_any(&mut res, Box::new("d".to_string())).unwrap();
+        assert_eq!(res.unwrap().name, "d");
+
+        // Arc / Rc: writable only while uniquely owned
+        let mut arc = Arc::new(User { name: "a".to_string() });
+        name.for_arc().set_any(&mut arc, Box::new("e".to_string())).unwrap();
+        assert_eq!(arc.name, "e");
+        let shared = arc.clone();
+        assert_eq!(
+            name.for_arc().set_any(&mut arc, Box::new("f".to_string())),
+            Err(KpError::NotFound)
+        );
+        drop(shared);
+
+        let mut rc = Rc::new(User { name: "a".to_string() });
+        name.for_rc().set_any(&mut rc, Box::new("g".to_string())).unwrap();
+        assert_eq!(rc.name, "g");
+    }
+
+    #[test]
+    fn test_akp_get_mut_and_set_any() {
+        struct User {
+            age: u32,
+        }
+        struct Product {
+            price: f64,
+        }
+
+        let mut user = User { age: 1 };
+        let mut product = Product { price: 2.0 };
+        let kps = vec![
+            AKp::new(KpType::new(|u: &User| Some(&u.age), |u: &mut User| Some(&mut u.age))),
+            AKp::new(KpType::new(
+                |p: &Product| Some(&p.price),
+                |p: &mut Product| Some(&mut p.price),
+            )),
+        ];
+
+        *kps[0].get_mut_as::<User, u32>(&mut user).unwrap().unwrap() = 2;
+        assert_eq!(user.age, 2);
+        assert!(kps[0].get_mut(&mut product as &mut dyn Any).is_none());
+
+        kps[1].set_any(&mut product, Box::new(3.5f64)).unwrap();
+        assert_eq!(product.price, 3.5);
+        assert_eq!(
+            kps[0].set_any(&mut product, Box::new(3u32)),
+            Err(KpError::RootTypeMismatch {
+                expected: TypeId::of::<User>(),
+                found: TypeId::of::<Product>(),
+            })
+        );
+        assert!(matches!(
+            kps[0].set_any(&mut user, Box::new(3i64)),
+            Err(KpError::ValueTypeMismatch { .. })
+        ));
+
+        // Write-side adapters
+        let mut boxed = Box::new(User { age: 0 });
+        kps[0].for_box::<User>().set_any(&mut boxed, Box::new(7u32)).unwrap();
+        assert_eq!(boxed.age, 7);
+        let mut opt = Some(User { age: 0 });
+        kps[0].for_option::<User>().set_any(&mut opt, Box::new(8u32)).unwrap();
+        assert_eq!(opt.unwrap().age, 8);
+        let mut arc = Arc::new(User { age: 0 });
+        kps[0].for_arc::<User>().set_any(&mut arc, Box::new(9u32)).unwrap();
+        assert_eq!(arc.age, 9);
+    }
+
+    #[test]
+    fn test_erased_then() {
+        struct Company {
+            ceo: Person,
+        }
+        struct Person {
+            name: String,
+            age: u32,
+        }
+
+        let mut company = Company {
+            ceo: Person {
+                name: "Ada".to_string(),
+                age: 36,
+            },
+        };
+
+        // Pieces as they might come out of a string-path registry
+        let ceo = AKp::new(KpType::new(|c: &Company| Some(&c.ceo), |c: &mut Company| Some(&mut c.ceo)));
+        let name = AKp::new(KpType::new(|p: &Person| Some(&p.name), |p: &mut Person| Some(&mut p.name)));
+        let age = AKp::new(KpType::new(|p: &Person| Some(&p.age), |p: &mut Person| Some(&mut p.age)));
+
+        let ceo_name = ceo.then(&name).unwrap();
+        assert_eq!(ceo_name.root_type_id(), TypeId::of::<Company>());
+        assert_eq!(ceo_name.value_type_id(), TypeId::of::<String>());
+        assert_eq!(
+            ceo_name.get_as::<Company, String>(&company),
+            Some(Some(&"Ada".to_string()))
+        );
+        ceo.then(&age).unwrap().set_any(&mut company, Box::new(37u32)).unwrap();
+        assert_eq!(company.ceo.age, 37);
+
+        assert_eq!(
+            name.then(&age).err(),
+            Some(TypeMismatch {
+                expected: TypeId::of::<Person>(),
+                found: TypeId::of::<String>(),
+            })
+        );
+
+        // Typed root, erased value
+        let ceo_p = PKp::new(KpType::new(|c: &Company| Some(&c.ceo), |c: &mut Company| Some(&mut c.ceo)));
+        let name_p = PKp::new(KpType::new(|p: &Person| Some(&p.name), |p: &mut Person| Some(&mut p.name)));
+        let ceo_name_p = ceo_p.then_pkp(&name_p).unwrap();
+        *ceo_name_p.get_mut_as::<String>(&mut company).unwrap() = "Grace".to_string();
+        assert_eq!(company.ceo.name, "Grace");
+        assert!(name_p.then_pkp(&name_p).is_err());
+    }
+
+    #[test]
+    fn test_lens_traits_across_families() {
+        use crate::lens::{Lens, ReadKp, WriteKp};
+        use crate::lock::{ArcMutexAccess, LockKp};
+        use std::sync::Mutex;
+
+        #[derive(Clone)]
+        struct Line {
+            price: f64,
+        }
+        enum Slot {
+            Filled(Line),
+            Empty,
+        }
+        struct Order {
+            slot: Slot,
+            shared: Arc<Mutex<Line>>,
+        }
+
+        fn sum_all(kps: &[&dyn ReadKp<Root = Order, Value = f64>], order: &Order) -> f64 {
+            kps.iter().filter_map(|kp| kp.read(order)).sum()
+        }
+        fn bump(kp: &impl WriteKp<Root = Order, Value = f64>, order: &mut Order) -> bool {
+            kp.modify(order, |p| *p += 1.0)
+        }
+
+        let mut order = Order {
+            slot: Slot::Filled(Line { price: 2.0 }),
+            shared: Arc::new(Mutex::new(Line { price: 10.0 })),
+        };
+
+        let slot: KpType<'static, Order, Slot> =
+            Kp::new(|o: &Order| Some(&o.slot), |o: &mut Order| Some(&mut o.slot));
+        let filled: EnumKpType<'static, Slot, Line> = variant_of(
+            |s: &Slot| match s {
+                Slot::Filled(l) => Some(l),
+                Slot::Empty => None,
+            },
+            |s: &mut Slot| match s {
+                Slot::Filled(l) => Some(l),
+                Slot::Empty => None,
+            },
+            Slot::Filled,
+        );
+        fn price() -> KpType<'static, Line, f64> {
+            Kp::new(|l: &Line| Some(&l.price), |l: &mut Line| Some(&mut l.price))
+        }
+        let shared: KpType<'static, Order, Arc<Mutex<Line>>> =
+            Kp::new(|o: &Order| Some(&o.shared), |o: &mut Order| Some(&mut o.shared));
+        let line: KpType<'static, Line, Line> = Kp::new(|l: &Line| Some(l), |l: &mut Line| Some(l));
+
+        let slot_price = slot.compose(filled).compose(price());
+        let shared_price = LockKp::new(shared, ArcMutexAccess::new(), line).compose(price());
+
+        assert_eq!(sum_all(&[&slot_price, &shared_price], &order), 12.0);
+        assert!(bump(&slot_price, &mut order));
+        assert!(bump(&shared_price, &mut order));
+        assert_eq!(shared_price.replace(&mut order, 0.5), Some(11.0));
+        assert_eq!(slot_price.read_cloned(&order), Some(3.0));
+
+        order.slot = Slot::Empty;
+        assert!(!bump(&slot_price, &mut order));
+        assert_eq!(sum_all(&[&slot_price, &shared_price], &order), 0.5);
+
+        // Erased keypaths take part too
+        let erased = PKp::new(KpType::new(|o: &Order| Some(&o.shared), |o: &mut Order| Some(&mut o.shared)));
+        assert!(ReadKp::read(&erased, &order).is_some());
+    }
+
+    #[test]
+    fn test_named_combinators_in_struct_fields() {
+        use crate::lens::{Filter, ForArc, Lens, Map, ReadKp, Then, WriteKp};
+
+        struct Account {
+            owner: String,
+            balance: i64,
+        }
+        struct Bank {
+            main: Account,
+        }
+
+        type MainKp = KpType<'static, Bank, Account>;
+        type BalanceKp = KpType<'static, Account, i64>;
+        struct Bindings {
+            balance: ForArc<Then<MainKp, BalanceKp>>,
+            overdrawn: Filter<Then<MainKp, BalanceKp>, fn(&i64) -> bool>,
+            owner_len: Map<Then<MainKp, KpType<'static, Account, String>>, fn(&String) -> usize>,
+        }
+
+        fn main_kp() -> MainKp {
+            Kp::new(|b: &Bank| Some(&b.main), |b: &mut Bank| Some(&mut b.main))
+        }
+        fn balance() -> BalanceKp {
+            Kp::new(|a: &Account| Some(&a.balance), |a: &mut Account| Some(&mut a.balance))
+        }
+        let owner: KpType<'static, Account, String> =
+            Kp::new(|a: &Account| Some(&a.owner), |a: &mut Account| Some(&mut a.owner));
+
+        let bindings = Bindings {
+            balance: main_kp().compose(balance()).in_arc(),
+            overdrawn: main_kp().compose(balance()).filtered(|b: &i64| *b < 0),
+            owner_len: main_kp().compose(owner).mapped(String::len as fn(&String) -> usize),
+        };
+
+        let mut bank = Arc::new(Bank {
+            main: Account {
+                owner: "Ada".into(),
+                balance: 5,
+            },
+        });
+        assert_eq!(bindings.balance.read(&bank), Some(&5));
+        assert_eq!(bindings.owner_len.get(&bank), Some(3));
+        assert_eq!(bindings.overdrawn.read(&bank), None);
+
+        // unique Arc: writable
+        *bindings.balance.write(&mut bank).unwrap() -= 10;
+        assert_eq!(bindings.overdrawn.read(&bank), Some(&-5));
+        assert!(bindings.overdrawn.modify(Arc::get_mut(&mut bank).unwrap(), |b| *b = 0));
+        assert_eq!(bindings.overdrawn.write(Arc::get_mut(&mut bank).unwrap()), None);
+
+        // shared Arc: read-only
+        let other = Arc::clone(&bank);
+        assert!(bindings.balance.write(&mut bank).is_none());
+        assert_eq!((bindings.balance >> KpType::<'static, i64, i64>::identity()).read(&other), Some(&0));
+    }
+
+    #[test]
+    fn test_map_lens_guard_and_computed() {
+        use crate::mapped::{Computed, MapLens};
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Dollars {
+            whole: i64,
+            cents: i64,
+        }
+        struct Invoice {
+            total_cents: i64,
+            tax_cents: i64,
+        }
+
+        let total: KpType<'static, Invoice, i64> = Kp::new(
+            |i: &Invoice| Some(&i.total_cents),
+            |i: &mut Invoice| Some(&mut i.total_cents),
+        );
+        let tax: KpType<'static, Invoice, i64> =
+            Kp::new(|i: &Invoice| Some(&i.tax_cents), |i: &mut Invoice| Some(&mut i.tax_cents));
+        let dollars = total.map_lens(
+            |c: &i64| Dollars {
+                whole: c / 100,
+                cents: c % 100,
+            },
+            |d: Dollars| d.whole * 100 + d.cents,
+        );
+
+        let mut invoice = Invoice {
+            total_cents: 1250,
+            tax_cents: 250,
+        };
+        assert_eq!(dollars.get(&invoice), Some(Dollars { whole: 12, cents: 50 }));
+
+        {
+            let mut guard = dollars.get_mut(&mut invoice).unwrap();
+            guard.whole += 1;
+            // nothing written until the guard goes away
+        }
+        assert_eq!(invoice.total_cents, 1350);
+
+        dollars.get_mut(&mut invoice).unwrap().set(Dollars { whole: 2, cents: 5 });
+        assert_eq!(invoice.total_cents, 205);
+
+        let mut guard = dollars.get_mut(&mut invoice).unwrap();
+        guard.whole = 99;
+        guard.discard();
+        assert_eq!(invoice.total_cents, 205);
+
+        // Any ReadKp + WriteKp can be mapped, not only Kp
+        let tax_dollars = MapLens::new(
+            &tax,
+            |c: &i64| Some(*c as f64 / 100.0),
+            |d: f64| (d * 100.0).round() as i64,
+        );
+        assert!(tax_dollars.set(&mut invoice, 3.1));
+        assert_eq!(invoice.tax_cents, 310);
+
+        let net = Computed::zip(dollars.source(), &tax, |t, x| t - x);
+        assert_eq!(net.get(&invoice), Some(-105));
+    }
+
     // ========== MAP TESTS ==========
 
     #[test]
@@ -42,7 +42,10 @@
 //! - No dangling pointers or use-after-free possible
 //! - Rust's ownership system enforces correctness
 
-use crate::Kp;
+use crate::{Kp, KpType};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
 use std::sync::{Arc, Mutex};
 
 /// Trait for types that can provide lock/unlock behavior
@@ -122,14 +125,14 @@ pub struct LockKp<
     G2,
     S2,
 > where
-    Root: std::borrow::Borrow<R>,
-    LockValue: std::borrow::Borrow<Lock>,
-    MidValue: std::borrow::Borrow<Mid>,
-    Value: std::borrow::Borrow<V>,
-    MutRoot: std::borrow::BorrowMut<R>,
-    MutLock: std::borrow::BorrowMut<Lock>,
-    MutMid: std::borrow::BorrowMut<Mid>,
-    MutValue: std::borrow::BorrowMut<V>,
+    Root: core::borrow::Borrow<R>,
+    LockValue: core::borrow::Borrow<Lock>,
+    MidValue: core::borrow::Borrow<Mid>,
+    Value: core::borrow::Borrow<V>,
+    MutRoot: core::borrow::BorrowMut<R>,
+    MutLock: core::borrow::BorrowMut<Lock>,
+    MutMid: core::borrow::BorrowMut<Mid>,
+    MutValue: core::borrow::BorrowMut<V>,
     G1: Fn(Root) -> Option<LockValue>,
     S1: Fn(MutRoot) -> Option<MutLock>,
     L: LockAccess<Lock, MidValue> + LockAccess<Lock, MutMid>,
@@ -185,14 +188,14 @@ impl<
         S2,
     >
 where
-    Root: std::borrow::Borrow<R>,
-    LockValue: std::borrow::Borrow<Lock>,
-    MidValue: std::borrow::Borrow<Mid>,
-    Value: std::borrow::Borrow<V>,
-    MutRoot: std::borrow::BorrowMut<R>,
-    MutLock: std::borrow::BorrowMut<Lock>,
-    MutMid: std::borrow::BorrowMut<Mid>,
-    MutValue: std::borrow::BorrowMut<V>,
+    Root: core::borrow::Borrow<R>,
+    LockValue: core::borrow::Borrow<Lock>,
+    MidValue: core::borrow::Borrow<Mid>,
+    Value: core::borrow::Borrow<V>,
+    MutRoot: core::borrow::BorrowMut<R>,
+    MutLock: core::borrow::BorrowMut<Lock>,
+    MutMid: core::borrow::BorrowMut<Mid>,
+    MutValue: core::borrow::BorrowMut<V>,
     G1: Fn(Root) -> Option<LockValue>,
     S1: Fn(MutRoot) -> Option<MutLock>,
     L: LockAccess<Lock, MidValue> + LockAccess<Lock, MutMid>,
@@ -208,6 +211,18 @@ where
         Self { prev, mid, next }
     }
 
+    /// Pair with an identity (see [crate::KpId]) built from the ids of `prev` and `next`: the
+    /// steps of `prev`, a `"lock"` step from `Lock` to `Mid`, then the steps of `next`.
+    #[cfg(feature = "alloc")]
+    pub fn with_ids(self, prev: &crate::KpId, next: &crate::KpId) -> crate::IdKp<Self>
+    where
+        Lock: 'static,
+        Mid: 'static,
+    {
+        let id = prev.then(&crate::KpId::field::<Lock, Mid>("lock")).then(next);
+        crate::IdKp::new(self, id)
+    }
+
     /// Get an immutable reference through the lock (sync, blocking).
     ///
     /// This will:
@@ -302,10 +317,11 @@ where
     /// - Locks like `Mutex` and `RwLock` provide interior mutability
     /// - We only need `&Lock`, not `&mut Lock`, to get mutable access to the inner data
     /// - This eliminates an unnecessary Arc reference count increment
+    #[cfg(feature = "alloc")]
     pub fn set<F>(&self, root: Root, updater: F) -> Result<(), String>
     where
         F: FnOnce(&mut V),
-        MutValue: std::borrow::BorrowMut<V>,
+        MutValue: core::borrow::BorrowMut<V>,
     {
         (self.prev.get)(root)
             .ok_or_else(|| "Failed to get lock container".to_string())
@@ -357,10 +373,10 @@ where
     where
         V: 'static,
         V2: 'static,
-        Value: std::borrow::Borrow<V>,
-        Value2: std::borrow::Borrow<V2>,
-        MutValue: std::borrow::BorrowMut<V>,
-        MutValue2: std::borrow::BorrowMut<V2>,
+        Value: core::borrow::Borrow<V>,
+        Value2: core::borrow::Borrow<V2>,
+        MutValue: core::borrow::BorrowMut<V>,
+        MutValue2: core::borrow::BorrowMut<V2>,
         G3: Fn(Value) -> Option<Value2> + 'static,
         S3: Fn(MutValue) -> Option<MutValue2> + 'static,
     {
@@ -460,14 +476,16 @@ where
     where
         V: 'static + Clone,
         V2: 'static,
-        Value: std::borrow::Borrow<V>,
-        LockValue2: std::borrow::Borrow<Lock2>,
-        MidValue2: std::borrow::Borrow<Mid2>,
-        Value2: std::borrow::Borrow<V2>,
-        MutValue: std::borrow::BorrowMut<V>,
-        MutLock2: std::borrow::BorrowMut<Lock2>,
-        MutMid2: std::borrow::BorrowMut<Mid2>,
-        MutValue2: std::borrow::BorrowMut<V2>,
+        Lock2: 'static,
+        Mid2: 'static,
+        Value: core::borrow::Borrow<V>,
+        LockValue2: core::borrow::Borrow<Lock2>,
+        MidValue2: core::borrow::Borrow<Mid2>,
+        Value2: core::borrow::Borrow<V2>,
+        MutValue: core::borrow::BorrowMut<V>,
+        MutLock2: core::borrow::BorrowMut<Lock2>,
+        MutMid2: core::borrow::BorrowMut<Mid2>,
+        MutValue2: core::borrow::BorrowMut<V2>,
         G2_1: Fn(Value) -> Option<LockValue2> + 'static,
         S2_1: Fn(MutValue) -> Option<MutLock2> + 'static,
         L2: LockAccess<Lock2, MidValue2> + LockAccess<Lock2, MutMid2> + Clone + 'static, // SHALLOW: PhantomData clone
@@ -524,6 +542,7 @@ where
 
     /// Chain with an async keypath. Use `.get(&root).await` on the returned keypath.
     /// When `AsyncKp::Value` is a reference type (`&T` / `&mut T`), `V2` is inferred as `T` via [crate::KeyPathValueTarget].
+    #[cfg(feature = "std")]
     pub fn then_async<AsyncKp>(
         self,
         async_kp: AsyncKp,
@@ -542,18 +561,18 @@ where
     >
     where
         V: 'static + Clone,
-        Value: std::borrow::Borrow<V>,
-        MutValue: std::borrow::BorrowMut<V>,
+        Value: core::borrow::Borrow<V>,
+        MutValue: core::borrow::BorrowMut<V>,
         AsyncKp: crate::async_lock::AsyncKeyPathLike<Value, MutValue>,
         AsyncKp::Value: crate::KeyPathValueTarget
-            + std::borrow::Borrow<<AsyncKp::Value as crate::KeyPathValueTarget>::Target>,
-        AsyncKp::MutValue: std::borrow::BorrowMut<<AsyncKp::Value as crate::KeyPathValueTarget>::Target>,
+            + core::borrow::Borrow<<AsyncKp::Value as crate::KeyPathValueTarget>::Target>,
+        AsyncKp::MutValue: core::borrow::BorrowMut<<AsyncKp::Value as crate::KeyPathValueTarget>::Target>,
         <AsyncKp::Value as crate::KeyPathValueTarget>::Target: 'static,
     {
         crate::async_lock::KpThenAsyncKeyPath {
             first: self,
             second: async_kp,
-            _p: std::marker::PhantomData,
+            _p: core::marker::PhantomData,
         }
     }
 }
@@ -562,14 +581,21 @@ where
 // KpThenLockKp: Kp .then_lock(LockKp) — sync keypath then sync lock
 // ============================================================================
 
+// Type marker of a [KpThenLockKp]; it holds no R, V or root / value data.
+#[cfg(feature = "std")]
+type ThenLockMarker<R, V, V2, Root, Value, Value2, MutRoot, MutValue, MutValue2> =
+    core::marker::PhantomData<(R, V, V2, Root, Value, Value2, MutRoot, MutValue, MutValue2)>;
+
 /// Keypath that chains a [crate::Kp] with a [LockKp]. Use [crate::Kp::then_lock] to create.
+#[cfg(feature = "std")]
 #[derive(Clone)]
 pub struct KpThenLockKp<R, V, V2, Root, Value, Value2, MutRoot, MutValue, MutValue2, First, Second> {
     pub(crate) first: First,
     pub(crate) second: Second,
-    pub(crate) _p: std::marker::PhantomData<(R, V, V2, Root, Value, Value2, MutRoot, MutValue, MutValue2)>,
+    pub(crate) _p: ThenLockMarker<R, V, V2, Root, Value, Value2, MutRoot, MutValue, MutValue2>,
 }
 
+#[cfg(feature = "std")]
 impl<R, V, V2, Root, Value, Value2, MutRoot, MutValue, MutValue2, First, Second>
     KpThenLockKp<R, V, V2, Root, Value, Value2, MutRoot, MutValue, MutValue2, First, Second>
 where
@@ -606,19 +632,22 @@ where
 ///
 /// The `Clone` impl is required for the `then_lock()` method to work, but it's
 /// completely free (compiled away to nothing).
+#[cfg(feature = "std")]
 #[derive(Clone)] // ZERO-COST: Only clones PhantomData (zero-sized type)
 pub struct ArcMutexAccess<T> {
-    _phantom: std::marker::PhantomData<T>, // Zero-sized, no runtime cost
+    _phantom: core::marker::PhantomData<T>, // Zero-sized, no runtime cost
 }
 
+#[cfg(feature = "std")]
 impl<T> ArcMutexAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl<T> Default for ArcMutexAccess<T> {
     fn default() -> Self {
         Self::new()
@@ -626,6 +655,7 @@ impl<T> Default for ArcMutexAccess<T> {
 }
 
 // Implementation for immutable access (returns reference to locked value)
+#[cfg(feature = "std")]
 impl<'a, T: 'static> LockAccess<Arc<Mutex<T>>, &'a T> for ArcMutexAccess<T> {
     #[inline]
     fn lock_read(&self, lock: &Arc<Mutex<T>>) -> Option<&'a T> {
@@ -648,6 +678,7 @@ impl<'a, T: 'static> LockAccess<Arc<Mutex<T>>, &'a T> for ArcMutexAccess<T> {
 }
 
 // Implementation for mutable access
+#[cfg(feature = "std")]
 impl<'a, T: 'static> LockAccess<Arc<Mutex<T>>, &'a mut T> for ArcMutexAccess<T> {
     #[inline]
     fn lock_read(&self, lock: &Arc<Mutex<T>>) -> Option<&'a mut T> {
@@ -690,19 +721,22 @@ impl<'a, T: 'static> LockAccess<Arc<Mutex<T>>, &'a mut T> for ArcMutexAccess<T>
 /// - **Slightly more overhead**: RwLock has more complex internal state
 /// - **Use when**: Many readers, few writers
 /// - **Avoid when**: Frequent writes or simple cases (use Mutex)
+#[cfg(feature = "std")]
 #[derive(Clone)] // ZERO-COST: Only clones PhantomData (zero-sized type)
 pub struct ArcRwLockAccess<T> {
-    _phantom: std::marker::PhantomData<T>, // Zero-sized, no runtime cost
+    _phantom: core::marker::PhantomData<T>, // Zero-sized, no runtime cost
 }
 
+#[cfg(feature = "std")]
 impl<T> ArcRwLockAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl<T> Default for ArcRwLockAccess<T> {
     fn default() -> Self {
         Self::new()
@@ -710,6 +744,7 @@ impl<T> Default for ArcRwLockAccess<T> {
 }
 
 // Implementation for immutable access (read lock)
+#[cfg(feature = "std")]
 impl<'a, T: 'static> LockAccess<Arc<std::sync::RwLock<T>>, &'a T> for ArcRwLockAccess<T> {
     fn lock_read(&self, lock: &Arc<std::sync::RwLock<T>>) -> Option<&'a T> {
         // Acquire read lock - allows multiple concurrent readers
@@ -729,6 +764,7 @@ impl<'a, T: 'static> LockAccess<Arc<std::sync::RwLock<T>>, &'a T> for ArcRwLockA
 }
 
 // Implementation for mutable access (write lock)
+#[cfg(feature = "std")]
 impl<'a, T: 'static> LockAccess<Arc<std::sync::RwLock<T>>, &'a mut T> for ArcRwLockAccess<T> {
     fn lock_read(&self, lock: &Arc<std::sync::RwLock<T>>) -> Option<&'a mut T> {
         // For mutable access, we need write lock (exclusive)
@@ -765,19 +801,22 @@ impl<'a, T: 'static> LockAccess<Arc<std::sync::RwLock<T>>, &'a mut T> for ArcRwL
 ///
 /// Since we're working with `&Mutex<T>`, this requires the Mutex to be
 /// stored somewhere with a stable address (e.g., in a struct, Box, or static).
+#[cfg(feature = "std")]
 #[derive(Clone)]
 pub struct StdMutexAccess<T> {
-    _phantom: std::marker::PhantomData<T>,
+    _phantom: core::marker::PhantomData<T>,
 }
 
+#[cfg(feature = "std")]
 impl<T> StdMutexAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl<T> Default for StdMutexAccess<T> {
     fn default() -> Self {
         Self::new()
@@ -785,6 +824,7 @@ impl<T> Default for StdMutexAccess<T> {
 }
 
 // Implementation for immutable access
+#[cfg(feature = "std")]
 impl<'a, T: 'static> LockAccess<Mutex<T>, &'a T> for StdMutexAccess<T> {
     fn lock_read(&self, lock: &Mutex<T>) -> Option<&'a T> {
         lock.lock().ok().map(|guard| {
@@ -802,6 +842,7 @@ impl<'a, T: 'static> LockAccess<Mutex<T>, &'a T> for StdMutexAccess<T> {
 }
 
 // Implementation for mutable access
+#[cfg(feature = "std")]
 impl<'a, T: 'static> LockAccess<Mutex<T>, &'a mut T> for StdMutexAccess<T> {
     fn lock_read(&self, lock: &Mutex<T>) -> Option<&'a mut T> {
         lock.lock().ok().map(|mut guard| {
@@ -833,19 +874,22 @@ impl<'a, T: 'static> LockAccess<Mutex<T>, &'a mut T> for StdMutexAccess<T> {
 /// # When to Use
 ///
 /// Use this when you have a direct reference to an RwLock, not wrapped in Arc.
+#[cfg(feature = "std")]
 #[derive(Clone)]
 pub struct StdRwLockAccess<T> {
-    _phantom: std::marker::PhantomData<T>,
+    _phantom: core::marker::PhantomData<T>,
 }
 
+#[cfg(feature = "std")]
 impl<T> StdRwLockAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl<T> Default for StdRwLockAccess<T> {
     fn default() -> Self {
         Self::new()
@@ -853,6 +897,7 @@ impl<T> Default for StdRwLockAccess<T> {
 }
 
 // Implementation for immutable access (read lock)
+#[cfg(feature = "std")]
 impl<'a, T: 'static> LockAccess<std::sync::RwLock<T>, &'a T> for StdRwLockAccess<T> {
     fn lock_read(&self, lock: &std::sync::RwLock<T>) -> Option<&'a T> {
         lock.read().ok().map(|guard| {
@@ -870,6 +915,7 @@ impl<'a, T: 'static> LockAccess<std::sync::RwLock<T>, &'a T> for StdRwLockAccess
 }
 
 // Implementation for mutable access (write lock)
+#[cfg(feature = "std")]
 impl<'a, T: 'static> LockAccess<std::sync::RwLock<T>, &'a mut T> for StdRwLockAccess<T> {
     fn lock_read(&self, lock: &std::sync::RwLock<T>) -> Option<&'a mut T> {
         lock.write().ok().map(|mut guard| {
@@ -919,14 +965,14 @@ impl<'a, T: 'static> LockAccess<std::sync::RwLock<T>, &'a mut T> for StdRwLockAc
 /// - When you don't need lock poisoning semantics
 #[derive(Clone)] // ZERO-COST: Only clones PhantomData (zero-sized type)
 pub struct ParkingLotMutexAccess<T> {
-    _phantom: std::marker::PhantomData<T>,
+    _phantom: core::marker::PhantomData<T>,
 }
 
 #[cfg(feature = "parking_lot")]
 impl<T> ParkingLotMutexAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
@@ -1014,14 +1060,14 @@ impl<'a, T: 'static> LockAccess<Arc<parking_lot::Mutex<T>>, &'a mut T>
 /// - When you don't need lock poisoning semantics
 #[derive(Clone)] // ZERO-COST: Only clones PhantomData (zero-sized type)
 pub struct ParkingLotRwLockAccess<T> {
-    _phantom: std::marker::PhantomData<T>,
+    _phantom: core::marker::PhantomData<T>,
 }
 
 #[cfg(feature = "parking_lot")]
 impl<T> ParkingLotRwLockAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
@@ -1088,14 +1134,14 @@ impl<'a, T: 'static> LockAccess<Arc<parking_lot::RwLock<T>>, &'a mut T>
 /// not wrapped in Arc.
 #[derive(Clone)]
 pub struct DirectParkingLotMutexAccess<T> {
-    _phantom: std::marker::PhantomData<T>,
+    _phantom: core::marker::PhantomData<T>,
 }
 
 #[cfg(feature = "parking_lot")]
 impl<T> DirectParkingLotMutexAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
@@ -1159,14 +1205,14 @@ impl<'a, T: 'static> LockAccess<parking_lot::Mutex<T>, &'a mut T>
 /// not wrapped in Arc.
 #[derive(Clone)]
 pub struct DirectParkingLotRwLockAccess<T> {
-    _phantom: std::marker::PhantomData<T>,
+    _phantom: core::marker::PhantomData<T>,
 }
 
 #[cfg(feature = "parking_lot")]
 impl<T> DirectParkingLotRwLockAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
@@ -1210,6 +1256,248 @@ impl<'a, T: 'static> LockAccess<parking_lot::RwLock<T>, &'a mut T>
     }
 }
 
+// ============================================================================
+// Closure-scoped Lock Access (ScopedLockKp)
+// ============================================================================
+
+/// Lock access that lends the locked value to a closure and releases the lock when it returns.
+///
+/// For locks whose guards borrow the lock (`spin`, `critical_section`), where [LockAccess] could
+/// only return a reference that outlives the guard. Used by [ScopedLockKp].
+pub trait ScopedLockAccess<Lock, Inner> {
+    /// Run `f` with shared access to the inner value; `None` if it cannot be borrowed.
+    fn with_read<Out>(&self, lock: &Lock, f: impl FnOnce(&Inner) -> Out) -> Option<Out>;
+
+    /// Run `f` with exclusive access to the inner value; `None` if it cannot be borrowed.
+    fn with_write<Out>(&self, lock: &Lock, f: impl FnOnce(&mut Inner) -> Out) -> Option<Out>;
+}
+
+/// Keypath from `R` through a lock (opened with a [ScopedLockAccess]) to `V`.
+///
+/// The lock is held only while the callback of [ScopedLockKp::with] / [ScopedLockKp::with_mut]
+/// runs, so no reference into the locked value outlives it.
+///
+/// # Example
+/// ```
+/// use core::cell::RefCell;
+/// use rust_key_paths::{KpType, ScopedLockAccess, ScopedLockKp};
+///
+/// struct RefCellAccess;
+///
+/// impl<T> ScopedLockAccess<RefCell<T>, T> for RefCellAccess {
+///     fn with_read<Out>(&self, lock: &RefCell<T>, f: impl FnOnce(&T) -> Out) -> Option<Out> {
+///         lock.try_borrow().ok().map(|value| f(&value))
+///     }
+///
+///     fn with_write<Out>(&self, lock: &RefCell<T>, f: impl FnOnce(&mut T) -> Out) -> Option<Out> {
+///         lock.try_borrow_mut().ok().map(|mut value| f(&mut value))
+///     }
+/// }
+///
+/// struct Counter {
+///     hits: RefCell<u32>,
+/// }
+///
+/// let hits = ScopedLockKp::new(
+///     KpType::new(|c: &Counter| Some(&c.hits), |c: &mut Counter| Some(&mut c.hits)),
+///     RefCellAccess,
+///     KpType::new(|n: &u32| Some(n), |n: &mut u32| Some(n)),
+/// );
+/// let counter = Counter { hits: RefCell::new(1) };
+/// hits.with_mut(&counter, |n| *n += 1);
+/// assert_eq!(hits.with(&counter, |n| *n), Some(2));
+/// ```
+pub struct ScopedLockKp<R, Lock, Mid, V, L> {
+    prev: for<'b> fn(&'b R) -> Option<&'b Lock>,
+    mid: L,
+    next_get: for<'b> fn(&'b Mid) -> Option<&'b V>,
+    next_set: for<'b> fn(&'b mut Mid) -> Option<&'b mut V>,
+}
+
+// Function pointers and the access only; never touches R, Lock, Mid or V.
+impl<R, Lock, Mid, V, L: Clone> Clone for ScopedLockKp<R, Lock, Mid, V, L> {
+    fn clone(&self) -> Self {
+        Self {
+            prev: self.prev,
+            mid: self.mid.clone(),
+            next_get: self.next_get,
+            next_set: self.next_set,
+        }
+    }
+}
+
+impl<R, Lock, Mid, V, L> ScopedLockKp<R, Lock, Mid, V, L>
+where
+    L: ScopedLockAccess<Lock, Mid>,
+{
+    /// `prev` reaches the lock (locks are opened through `&Lock`, so only its getter is used),
+    /// `mid` opens it and `next` goes from the locked value to `V`.
+    pub fn new<'a>(prev: KpType<'a, R, Lock>, mid: L, next: KpType<'a, Mid, V>) -> Self {
+        Self {
+            prev: prev.get,
+            mid,
+            next_get: next.get,
+            next_set: next.set,
+        }
+    }
+
+    /// Run `f` on the value with the lock held for reading; `None` if a keypath finds nothing
+    /// or the lock cannot be taken.
+    pub fn with<Out>(&self, root: &R, f: impl FnOnce(&V) -> Out) -> Option<Out> {
+        let lock = (self.prev)(root)?;
+        self.mid
+            .with_read(lock, |mid| (self.next_get)(mid).map(f))
+            .flatten()
+    }
+
+    /// Run `f` on the value with the lock held for writing; `None` if a keypath finds nothing
+    /// or the lock cannot be taken.
+    pub fn with_mut<Out>(&self, root: &R, f: impl FnOnce(&mut V) -> Out) -> Option<Out> {
+        let lock = (self.prev)(root)?;
+        self.mid
+            .with_write(lock, |mid| (self.next_set)(mid).map(f))
+            .flatten()
+    }
+}
+
+// ============================================================================
+// Spin Lock Access Implementations (no_std)
+// ============================================================================
+
+#[cfg(feature = "spin")]
+/// [ScopedLockAccess] for spin::Mutex<T>
+///
+/// # When to Use
+///
+/// Busy-waiting mutex for `no_std` targets without an OS scheduler. Usually held in a
+/// `static` or a struct field directly, so there is no Arc variant.
+#[derive(Clone)]
+pub struct SpinMutexAccess<T> {
+    _phantom: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "spin")]
+impl<T> SpinMutexAccess<T> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "spin")]
+impl<T> Default for SpinMutexAccess<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "spin")]
+impl<T> ScopedLockAccess<spin::Mutex<T>, T> for SpinMutexAccess<T> {
+    fn with_read<Out>(&self, lock: &spin::Mutex<T>, f: impl FnOnce(&T) -> Out) -> Option<Out> {
+        Some(f(&lock.lock()))
+    }
+
+    fn with_write<Out>(&self, lock: &spin::Mutex<T>, f: impl FnOnce(&mut T) -> Out) -> Option<Out> {
+        Some(f(&mut lock.lock()))
+    }
+}
+
+#[cfg(feature = "spin")]
+/// [ScopedLockAccess] for spin::RwLock<T>
+///
+/// Reads take a shared `read()` guard, writes an exclusive `write()` guard.
+#[derive(Clone)]
+pub struct SpinRwLockAccess<T> {
+    _phantom: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "spin")]
+impl<T> SpinRwLockAccess<T> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "spin")]
+impl<T> Default for SpinRwLockAccess<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "spin")]
+impl<T> ScopedLockAccess<spin::RwLock<T>, T> for SpinRwLockAccess<T> {
+    fn with_read<Out>(&self, lock: &spin::RwLock<T>, f: impl FnOnce(&T) -> Out) -> Option<Out> {
+        Some(f(&lock.read()))
+    }
+
+    fn with_write<Out>(&self, lock: &spin::RwLock<T>, f: impl FnOnce(&mut T) -> Out) -> Option<Out> {
+        Some(f(&mut lock.write()))
+    }
+}
+
+// ============================================================================
+// Critical Section Access Implementation (no_std)
+// ============================================================================
+
+#[cfg(feature = "critical-section")]
+/// [ScopedLockAccess] for critical_section::Mutex<RefCell<T>>
+///
+/// # Critical Section Semantics
+///
+/// The callback runs inside `critical_section::with` (interrupts disabled on single-core
+/// targets) with the `RefCell` borrowed, so keep it short. A nested access to the same cell
+/// from inside the callback returns `None`.
+#[derive(Clone)]
+pub struct CriticalSectionAccess<T> {
+    _phantom: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "critical-section")]
+impl<T> CriticalSectionAccess<T> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl<T> Default for CriticalSectionAccess<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl<T> ScopedLockAccess<critical_section::Mutex<core::cell::RefCell<T>>, T>
+    for CriticalSectionAccess<T>
+{
+    fn with_read<Out>(
+        &self,
+        lock: &critical_section::Mutex<core::cell::RefCell<T>>,
+        f: impl FnOnce(&T) -> Out,
+    ) -> Option<Out> {
+        critical_section::with(|cs| lock.borrow(cs).try_borrow().ok().map(|value| f(&value)))
+    }
+
+    fn with_write<Out>(
+        &self,
+        lock: &critical_section::Mutex<core::cell::RefCell<T>>,
+        f: impl FnOnce(&mut T) -> Out,
+    ) -> Option<Out> {
+        critical_section::with(|cs| {
+            lock.borrow(cs)
+                .try_borrow_mut()
+                .ok()
+                .map(|mut value| f(&mut value))
+        })
+    }
+}
+
 // ============================================================================
 // RefCell Access Implementation (Single-threaded)
 // ============================================================================
@@ -1262,19 +1550,22 @@ impl<'a, T: 'static> LockAccess<parking_lot::RwLock<T>, &'a mut T>
 ///     Kp::new(|i: &Inner| Some(&i.value), |i: &mut Inner| Some(&mut i.value)),
 /// );
 /// ```
+#[cfg(feature = "alloc")]
 #[derive(Clone)] // ZERO-COST: Only clones PhantomData (zero-sized type)
 pub struct RcRefCellAccess<T> {
-    _phantom: std::marker::PhantomData<T>, // Zero-sized, no runtime cost
+    _phantom: core::marker::PhantomData<T>, // Zero-sized, no runtime cost
 }
 
+#[cfg(feature = "alloc")]
 impl<T> RcRefCellAccess<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: std::marker::PhantomData,
+            _phantom: core::marker::PhantomData,
         }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> Default for RcRefCellAccess<T> {
     fn default() -> Self {
         Self::new()
@@ -1282,8 +1573,9 @@ impl<T> Default for RcRefCellAccess<T> {
 }
 
 // Implementation for immutable access (borrow)
-impl<'a, T: 'static> LockAccess<std::rc::Rc<std::cell::RefCell<T>>, &'a T> for RcRefCellAccess<T> {
-    fn lock_read(&self, lock: &std::rc::Rc<std::cell::RefCell<T>>) -> Option<&'a T> {
+#[cfg(feature = "alloc")]
+impl<'a, T: 'static> LockAccess<alloc::rc::Rc<core::cell::RefCell<T>>, &'a T> for RcRefCellAccess<T> {
+    fn lock_read(&self, lock: &alloc::rc::Rc<core::cell::RefCell<T>>) -> Option<&'a T> {
         // Acquire immutable borrow - allows multiple concurrent readers
         // SHALLOW CLONE: Only Rc refcount is incremented when accessing lock
         // Note: borrow() panics on borrow violation (not thread-safe, runtime check)
@@ -1292,7 +1584,7 @@ impl<'a, T: 'static> LockAccess<std::rc::Rc<std::cell::RefCell<T>>, &'a T> for R
         unsafe { Some(&*ptr) }
     }
 
-    fn lock_write(&self, lock: &std::rc::Rc<std::cell::RefCell<T>>) -> Option<&'a T> {
+    fn lock_write(&self, lock: &alloc::rc::Rc<core::cell::RefCell<T>>) -> Option<&'a T> {
         // For immutable access, we use borrow (not borrow_mut)
         let guard = lock.borrow();
         let ptr = &*guard as *const T;
@@ -1301,10 +1593,11 @@ impl<'a, T: 'static> LockAccess<std::rc::Rc<std::cell::RefCell<T>>, &'a T> for R
 }
 
 // Implementation for mutable access (borrow_mut)
-impl<'a, T: 'static> LockAccess<std::rc::Rc<std::cell::RefCell<T>>, &'a mut T>
+#[cfg(feature = "alloc")]
+impl<'a, T: 'static> LockAccess<alloc::rc::Rc<core::cell::RefCell<T>>, &'a mut T>
     for RcRefCellAccess<T>
 {
-    fn lock_read(&self, lock: &std::rc::Rc<std::cell::RefCell<T>>) -> Option<&'a mut T> {
+    fn lock_read(&self, lock: &alloc::rc::Rc<core::cell::RefCell<T>>) -> Option<&'a mut T> {
         // For mutable access, we need exclusive borrow
         // Note: borrow_mut() panics on borrow violation (not thread-safe, runtime check)
         let mut guard = lock.borrow_mut();
@@ -1312,7 +1605,7 @@ impl<'a, T: 'static> LockAccess<std::rc::Rc<std::cell::RefCell<T>>, &'a mut T>
         unsafe { Some(&mut *ptr) }
     }
 
-    fn lock_write(&self, lock: &std::rc::Rc<std::cell::RefCell<T>>) -> Option<&'a mut T> {
+    fn lock_write(&self, lock: &alloc::rc::Rc<core::cell::RefCell<T>>) -> Option<&'a mut T> {
         // Acquire mutable borrow - exclusive access
         let mut guard = lock.borrow_mut();
         let ptr = &mut *guard as *mut T;
@@ -1324,6 +1617,7 @@ impl<'a, T: 'static> LockAccess<std::rc::Rc<std::cell::RefCell<T>>, &'a mut T>
 // Helper Functions
 // ============================================================================
 
+#[cfg(feature = "std")]
 /// Type alias for LockKp over Arc<std::sync::Mutex<T>>. Use with derive macro's `_lock()` methods.
 pub type LockKpArcMutexFor<Root, Lock, Inner> = LockKp<
     Root,
@@ -1345,6 +1639,7 @@ pub type LockKpArcMutexFor<Root, Lock, Inner> = LockKp<
     for<'b> fn(&'b mut Inner) -> Option<&'b mut Inner>,
 >;
 
+#[cfg(feature = "std")]
 /// Type alias for LockKp over Arc<std::sync::RwLock<T>>. Use with derive macro's `_lock()` methods.
 pub type LockKpArcRwLockFor<Root, Lock, Inner> = LockKp<
     Root,
@@ -1410,6 +1705,29 @@ pub type LockKpParkingLotRwLockFor<Root, Lock, Inner> = LockKp<
     for<'b> fn(&'b mut Inner) -> Option<&'b mut Inner>,
 >;
 
+/// Type alias for LockKp over any lock with a user-provided [LockAccess] impl.
+/// Use with the derive macro's `_lock()` methods for `#[kp(lock = "Access")]` fields.
+pub type LockKpFor<Root, Lock, Inner, Access> = LockKp<
+    Root,
+    Lock,
+    Inner,
+    Inner,
+    &'static Root,
+    &'static Lock,
+    &'static Inner,
+    &'static Inner,
+    &'static mut Root,
+    &'static mut Lock,
+    &'static mut Inner,
+    &'static mut Inner,
+    for<'b> fn(&'b Root) -> Option<&'b Lock>,
+    for<'b> fn(&'b mut Root) -> Option<&'b mut Lock>,
+    Access,
+    for<'b> fn(&'b Inner) -> Option<&'b Inner>,
+    for<'b> fn(&'b mut Inner) -> Option<&'b mut Inner>,
+>;
+
+#[cfg(feature = "std")]
 /// Type alias for common LockKp usage with Arc<Mutex<T>>
 pub type LockKpType<'a, R, Mid, V> = LockKp<
     R,
@@ -0,0 +1,273 @@
+//! Mapped keypaths that still write: [MapLens] converts the value on the way out and back on
+//! the way in, and [Computed] derives a read-only value from several source keypaths.
+//!
+//! [crate::Kp::map] only reads. [crate::Kp::map_lens] takes a `to` and a `from` conversion;
+//! [MapLens::get_mut] hands out a [MapLensGuard] holding the converted value, which is
+//! converted back and stored when the guard drops (or at once with [MapLensGuard::set]).
+
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+use crate::lens::{ReadKp, WriteKp};
+use crate::Kp;
+
+type ComputeFn<'a, R, W> = Rc<dyn Fn(&R) -> Option<W> + 'a>;
+
+/// Writable projection of a keypath through a pair of conversions.
+///
+/// Built with [crate::Kp::map_lens] (total `to`) or [crate::Kp::try_map_lens] (`to` may fail,
+/// e.g. parsing a `String` field); [MapLens::new] accepts any [ReadKp] + [WriteKp].
+///
+/// # Example
+/// ```
+/// use rust_key_paths::KpType;
+///
+/// struct Sensor { celsius: f64 }
+///
+/// let celsius: KpType<'static, Sensor, f64> =
+///     KpType::new(|s: &Sensor| Some(&s.celsius), |s: &mut Sensor| Some(&mut s.celsius));
+/// let fahrenheit = celsius.map_lens(|c: &f64| c * 9.0 / 5.0 + 32.0, |f: f64| (f - 32.0) * 5.0 / 9.0);
+///
+/// let mut sensor = Sensor { celsius: 100.0 };
+/// assert_eq!(fahrenheit.get(&sensor), Some(212.0));
+///
+/// // Written back as celsius when the guard drops
+/// *fahrenheit.get_mut(&mut sensor).unwrap() -= 180.0;
+/// assert_eq!(sensor.celsius, 0.0);
+///
+/// fahrenheit.set(&mut sensor, 14.0);
+/// assert_eq!(sensor.celsius, -10.0);
+/// ```
+#[derive(Clone)]
+pub struct MapLens<K, To, From> {
+    kp: K,
+    to: To,
+    from: From,
+}
+
+/// Converted value borrowed from a [MapLens]; converted back into the source on drop.
+pub struct MapLensGuard<'r, V, W, From>
+where
+    From: Fn(W) -> V,
+{
+    slot: &'r mut V,
+    value: Option<W>,
+    from: &'r From,
+}
+
+impl<K, To, From> MapLens<K, To, From> {
+    /// `to` converts the source value (returning `None` when it cannot); `from` converts back.
+    pub fn new(kp: K, to: To, from: From) -> Self {
+        Self { kp, to, from }
+    }
+
+    /// The source keypath.
+    pub fn source(&self) -> &K {
+        &self.kp
+    }
+}
+
+impl<K, W, To, From> MapLens<K, To, From>
+where
+    K: ReadKp + WriteKp,
+    K::Value: Sized,
+    To: Fn(&K::Value) -> Option<W>,
+    From: Fn(W) -> K::Value,
+{
+    /// The converted value.
+    pub fn get(&self, root: &K::Root) -> Option<W> {
+        self.kp.read(root).and_then(|v| (self.to)(v))
+    }
+
+    /// The converted value behind a guard that writes it back when dropped.
+    pub fn get_mut<'r>(&'r self, root: &'r mut K::Root) -> Option<MapLensGuard<'r, K::Value, W, From>> {
+        let slot = self.kp.write(root)?;
+        let value = (self.to)(slot)?;
+        Some(MapLensGuard {
+            slot,
+            value: Some(value),
+            from: &self.from,
+        })
+    }
+
+    /// Convert `value` back and store it. Returns `false` when the path does not resolve.
+    pub fn set(&self, root: &mut K::Root, value: W) -> bool {
+        match self.kp.write(root) {
+            Some(slot) => {
+                *slot = (self.from)(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'r, V, W, From> MapLensGuard<'r, V, W, From>
+where
+    From: Fn(W) -> V,
+{
+    /// Replace the converted value and write it back now.
+    pub fn set(mut self, value: W) {
+        self.value = Some(value);
+    }
+
+    /// Drop the guard without writing anything back.
+    pub fn discard(mut self) {
+        self.value = None;
+    }
+}
+
+impl<'r, V, W, From> Deref for MapLensGuard<'r, V, W, From>
+where
+    From: Fn(W) -> V,
+{
+    type Target = W;
+
+    fn deref(&self) -> &W {
+        self.value.as_ref().expect("MapLensGuard value is present until drop")
+    }
+}
+
+impl<'r, V, W, From> DerefMut for MapLensGuard<'r, V, W, From>
+where
+    From: Fn(W) -> V,
+{
+    fn deref_mut(&mut self) -> &mut W {
+        self.value.as_mut().expect("MapLensGuard value is present until drop")
+    }
+}
+
+impl<'r, V, W, From> Drop for MapLensGuard<'r, V, W, From>
+where
+    From: Fn(W) -> V,
+{
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            *self.slot = (self.from)(value);
+        }
+    }
+}
+
+impl<'x, R, V, G, S> Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+{
+    /// Writable projection through `to` / `from` (see [MapLens]).
+    pub fn map_lens<W, To, From>(
+        self,
+        to: To,
+        from: From,
+    ) -> MapLens<Self, impl Fn(&V) -> Option<W>, From>
+    where
+        To: Fn(&V) -> W,
+        From: Fn(W) -> V,
+    {
+        MapLens::new(self, move |v: &V| Some(to(v)), from)
+    }
+
+    /// Like [Kp::map_lens], for conversions that can fail: where `to` returns `None` the mapped
+    /// keypath does not resolve.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_key_paths::KpType;
+    ///
+    /// struct Config { port: String }
+    ///
+    /// let port: KpType<'static, Config, String> =
+    ///     KpType::new(|c: &Config| Some(&c.port), |c: &mut Config| Some(&mut c.port));
+    /// let port_num = port.try_map_lens(|s: &String| s.parse::<u16>().ok(), |n: u16| n.to_string());
+    ///
+    /// let mut config = Config { port: "8080".to_string() };
+    /// *port_num.get_mut(&mut config).unwrap() += 1;
+    /// assert_eq!(config.port, "8081");
+    ///
+    /// config.port = "http".to_string();
+    /// assert_eq!(port_num.get(&config), None);
+    /// ```
+    pub fn try_map_lens<W, To, From>(self, to: To, from: From) -> MapLens<Self, To, From>
+    where
+        To: Fn(&V) -> Option<W>,
+        From: Fn(W) -> V,
+    {
+        MapLens::new(self, to, from)
+    }
+}
+
+/// Read-only value computed from one or more source keypaths of the same root.
+///
+/// # Example
+/// ```
+/// use rust_key_paths::{Computed, KpType};
+///
+/// struct Person { first: String, last: String, nickname: Option<String> }
+///
+/// let first: KpType<'static, Person, String> =
+///     KpType::new(|p: &Person| Some(&p.first), |p: &mut Person| Some(&mut p.first));
+/// let last: KpType<'static, Person, String> =
+///     KpType::new(|p: &Person| Some(&p.last), |p: &mut Person| Some(&mut p.last));
+///
+/// let full_name = Computed::zip(first, last, |f, l| format!("{} {}", f, l));
+/// let p = Person { first: "Ada".into(), last: "Lovelace".into(), nickname: None };
+/// assert_eq!(full_name.get(&p).as_deref(), Some("Ada Lovelace"));
+///
+/// let display = Computed::new(|p: &Person| p.nickname.clone().or_else(|| full_name.get(p)));
+/// assert_eq!(display.get(&p).as_deref(), Some("Ada Lovelace"));
+/// ```
+pub struct Computed<'a, R, W> {
+    compute: ComputeFn<'a, R, W>,
+}
+
+// Shares the compute closure; never clones R or W.
+impl<'a, R, W> Clone for Computed<'a, R, W> {
+    fn clone(&self) -> Self {
+        Self {
+            compute: Rc::clone(&self.compute),
+        }
+    }
+}
+
+impl<'a, R, W> Computed<'a, R, W> {
+    pub fn new(compute: impl Fn(&R) -> Option<W> + 'a) -> Self {
+        Self {
+            compute: Rc::new(compute),
+        }
+    }
+
+    /// Compute from one source keypath.
+    pub fn from_kp<A>(a: A, f: impl Fn(&A::Value) -> W + 'a) -> Self
+    where
+        A: ReadKp<Root = R> + 'a,
+    {
+        Self::new(move |root| Some(f(a.read(root)?)))
+    }
+
+    /// Compute from two source keypaths; `None` unless both resolve.
+    pub fn zip<A, B>(a: A, b: B, f: impl Fn(&A::Value, &B::Value) -> W + 'a) -> Self
+    where
+        A: ReadKp<Root = R> + 'a,
+        B: ReadKp<Root = R> + 'a,
+    {
+        Self::new(move |root| Some(f(a.read(root)?, b.read(root)?)))
+    }
+
+    /// Compute from three source keypaths; `None` unless all resolve.
+    pub fn zip3<A, B, C>(
+        a: A,
+        b: B,
+        c: C,
+        f: impl Fn(&A::Value, &B::Value, &C::Value) -> W + 'a,
+    ) -> Self
+    where
+        A: ReadKp<Root = R> + 'a,
+        B: ReadKp<Root = R> + 'a,
+        C: ReadKp<Root = R> + 'a,
+    {
+        Self::new(move |root| Some(f(a.read(root)?, b.read(root)?, c.read(root)?)))
+    }
+
+    pub fn get(&self, root: &R) -> Option<W> {
+        (self.compute)(root)
+    }
+}
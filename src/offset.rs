@@ -0,0 +1,282 @@
+//! Offset keypaths: a plain (inline, no container) field path stored as a byte offset from
+//! `core::mem::offset_of!`.
+//!
+//! Reading through an [OffsetKp] is one pointer add, with no closure call and no `Option` to
+//! unwrap, and composing two of them ([OffsetKp::then]) adds their offsets in a `const fn`. That
+//! makes them a fit for hot loops over `&[R]` ([OffsetKp::iter], [OffsetKp::gather]) and for
+//! mirroring `#[repr(C)]` layouts shared with FFI.
+//!
+//! Build one with [offset_kp!](crate::offset_kp), which checks that the offset and the value
+//! type name the same field. `#[derive(Kp)]` generates `{field}_offset()` (a `const fn`) for every
+//! field of a non-generic, non-packed struct. Offsets stop at the first container: an
+//! `Option<T>` field gives `OffsetKp<R, Option<T>>`, and from there the closure-based [Kp]
+//! accessors take over ([OffsetKp::to_kp], [Lens::compose]).
+//!
+//! # Example
+//! ```
+//! use rust_key_paths::{OffsetKp, offset_kp};
+//!
+//! #[repr(C)]
+//! struct Vec3 { x: f32, y: f32, z: f32 }
+//! #[repr(C)]
+//! struct Particle { id: u32, pos: Vec3 }
+//!
+//! const POS: OffsetKp<Particle, Vec3> = offset_kp!(Particle, pos);
+//! const Y: OffsetKp<Vec3, f32> = offset_kp!(Vec3, y);
+//! const POS_Y: OffsetKp<Particle, f32> = POS.then(Y);
+//! assert_eq!(POS_Y.offset(), 8);
+//!
+//! let mut particles: Vec<Particle> = (0..4)
+//!     .map(|i| Particle { id: i, pos: Vec3 { x: 0.0, y: i as f32, z: 0.0 } })
+//!     .collect();
+//! let mut ys = [0.0; 4];
+//! POS_Y.gather(&particles, &mut ys);
+//! assert_eq!(ys, [0.0, 1.0, 2.0, 3.0]);
+//!
+//! POS_Y.iter_mut(&mut particles).for_each(|y| *y *= 2.0);
+//! assert_eq!(*POS_Y.get(&particles[3]), 6.0);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::Shr;
+
+use crate::lens::{Lens, ReadKp, WriteKp};
+use crate::vivify::FillKp;
+use crate::Kp;
+
+/// Keypath to the `V` stored `offset` bytes into every `R`.
+///
+/// Only constructible through `unsafe` ([OffsetKp::new]) or [offset_kp!](crate::offset_kp), so
+/// every access can skip the checks a closure keypath would do.
+pub struct OffsetKp<R, V> {
+    offset: usize,
+    _p: PhantomData<fn(&R) -> &V>,
+}
+
+// OffsetKp holds only the offset; copying never touches R or V.
+impl<R, V> Clone for OffsetKp<R, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R, V> Copy for OffsetKp<R, V> {}
+
+impl<R, V> core::fmt::Debug for OffsetKp<R, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OffsetKp").field("offset", &self.offset).finish()
+    }
+}
+
+impl<R, V> OffsetKp<R, V> {
+    /// # Safety
+    /// Every `R` must hold a properly aligned, initialized `V` at `offset` bytes from its start,
+    /// borrowed whenever the `R` is (an inline field, not behind a pointer or in a union).
+    pub const unsafe fn new(offset: usize) -> Self {
+        Self {
+            offset,
+            _p: PhantomData,
+        }
+    }
+
+    /// Used by [offset_kp!](crate::offset_kp): `field` is never called, it only pins `V` to the
+    /// type of the field the offset was taken from.
+    ///
+    /// # Safety
+    /// As for [OffsetKp::new]; `field` must project the same field `offset` points at.
+    #[doc(hidden)]
+    pub const unsafe fn from_field<F>(offset: usize, field: F) -> Self
+    where
+        F: FnOnce(&R) -> &V,
+    {
+        core::mem::forget(field);
+        unsafe { Self::new(offset) }
+    }
+
+    /// The byte offset of the value inside `R`.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// `R -> V -> W` in one step: the offsets add up.
+    #[inline]
+    pub const fn then<W>(self, next: OffsetKp<V, W>) -> OffsetKp<R, W> {
+        OffsetKp {
+            offset: self.offset + next.offset,
+            _p: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn get<'a>(&self, root: &'a R) -> &'a V {
+        // SAFETY: guaranteed by the constructor.
+        unsafe { &*(root as *const R).cast::<u8>().add(self.offset).cast::<V>() }
+    }
+
+    #[inline(always)]
+    pub fn get_mut<'a>(&self, root: &'a mut R) -> &'a mut V {
+        // SAFETY: guaranteed by the constructor; `root` is borrowed uniquely.
+        unsafe { &mut *(root as *mut R).cast::<u8>().add(self.offset).cast::<V>() }
+    }
+
+    /// The value in each root, in order.
+    #[inline]
+    pub fn iter<'a>(self, roots: &'a [R]) -> impl ExactSizeIterator<Item = &'a V> + 'a
+    where
+        R: 'a,
+        V: 'a,
+    {
+        roots.iter().map(move |root| self.get(root))
+    }
+
+    #[inline]
+    pub fn iter_mut<'a>(self, roots: &'a mut [R]) -> impl ExactSizeIterator<Item = &'a mut V> + 'a
+    where
+        R: 'a,
+        V: 'a,
+    {
+        roots.iter_mut().map(move |root| self.get_mut(root))
+    }
+
+    /// Copies the value out of every root into `out`.
+    ///
+    /// # Panics
+    /// If `out.len() != roots.len()`.
+    #[inline]
+    pub fn gather(&self, roots: &[R], out: &mut [V])
+    where
+        V: Copy,
+    {
+        assert_eq!(roots.len(), out.len(), "OffsetKp::gather: length mismatch");
+        // SAFETY: lengths checked above.
+        unsafe { self.gather_unchecked(roots, out.as_mut_ptr()) }
+    }
+
+    /// [OffsetKp::gather] without the length check.
+    ///
+    /// # Safety
+    /// `out` must be valid for `roots.len()` writes of `V`.
+    #[inline]
+    pub unsafe fn gather_unchecked(&self, roots: &[R], out: *mut V)
+    where
+        V: Copy,
+    {
+        for (i, root) in roots.iter().enumerate() {
+            // SAFETY: `i < roots.len()`, which the caller guarantees `out` can hold.
+            unsafe { out.add(i).write(*self.get(root)) };
+        }
+    }
+
+    /// Copies `values[i]` into the value of `roots[i]`.
+    ///
+    /// # Panics
+    /// If `values.len() != roots.len()`.
+    #[inline]
+    pub fn scatter(&self, roots: &mut [R], values: &[V])
+    where
+        V: Copy,
+    {
+        assert_eq!(roots.len(), values.len(), "OffsetKp::scatter: length mismatch");
+        for (root, value) in roots.iter_mut().zip(values) {
+            *self.get_mut(root) = *value;
+        }
+    }
+
+    /// The same path as a closure-based [Kp], for composing with container, enum and lock steps.
+    ///
+    /// [crate::KpType] holds plain `fn` pointers, which cannot carry the offset; the returned
+    /// keypath has the same API and converts on to [crate::KpDynamic] via `From`.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn to_kp<'a>(
+        self,
+    ) -> Kp<
+        R,
+        V,
+        &'a R,
+        &'a V,
+        &'a mut R,
+        &'a mut V,
+        impl for<'b> Fn(&'b R) -> Option<&'b V> + Copy,
+        impl for<'b> Fn(&'b mut R) -> Option<&'b mut V> + Copy,
+    >
+    where
+        R: 'a,
+        V: 'a,
+    {
+        fn getter<R, V>(kp: OffsetKp<R, V>) -> impl for<'b> Fn(&'b R) -> Option<&'b V> + Copy {
+            move |root| Some(kp.get(root))
+        }
+        fn setter<R, V>(kp: OffsetKp<R, V>) -> impl for<'b> Fn(&'b mut R) -> Option<&'b mut V> + Copy {
+            move |root| Some(kp.get_mut(root))
+        }
+        Kp::new(getter(self), setter(self))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R: 'static, V: 'static> From<OffsetKp<R, V>> for crate::KpDynamic<R, V> {
+    #[inline]
+    fn from(kp: OffsetKp<R, V>) -> Self {
+        Kp::new(
+            alloc::boxed::Box::new(move |root: &R| Some(kp.get(root))),
+            alloc::boxed::Box::new(move |root: &mut R| Some(kp.get_mut(root))),
+        )
+    }
+}
+
+impl<R, V> Lens for OffsetKp<R, V> {
+    type Root = R;
+    type Value = V;
+}
+
+impl<R, V> ReadKp for OffsetKp<R, V> {
+    #[inline(always)]
+    fn read<'a>(&self, root: &'a R) -> Option<&'a V> {
+        Some(self.get(root))
+    }
+}
+
+impl<R, V> WriteKp for OffsetKp<R, V> {
+    #[inline(always)]
+    fn write<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        Some(self.get_mut(root))
+    }
+}
+
+impl<R, V> FillKp for OffsetKp<R, V> {
+    #[inline(always)]
+    fn get_mut_or_default<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        Some(self.get_mut(root))
+    }
+}
+
+// Offsets stay offsets: `a >> b` is `a.then(b)`, one pointer add. Mix with other keypath kinds
+// through `Lens::compose`.
+impl<R, V, W> Shr<OffsetKp<V, W>> for OffsetKp<R, V> {
+    type Output = OffsetKp<R, W>;
+
+    #[inline]
+    fn shr(self, next: OffsetKp<V, W>) -> OffsetKp<R, W> {
+        self.then(next)
+    }
+}
+
+/// `OffsetKp<Root, _>` for a plain field path, e.g. `offset_kp!(Particle, pos.y)` or
+/// `offset_kp!(Pair, 0)`. Usable in `const` items.
+///
+/// The path must not go through a pointer or container (`offset_of!` rejects those) nor into a
+/// `#[repr(packed)]` struct (the borrow check on the field rejects those).
+#[macro_export]
+macro_rules! offset_kp {
+    ($root:ty, $($field:tt).+ $(,)?) => {
+        // SAFETY: `offset_of!` and the projection name the same inline field of `$root`.
+        unsafe {
+            $crate::OffsetKp::<$root, _>::from_field(
+                ::core::mem::offset_of!($root, $($field).+),
+                |root: &$root| &root.$($field).+,
+            )
+        }
+    };
+}
@@ -0,0 +1,139 @@
+//! Type-changing keypaths: [PolyKp] rebuilds a root of one type into a root of another by
+//! replacing a single field (`Config<String>` → `Config<Url>`).
+//!
+//! A [PolyKp] owns its root: [PolyKp::over] consumes an `S`, maps the focused `A` to a `B`,
+//! and returns the rebuilt `T`. `#[derive(Kp)]` on a struct generic in a field's type
+//! generates `{field}_poly::<B>()` for that field.
+
+/// The focus-mapping function [PolyKp]'s `over` closure is called with.
+pub type PolyMap<'f, A, B> = &'f mut dyn FnMut(A) -> B;
+
+/// `get` of a [PolyKp]: borrows the `A` focus of an `S`. Implemented for every such closure.
+pub trait PolyGet<S, A>: for<'b> Fn(&'b S) -> &'b A {}
+
+impl<S, A, F: for<'b> Fn(&'b S) -> &'b A> PolyGet<S, A> for F {}
+
+/// `over` of a [PolyKp]: rebuilds an `S` into a `T`, mapping its `A` focus to a `B`. Implemented
+/// for every such closure.
+pub trait PolyOver<S, T, A, B>: Fn(S, PolyMap<'_, A, B>) -> T {}
+
+impl<S, T, A, B, F: Fn(S, PolyMap<'_, A, B>) -> T> PolyOver<S, T, A, B> for F {}
+
+// Variance / auto-trait marker: consumes S and B, produces T and A.
+type PolyMarker<S, T, A, B> = core::marker::PhantomData<fn(S, B) -> (T, A)>;
+
+/// By-value lens from `S` to `A` that can swap the focus for a `B`, producing a `T`.
+///
+/// Exactly one value is focused, so `over`'s function runs exactly once.
+///
+/// # Example
+/// ```
+/// use rust_key_paths::PolyKp;
+///
+/// struct Config<U> { name: &'static str, url: U }
+///
+/// fn url<U, B>() -> PolyKp<Config<U>, Config<B>, U, B> {
+///     PolyKp::new(
+///         |c: &Config<U>| &c.url,
+///         |c: Config<U>, f: &mut dyn FnMut(U) -> B| Config { name: c.name, url: f(c.url) },
+///     )
+/// }
+///
+/// let raw = Config { name: "api", url: "https://example.com:8443".to_string() };
+/// assert_eq!(url::<String, u16>().get(&raw), "https://example.com:8443");
+///
+/// // Validate, then change the field type
+/// let port: Config<u16> = url().over(raw, |u: String| u.rsplit(':').next().unwrap().parse().unwrap());
+/// assert_eq!((port.name, port.url), ("api", 8443));
+///
+/// let unset: Config<Option<u16>> = url().set(port, None);
+/// assert_eq!(unset.url, None);
+/// ```
+pub struct PolyKp<
+    S,
+    T,
+    A,
+    B,
+    G = for<'b> fn(&'b S) -> &'b A,
+    O = fn(S, PolyMap<'_, A, B>) -> T,
+> {
+    get: G,
+    over: O,
+    _p: PolyMarker<S, T, A, B>,
+}
+
+// Function pointers / closures only; cloning never touches S, T, A or B.
+impl<S, T, A, B, G: Clone, O: Clone> Clone for PolyKp<S, T, A, B, G, O> {
+    fn clone(&self) -> Self {
+        Self {
+            get: self.get.clone(),
+            over: self.over.clone(),
+            _p: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, T, A, B, G: Copy, O: Copy> Copy for PolyKp<S, T, A, B, G, O> {}
+
+impl<S, T, A, B, G, O> PolyKp<S, T, A, B, G, O>
+where
+    G: for<'b> Fn(&'b S) -> &'b A,
+    O: Fn(S, PolyMap<'_, A, B>) -> T,
+{
+    /// `get` borrows the focus; `over` takes the root apart, maps the focus with the given
+    /// function (calling it once) and rebuilds.
+    pub fn new(get: G, over: O) -> Self {
+        Self {
+            get,
+            over,
+            _p: core::marker::PhantomData,
+        }
+    }
+
+    /// Borrow the focused value.
+    #[inline]
+    pub fn get<'b>(&self, root: &'b S) -> &'b A {
+        (self.get)(root)
+    }
+
+    /// Rebuild `root` with the focus replaced by `f(focus)`.
+    #[inline]
+    pub fn over(&self, root: S, f: impl FnOnce(A) -> B) -> T {
+        let mut f = Some(f);
+        (self.over)(root, &mut |a| (f.take().expect("PolyKp focuses exactly one value"))(a))
+    }
+
+    /// Rebuild `root` with the focus replaced by `value`.
+    #[inline]
+    pub fn set(&self, root: S, value: B) -> T {
+        self.over(root, |_| value)
+    }
+
+    /// Focus further into the current focus (`config.server.url`). Like [crate::Kp::then], the
+    /// intermediate type must be `'static`.
+    pub fn then<C, D, G2, O2>(
+        self,
+        next: PolyKp<A, B, C, D, G2, O2>,
+    ) -> PolyKp<S, T, C, D, impl PolyGet<S, C>, impl PolyOver<S, T, C, D>>
+    where
+        A: 'static,
+        G2: for<'b> Fn(&'b A) -> &'b C,
+        O2: Fn(A, PolyMap<'_, C, D>) -> B,
+    {
+        let Self { get, over, .. } = self;
+        let PolyKp {
+            get: next_get,
+            over: next_over,
+            ..
+        } = next;
+        PolyKp::new(
+            hr_get(move |s: &S| next_get(get(s))),
+            move |s: S, f: PolyMap<'_, C, D>| over(s, &mut |a: A| next_over(a, &mut *f)),
+        )
+    }
+}
+
+// Pins a closure to the higher-ranked borrow signature (inference alone picks one lifetime).
+fn hr_get<S, C, F: for<'b> Fn(&'b S) -> &'b C>(f: F) -> F {
+    f
+}
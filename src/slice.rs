@@ -0,0 +1,75 @@
+//! Range / window keypaths over slice-like fields (`[T; N]`, `Box<[T]>`, `&[T]`).
+//!
+//! `[T]` is unsized, so it cannot be the value type of a [crate::Kp]. [SliceKp] keeps a keypath to
+//! the whole slice plus a `Range<usize>` and applies the range on every access.
+//!
+//! The Kp derive generates `{field}_range(a..b)` for array, boxed-slice and slice-reference fields.
+
+use core::ops::Range;
+
+/// Keypath to a sub-slice `root.field[range]`.
+///
+/// Out-of-bounds ranges yield `None` (like [slice::get]), never panic.
+///
+/// # Example
+/// ```
+/// use rust_key_paths::SliceKp;
+///
+/// struct Samples { data: [f32; 4] }
+///
+/// let mid = SliceKp::new(|s: &Samples| Some(&s.data[..]), |s: &mut Samples| Some(&mut s.data[..]), 1..3);
+/// let mut samples = Samples { data: [0.0, 1.0, 2.0, 3.0] };
+/// assert_eq!(mid.get(&samples), Some(&[1.0, 2.0][..]));
+/// mid.get_mut(&mut samples).unwrap().fill(9.0);
+/// assert_eq!(samples.data, [0.0, 9.0, 9.0, 3.0]);
+/// ```
+pub struct SliceKp<R, T> {
+    get: for<'b> fn(&'b R) -> Option<&'b [T]>,
+    get_mut: for<'b> fn(&'b mut R) -> Option<&'b mut [T]>,
+    range: Range<usize>,
+}
+
+// SliceKp holds only fn pointers and a range; cloning never touches R or T.
+impl<R, T> Clone for SliceKp<R, T> {
+    fn clone(&self) -> Self {
+        Self {
+            get: self.get,
+            get_mut: self.get_mut,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<R, T> SliceKp<R, T> {
+    pub const fn new(
+        get: for<'b> fn(&'b R) -> Option<&'b [T]>,
+        get_mut: for<'b> fn(&'b mut R) -> Option<&'b mut [T]>,
+        range: Range<usize>,
+    ) -> Self {
+        Self { get, get_mut, range }
+    }
+
+    /// The window `root.field[range]`, or `None` if the path or the range does not resolve.
+    #[inline]
+    pub fn get<'b>(&self, root: &'b R) -> Option<&'b [T]> {
+        (self.get)(root).and_then(|s| s.get(self.range.clone()))
+    }
+
+    /// Mutable window. `None` for read-only sources (e.g. `&[T]` fields).
+    #[inline]
+    pub fn get_mut<'b>(&self, root: &'b mut R) -> Option<&'b mut [T]> {
+        (self.get_mut)(root).and_then(|s| s.get_mut(self.range.clone()))
+    }
+
+    /// The range this keypath selects.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Same source, different window.
+    #[inline]
+    pub fn with_range(&self, range: Range<usize>) -> Self {
+        Self::new(self.get, self.get_mut, range)
+    }
+}
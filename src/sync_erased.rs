@@ -0,0 +1,773 @@
+//! Thread-safe type-erased keypaths: [SyncPKp] and [SyncAKp].
+//!
+//! Same API as [PKp](crate::PKp) / [AKp](crate::AKp), but the closures live behind
+//! `Arc<dyn Fn + Send + Sync>`, so the keypaths are `Send + Sync + Clone`. Keep them in a global
+//! `OnceLock` registry or hand them to rayon / tokio tasks. `#[derive(Pkp)]` and `#[derive(Akp)]`
+//! generate `sync_partial_kps()` / `sync_any_kps()`.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::any::{Any, TypeId};
+
+use crate::{id, AKp, KpError, KpId, KpType, PKp, TypeMismatch};
+
+/// Erased getter / setter of a [SyncPKp].
+type SyncPKpGetter<Root> = Arc<dyn for<'r> Fn(&'r Root) -> Option<&'r dyn Any> + Send + Sync>;
+type SyncPKpSetter<Root> = Arc<dyn for<'r> Fn(&'r mut Root) -> Option<&'r mut dyn Any> + Send + Sync>;
+
+/// Erased getter / setter of a [SyncAKp].
+type SyncAKpGetter = Arc<dyn for<'r> Fn(&'r dyn Any) -> Option<&'r dyn Any> + Send + Sync>;
+type SyncAKpSetter = Arc<dyn for<'r> Fn(&'r mut dyn Any) -> Option<&'r mut dyn Any> + Send + Sync>;
+
+/// Moves a boxed value into an erased value slot after checking its type.
+type SyncAnyAssign = Arc<dyn Fn(&mut dyn Any, Box<dyn Any>) -> Result<(), KpError> + Send + Sync>;
+
+fn sync_any_assign<V: Any>() -> SyncAnyAssign {
+    Arc::new(|slot: &mut dyn Any, value: Box<dyn Any>| {
+        let found = (*value).type_id();
+        let value = value.downcast::<V>().map_err(|_| KpError::ValueTypeMismatch {
+            expected: TypeId::of::<V>(),
+            found,
+        })?;
+        let slot = slot.downcast_mut::<V>().ok_or(KpError::NotFound)?;
+        *slot = *value;
+        Ok(())
+    })
+}
+
+/// [PKp](crate::PKp) that is `Send + Sync`: hides the value type, keeps the root type.
+///
+/// # Example
+/// ```
+/// use std::sync::OnceLock;
+/// use rust_key_paths::{KpType, SyncPKp};
+///
+/// struct User { name: String, age: u32 }
+///
+/// static FIELDS: OnceLock<Vec<SyncPKp<User>>> = OnceLock::new();
+/// let fields = FIELDS.get_or_init(|| {
+///     vec![
+///         SyncPKp::new(KpType::new(|u: &User| Some(&u.name), |u: &mut User| Some(&mut u.name))),
+///         SyncPKp::new(KpType::new(|u: &User| Some(&u.age), |u: &mut User| Some(&mut u.age))),
+///     ]
+/// });
+///
+/// let mut user = User { name: "Ada".to_string(), age: 36 };
+/// let age = fields[1].clone();
+/// std::thread::spawn(move || assert_eq!(age.value_type_id(), std::any::TypeId::of::<u32>()))
+///     .join()
+///     .unwrap();
+/// fields[1].set_any(&mut user, Box::new(37u32)).unwrap();
+/// assert_eq!(fields[1].get_as::<u32>(&user), Some(&37));
+/// ```
+pub struct SyncPKp<Root> {
+    getter: SyncPKpGetter<Root>,
+    setter: SyncPKpSetter<Root>,
+    assign: SyncAnyAssign,
+    value_type_id: TypeId,
+    id: Option<KpId>,
+    _phantom: core::marker::PhantomData<fn(Root) -> Root>,
+}
+
+impl<Root> Clone for SyncPKp<Root> {
+    fn clone(&self) -> Self {
+        Self {
+            getter: self.getter.clone(),
+            setter: self.setter.clone(),
+            assign: self.assign.clone(),
+            value_type_id: self.value_type_id,
+            id: self.id.clone(),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<Root> SyncPKp<Root>
+where
+    Root: 'static,
+{
+    /// Create a new SyncPKp from a KpType (the common reference-based keypath)
+    pub fn new<'a, V>(keypath: KpType<'a, Root, V>) -> Self
+    where
+        V: Any + 'static,
+    {
+        let value_type_id = TypeId::of::<V>();
+        let getter_fn = keypath.get;
+        let setter_fn = keypath.set;
+
+        Self {
+            getter: Arc::new(move |root: &Root| getter_fn(root).map(|val: &V| val as &dyn Any)),
+            setter: Arc::new(move |root: &mut Root| {
+                setter_fn(root).map(|val: &mut V| val as &mut dyn Any)
+            }),
+            assign: sync_any_assign::<V>(),
+            value_type_id,
+            id: None,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a SyncPKp from a KpType (alias for `new()`)
+    pub fn from<'a, V>(keypath: KpType<'a, Root, V>) -> Self
+    where
+        V: Any + 'static,
+    {
+        Self::new(keypath)
+    }
+
+    /// Get the value as a trait object
+    pub fn get<'r>(&self, root: &'r Root) -> Option<&'r dyn Any> {
+        (self.getter)(root)
+    }
+
+    /// Get the value mutably as a trait object
+    pub fn get_mut<'r>(&self, root: &'r mut Root) -> Option<&'r mut dyn Any> {
+        (self.setter)(root)
+    }
+
+    /// Replace the value, checking the value type.
+    pub fn set_any(&self, root: &mut Root, value: Box<dyn Any>) -> Result<(), KpError> {
+        let slot = (self.setter)(root).ok_or(KpError::NotFound)?;
+        (self.assign)(slot, value)
+    }
+
+    /// Compose with a keypath rooted at this keypath's value type `V`, checking the types at
+    /// construction.
+    pub fn then_pkp<V: Any>(&self, next: &SyncPKp<V>) -> Result<SyncPKp<Root>, TypeMismatch> {
+        if self.value_type_id != TypeId::of::<V>() {
+            return Err(TypeMismatch {
+                expected: TypeId::of::<V>(),
+                found: self.value_type_id,
+            });
+        }
+        let (first_get, next_get) = (self.getter.clone(), next.getter.clone());
+        let (first_set, next_set) = (self.setter.clone(), next.setter.clone());
+
+        Ok(SyncPKp {
+            getter: Arc::new(move |root: &Root| {
+                first_get(root)
+                    .and_then(|mid| mid.downcast_ref::<V>())
+                    .and_then(|mid| next_get(mid))
+            }),
+            setter: Arc::new(move |root: &mut Root| {
+                first_set(root)
+                    .and_then(|mid| mid.downcast_mut::<V>())
+                    .and_then(|mid| next_set(mid))
+            }),
+            assign: next.assign.clone(),
+            value_type_id: next.value_type_id,
+            id: id::join(&self.id, &next.id),
+            _phantom: core::marker::PhantomData,
+        })
+    }
+
+    /// Get the TypeId of the Value type
+    pub fn value_type_id(&self) -> TypeId {
+        self.value_type_id
+    }
+
+    /// Identity attached with `with_id` (see [KpId]). `then` keeps it when both sides are
+    /// identified, `filter` always does; `map` and the `for_*` root adapters drop it.
+    pub fn id(&self) -> Option<&KpId> {
+        self.id.as_ref()
+    }
+
+    /// Attach an identity, replacing any existing one.
+    pub fn with_id(mut self, id: KpId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Try to downcast the result to a specific type
+    pub fn get_as<'a, Value: Any>(&self, root: &'a Root) -> Option<&'a Value> {
+        if self.value_type_id == TypeId::of::<Value>() {
+            self.get(root).and_then(|any| any.downcast_ref::<Value>())
+        } else {
+            None
+        }
+    }
+
+    /// Try to downcast the mutable result to a specific type
+    pub fn get_mut_as<'a, Value: Any>(&self, root: &'a mut Root) -> Option<&'a mut Value> {
+        if self.value_type_id == TypeId::of::<Value>() {
+            self.get_mut(root).and_then(|any| any.downcast_mut::<Value>())
+        } else {
+            None
+        }
+    }
+
+    /// Get a human-readable name for the value type
+    pub fn kind_name(&self) -> String {
+        format!("{:?}", self.value_type_id)
+    }
+
+    /// Adapt this keypath to work with Arc<Root> instead of Root
+    pub fn for_arc(&self) -> SyncPKp<Arc<Root>> {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncPKp {
+            getter: Arc::new(move |arc: &Arc<Root>| getter(arc.as_ref())),
+            setter: Arc::new(move |arc: &mut Arc<Root>| Arc::get_mut(arc).and_then(|root| setter(root))),
+            assign: self.assign.clone(),
+            value_type_id: self.value_type_id,
+            id: None,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Adapt this keypath to work with Box<Root> instead of Root
+    // The adapted root type is `Box<Root>`, so the erased closures must take `&Box<Root>`.
+    #[allow(clippy::borrowed_box)]
+    pub fn for_box(&self) -> SyncPKp<Box<Root>> {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncPKp {
+            getter: Arc::new(move |boxed: &Box<Root>| getter(boxed.as_ref())),
+            setter: Arc::new(move |boxed: &mut Box<Root>| setter(boxed.as_mut())),
+            assign: self.assign.clone(),
+            value_type_id: self.value_type_id,
+            id: None,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Adapt this keypath to work with Rc<Root> instead of Root
+    pub fn for_rc(&self) -> SyncPKp<Rc<Root>> {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncPKp {
+            getter: Arc::new(move |rc: &Rc<Root>| getter(rc.as_ref())),
+            setter: Arc::new(move |rc: &mut Rc<Root>| Rc::get_mut(rc).and_then(|root| setter(root))),
+            assign: self.assign.clone(),
+            value_type_id: self.value_type_id,
+            id: None,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Adapt this keypath to work with Option<Root> instead of Root
+    pub fn for_option(&self) -> SyncPKp<Option<Root>> {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncPKp {
+            getter: Arc::new(move |opt: &Option<Root>| opt.as_ref().and_then(|root| getter(root))),
+            setter: Arc::new(move |opt: &mut Option<Root>| opt.as_mut().and_then(|root| setter(root))),
+            assign: self.assign.clone(),
+            value_type_id: self.value_type_id,
+            id: None,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Adapt this keypath to work with Result<Root, E> instead of Root
+    pub fn for_result<E>(&self) -> SyncPKp<Result<Root, E>>
+    where
+        E: 'static,
+    {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncPKp {
+            getter: Arc::new(move |result: &Result<Root, E>| {
+                result.as_ref().ok().and_then(|root| getter(root))
+            }),
+            setter: Arc::new(move |result: &mut Result<Root, E>| {
+                result.as_mut().ok().and_then(|root| setter(root))
+            }),
+            assign: self.assign.clone(),
+            value_type_id: self.value_type_id,
+            id: None,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Map the value through a transformation function (read-only, like [PKp::map])
+    pub fn map<OrigValue, MappedValue, F>(&self, mapper: F) -> SyncPKp<Root>
+    where
+        OrigValue: Any + 'static,
+        MappedValue: Any + 'static,
+        F: Fn(&OrigValue) -> MappedValue + Send + Sync + 'static,
+    {
+        let orig_type_id = self.value_type_id;
+        let getter = self.getter.clone();
+
+        SyncPKp {
+            getter: Arc::new(move |root: &Root| {
+                getter(root).and_then(|any_value| {
+                    if orig_type_id == TypeId::of::<OrigValue>() {
+                        any_value.downcast_ref::<OrigValue>().map(|orig_val| {
+                            // Same allocation strategy as PKp::map
+                            Box::leak(Box::new(mapper(orig_val))) as &dyn Any
+                        })
+                    } else {
+                        None
+                    }
+                })
+            }),
+            setter: Arc::new(|_: &mut Root| None),
+            assign: sync_any_assign::<MappedValue>(),
+            value_type_id: TypeId::of::<MappedValue>(),
+            id: None,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Filter the value based on a predicate with type checking
+    pub fn filter<Value, F>(&self, predicate: F) -> SyncPKp<Root>
+    where
+        Value: Any + 'static,
+        F: Fn(&Value) -> bool + Send + Sync + 'static,
+    {
+        let orig_type_id = self.value_type_id;
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+        let predicate = Arc::new(predicate);
+        let set_predicate = predicate.clone();
+
+        SyncPKp {
+            getter: Arc::new(move |root: &Root| {
+                getter(root).filter(|any_value| {
+                    orig_type_id == TypeId::of::<Value>()
+                        && any_value
+                            .downcast_ref::<Value>()
+                            .map(|val| predicate(val))
+                            .unwrap_or(false)
+                })
+            }),
+            setter: Arc::new(move |root: &mut Root| {
+                setter(root).filter(|any_value| {
+                    orig_type_id == TypeId::of::<Value>()
+                        && any_value
+                            .downcast_ref::<Value>()
+                            .map(|val| set_predicate(val))
+                            .unwrap_or(false)
+                })
+            }),
+            assign: self.assign.clone(),
+            value_type_id: orig_type_id,
+            id: self.id.clone(),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Thread-local view of a [SyncPKp], for APIs that take [PKp].
+impl<Root: 'static> From<SyncPKp<Root>> for PKp<Root> {
+    fn from(kp: SyncPKp<Root>) -> Self {
+        let getter = kp.getter;
+        let setter = kp.setter;
+        let assign = kp.assign;
+        PKp {
+            getter: Rc::new(move |root: &Root| getter(root)),
+            setter: Rc::new(move |root: &mut Root| setter(root)),
+            assign: Rc::new(move |slot: &mut dyn Any, value: Box<dyn Any>| assign(slot, value)),
+            value_type_id: kp.value_type_id,
+            id: kp.id,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+/// [AKp](crate::AKp) that is `Send + Sync`: hides both the root and the value type.
+///
+/// # Example
+/// ```
+/// use rust_key_paths::{KpType, SyncAKp};
+///
+/// struct User { age: u32 }
+/// struct Product { price: f64 }
+///
+/// let kps = vec![
+///     SyncAKp::new(KpType::new(|u: &User| Some(&u.age), |u: &mut User| Some(&mut u.age))),
+///     SyncAKp::new(KpType::new(|p: &Product| Some(&p.price), |p: &mut Product| Some(&mut p.price))),
+/// ];
+/// let handle = std::thread::spawn(move || {
+///     let product = Product { price: 9.5 };
+///     kps[1].get_as::<Product, f64>(&product).flatten().copied()
+/// });
+/// assert_eq!(handle.join().unwrap(), Some(9.5));
+/// ```
+#[derive(Clone)]
+pub struct SyncAKp {
+    getter: SyncAKpGetter,
+    setter: SyncAKpSetter,
+    assign: SyncAnyAssign,
+    root_type_id: TypeId,
+    value_type_id: TypeId,
+    id: Option<KpId>,
+}
+
+impl SyncAKp {
+    /// Create a new SyncAKp from a KpType (the common reference-based keypath)
+    pub fn new<'a, R, V>(keypath: KpType<'a, R, V>) -> Self
+    where
+        R: Any + 'static,
+        V: Any + 'static,
+    {
+        let getter_fn = keypath.get;
+        let setter_fn = keypath.set;
+
+        Self {
+            getter: Arc::new(move |any: &dyn Any| {
+                any.downcast_ref::<R>()
+                    .and_then(|root| getter_fn(root).map(|value: &V| value as &dyn Any))
+            }),
+            setter: Arc::new(move |any: &mut dyn Any| {
+                any.downcast_mut::<R>()
+                    .and_then(|root| setter_fn(root).map(|value: &mut V| value as &mut dyn Any))
+            }),
+            assign: sync_any_assign::<V>(),
+            root_type_id: TypeId::of::<R>(),
+            value_type_id: TypeId::of::<V>(),
+            id: None,
+        }
+    }
+
+    /// Create a SyncAKp from a KpType (alias for `new()`)
+    pub fn from<'a, R, V>(keypath: KpType<'a, R, V>) -> Self
+    where
+        R: Any + 'static,
+        V: Any + 'static,
+    {
+        Self::new(keypath)
+    }
+
+    /// Get the value as a trait object (with root type checking)
+    pub fn get<'r>(&self, root: &'r dyn Any) -> Option<&'r dyn Any> {
+        (self.getter)(root)
+    }
+
+    /// Get the value mutably as a trait object (with root type checking)
+    pub fn get_mut<'r>(&self, root: &'r mut dyn Any) -> Option<&'r mut dyn Any> {
+        (self.setter)(root)
+    }
+
+    /// Replace the value, checking both the root and the value type.
+    pub fn set_any(&self, root: &mut dyn Any, value: Box<dyn Any>) -> Result<(), KpError> {
+        let found = (*root).type_id();
+        if found != self.root_type_id {
+            return Err(KpError::RootTypeMismatch {
+                expected: self.root_type_id,
+                found,
+            });
+        }
+        let slot = (self.setter)(root).ok_or(KpError::NotFound)?;
+        (self.assign)(slot, value)
+    }
+
+    /// Compose with `next`, checking at construction that this keypath's value type is
+    /// `next`'s root type.
+    pub fn then(&self, next: &SyncAKp) -> Result<SyncAKp, TypeMismatch> {
+        if self.value_type_id != next.root_type_id {
+            return Err(TypeMismatch {
+                expected: next.root_type_id,
+                found: self.value_type_id,
+            });
+        }
+        let (first_get, next_get) = (self.getter.clone(), next.getter.clone());
+        let (first_set, next_set) = (self.setter.clone(), next.setter.clone());
+
+        Ok(SyncAKp {
+            getter: Arc::new(move |root: &dyn Any| first_get(root).and_then(|mid| next_get(mid))),
+            setter: Arc::new(move |root: &mut dyn Any| first_set(root).and_then(|mid| next_set(mid))),
+            assign: next.assign.clone(),
+            root_type_id: self.root_type_id,
+            value_type_id: next.value_type_id,
+            id: id::join(&self.id, &next.id),
+        })
+    }
+
+    /// Get the TypeId of the Root type
+    pub fn root_type_id(&self) -> TypeId {
+        self.root_type_id
+    }
+
+    /// Get the TypeId of the Value type
+    pub fn value_type_id(&self) -> TypeId {
+        self.value_type_id
+    }
+
+    /// Identity attached with `with_id` (see [KpId]). `then` keeps it when both sides are
+    /// identified, `filter` always does; `map` and the `for_*` root adapters drop it.
+    pub fn id(&self) -> Option<&KpId> {
+        self.id.as_ref()
+    }
+
+    /// Attach an identity, replacing any existing one.
+    pub fn with_id(mut self, id: KpId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Try to get the value with full type checking
+    pub fn get_as<'a, Root: Any, Value: Any>(&self, root: &'a Root) -> Option<Option<&'a Value>> {
+        if self.root_type_id == TypeId::of::<Root>() && self.value_type_id == TypeId::of::<Value>()
+        {
+            Some(
+                self.get(root as &dyn Any)
+                    .and_then(|any| any.downcast_ref::<Value>()),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Try to get the value mutably with full type checking
+    pub fn get_mut_as<'a, Root: Any, Value: Any>(
+        &self,
+        root: &'a mut Root,
+    ) -> Option<Option<&'a mut Value>> {
+        if self.root_type_id == TypeId::of::<Root>() && self.value_type_id == TypeId::of::<Value>()
+        {
+            Some(
+                self.get_mut(root as &mut dyn Any)
+                    .and_then(|any| any.downcast_mut::<Value>()),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Get a human-readable name for the value type
+    pub fn kind_name(&self) -> String {
+        format!("{:?}", self.value_type_id)
+    }
+
+    /// Get a human-readable name for the root type
+    pub fn root_kind_name(&self) -> String {
+        format!("{:?}", self.root_type_id)
+    }
+
+    /// Adapt this keypath to work with Arc<Root> instead of Root
+    pub fn for_arc<Root>(&self) -> SyncAKp
+    where
+        Root: Any + 'static,
+    {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncAKp {
+            getter: Arc::new(move |any: &dyn Any| {
+                any.downcast_ref::<Arc<Root>>()
+                    .and_then(|arc| getter(arc.as_ref() as &dyn Any))
+            }),
+            setter: Arc::new(move |any: &mut dyn Any| {
+                any.downcast_mut::<Arc<Root>>()
+                    .and_then(Arc::get_mut)
+                    .and_then(|root| setter(root as &mut dyn Any))
+            }),
+            assign: self.assign.clone(),
+            root_type_id: TypeId::of::<Arc<Root>>(),
+            value_type_id: self.value_type_id,
+            id: None,
+        }
+    }
+
+    /// Adapt this keypath to work with Box<Root> instead of Root
+    pub fn for_box<Root>(&self) -> SyncAKp
+    where
+        Root: Any + 'static,
+    {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncAKp {
+            getter: Arc::new(move |any: &dyn Any| {
+                any.downcast_ref::<Box<Root>>()
+                    .and_then(|boxed| getter(boxed.as_ref() as &dyn Any))
+            }),
+            setter: Arc::new(move |any: &mut dyn Any| {
+                any.downcast_mut::<Box<Root>>()
+                    .and_then(|boxed| setter(boxed.as_mut() as &mut dyn Any))
+            }),
+            assign: self.assign.clone(),
+            root_type_id: TypeId::of::<Box<Root>>(),
+            value_type_id: self.value_type_id,
+            id: None,
+        }
+    }
+
+    /// Adapt this keypath to work with Rc<Root> instead of Root
+    pub fn for_rc<Root>(&self) -> SyncAKp
+    where
+        Root: Any + 'static,
+    {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncAKp {
+            getter: Arc::new(move |any: &dyn Any| {
+                any.downcast_ref::<Rc<Root>>()
+                    .and_then(|rc| getter(rc.as_ref() as &dyn Any))
+            }),
+            setter: Arc::new(move |any: &mut dyn Any| {
+                any.downcast_mut::<Rc<Root>>()
+                    .and_then(Rc::get_mut)
+                    .and_then(|root| setter(root as &mut dyn Any))
+            }),
+            assign: self.assign.clone(),
+            root_type_id: TypeId::of::<Rc<Root>>(),
+            value_type_id: self.value_type_id,
+            id: None,
+        }
+    }
+
+    /// Adapt this keypath to work with Option<Root> instead of Root
+    pub fn for_option<Root>(&self) -> SyncAKp
+    where
+        Root: Any + 'static,
+    {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncAKp {
+            getter: Arc::new(move |any: &dyn Any| {
+                any.downcast_ref::<Option<Root>>()
+                    .and_then(|opt| opt.as_ref())
+                    .and_then(|root| getter(root as &dyn Any))
+            }),
+            setter: Arc::new(move |any: &mut dyn Any| {
+                any.downcast_mut::<Option<Root>>()
+                    .and_then(|opt| opt.as_mut())
+                    .and_then(|root| setter(root as &mut dyn Any))
+            }),
+            assign: self.assign.clone(),
+            root_type_id: TypeId::of::<Option<Root>>(),
+            value_type_id: self.value_type_id,
+            id: None,
+        }
+    }
+
+    /// Adapt this keypath to work with Result<Root, E> instead of Root
+    pub fn for_result<Root, E>(&self) -> SyncAKp
+    where
+        Root: Any + 'static,
+        E: Any + 'static,
+    {
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+
+        SyncAKp {
+            getter: Arc::new(move |any: &dyn Any| {
+                any.downcast_ref::<Result<Root, E>>()
+                    .and_then(|result| result.as_ref().ok())
+                    .and_then(|root| getter(root as &dyn Any))
+            }),
+            setter: Arc::new(move |any: &mut dyn Any| {
+                any.downcast_mut::<Result<Root, E>>()
+                    .and_then(|result| result.as_mut().ok())
+                    .and_then(|root| setter(root as &mut dyn Any))
+            }),
+            assign: self.assign.clone(),
+            root_type_id: TypeId::of::<Result<Root, E>>(),
+            value_type_id: self.value_type_id,
+            id: None,
+        }
+    }
+
+    /// Map the value through a transformation function (read-only, like [AKp::map])
+    pub fn map<Root, OrigValue, MappedValue, F>(&self, mapper: F) -> SyncAKp
+    where
+        Root: Any + 'static,
+        OrigValue: Any + 'static,
+        MappedValue: Any + 'static,
+        F: Fn(&OrigValue) -> MappedValue + Send + Sync + 'static,
+    {
+        let orig_root_type_id = self.root_type_id;
+        let orig_value_type_id = self.value_type_id;
+        let getter = self.getter.clone();
+
+        SyncAKp {
+            getter: Arc::new(move |any_root: &dyn Any| {
+                if any_root.type_id() != orig_root_type_id
+                    || orig_value_type_id != TypeId::of::<OrigValue>()
+                {
+                    return None;
+                }
+                getter(any_root).and_then(|any_value| {
+                    any_value.downcast_ref::<OrigValue>().map(|orig_val| {
+                        // Same allocation strategy as AKp::map
+                        Box::leak(Box::new(mapper(orig_val))) as &dyn Any
+                    })
+                })
+            }),
+            setter: Arc::new(|_: &mut dyn Any| None),
+            assign: sync_any_assign::<MappedValue>(),
+            root_type_id: orig_root_type_id,
+            value_type_id: TypeId::of::<MappedValue>(),
+            id: None,
+        }
+    }
+
+    /// Filter the value based on a predicate with full type checking
+    pub fn filter<Root, Value, F>(&self, predicate: F) -> SyncAKp
+    where
+        Root: Any + 'static,
+        Value: Any + 'static,
+        F: Fn(&Value) -> bool + Send + Sync + 'static,
+    {
+        let orig_root_type_id = self.root_type_id;
+        let orig_value_type_id = self.value_type_id;
+        let getter = self.getter.clone();
+        let setter = self.setter.clone();
+        let predicate = Arc::new(predicate);
+        let set_predicate = predicate.clone();
+
+        SyncAKp {
+            getter: Arc::new(move |any_root: &dyn Any| {
+                if any_root.type_id() != orig_root_type_id
+                    || orig_value_type_id != TypeId::of::<Value>()
+                {
+                    return None;
+                }
+                getter(any_root).filter(|any_value| {
+                    any_value
+                        .downcast_ref::<Value>()
+                        .map(|val| predicate(val))
+                        .unwrap_or(false)
+                })
+            }),
+            setter: Arc::new(move |any_root: &mut dyn Any| {
+                if (*any_root).type_id() != orig_root_type_id
+                    || orig_value_type_id != TypeId::of::<Value>()
+                {
+                    return None;
+                }
+                setter(any_root).filter(|any_value| {
+                    any_value
+                        .downcast_ref::<Value>()
+                        .map(|val| set_predicate(val))
+                        .unwrap_or(false)
+                })
+            }),
+            assign: self.assign.clone(),
+            root_type_id: orig_root_type_id,
+            value_type_id: orig_value_type_id,
+            id: self.id.clone(),
+        }
+    }
+}
+
+/// Thread-local view of a [SyncAKp], for APIs that take [AKp].
+impl From<SyncAKp> for AKp {
+    fn from(kp: SyncAKp) -> Self {
+        let getter = kp.getter;
+        let setter = kp.setter;
+        let assign = kp.assign;
+        AKp {
+            getter: Rc::new(move |root: &dyn Any| getter(root)),
+            setter: Rc::new(move |root: &mut dyn Any| setter(root)),
+            assign: Rc::new(move |slot: &mut dyn Any, value: Box<dyn Any>| assign(slot, value)),
+            root_type_id: kp.root_type_id,
+            value_type_id: kp.value_type_id,
+            id: kp.id,
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! Multi-focus keypaths: a [Traversal] focuses on every element of a collection
+//! (`company.employees[*].address.city`) for reading and writing.
+//!
+//! Collections opt in through [Traversable] (implemented for `Vec`, `VecDeque`, `LinkedList`,
+//! arrays, `HashMap` / `BTreeMap` values and `HashSet` / `BTreeSet`; the hashed ones need `std`). Build a traversal with
+//! [Kp::each] / [Kp::then_each] on the left, continue with [Traversal::then] (any
+//! [ReadKp] + [WriteKp], so [Kp], [crate::EnumKp], [crate::LockKp], ...) or
+//! [Traversal::then_each] on the right. `#[derive(Kp)]` generates `{field}_each()` for
+//! collection fields.
+//!
+//! Traversals visit elements through callbacks, so [Traversal::for_each],
+//! [Traversal::modify_all] and [Traversal::count] never allocate; [Traversal::iter] and
+//! [Traversal::iter_mut] collect the focused references into a `Vec` first.
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::lens::{ReadKp, WriteKp};
+use crate::Kp;
+
+type Visit<'a, R, V> = Rc<dyn for<'b> Fn(&'b R, &mut dyn FnMut(&'b V)) + 'a>;
+type VisitMut<'a, R, V> = Rc<dyn for<'b> Fn(&'b mut R, &mut dyn FnMut(&'b mut V)) + 'a>;
+
+/// A collection a [Traversal] can focus on element by element.
+///
+/// Set elements cannot change in place without breaking the set's hash / ordering invariant,
+/// so `HashSet` / `BTreeSet` visit nothing in [Traversable::each_mut].
+pub trait Traversable {
+    type Item;
+    fn each<'b>(&'b self, f: &mut dyn FnMut(&'b Self::Item));
+    fn each_mut<'b>(&'b mut self, f: &mut dyn FnMut(&'b mut Self::Item));
+}
+
+macro_rules! traversable_seq {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T> Traversable for $ty {
+                type Item = T;
+                #[inline]
+                fn each<'b>(&'b self, f: &mut dyn FnMut(&'b T)) {
+                    self.iter().for_each(f)
+                }
+                #[inline]
+                fn each_mut<'b>(&'b mut self, f: &mut dyn FnMut(&'b mut T)) {
+                    self.iter_mut().for_each(f)
+                }
+            }
+        )*
+    };
+}
+
+traversable_seq!(Vec<T>, VecDeque<T>, LinkedList<T>, Box<[T]>);
+
+impl<T, const N: usize> Traversable for [T; N] {
+    type Item = T;
+    #[inline]
+    fn each<'b>(&'b self, f: &mut dyn FnMut(&'b T)) {
+        self.iter().for_each(f)
+    }
+    #[inline]
+    fn each_mut<'b>(&'b mut self, f: &mut dyn FnMut(&'b mut T)) {
+        self.iter_mut().for_each(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Traversable for HashMap<K, V, S> {
+    type Item = V;
+    #[inline]
+    fn each<'b>(&'b self, f: &mut dyn FnMut(&'b V)) {
+        self.values().for_each(f)
+    }
+    #[inline]
+    fn each_mut<'b>(&'b mut self, f: &mut dyn FnMut(&'b mut V)) {
+        self.values_mut().for_each(f)
+    }
+}
+
+impl<K, V> Traversable for BTreeMap<K, V> {
+    type Item = V;
+    #[inline]
+    fn each<'b>(&'b self, f: &mut dyn FnMut(&'b V)) {
+        self.values().for_each(f)
+    }
+    #[inline]
+    fn each_mut<'b>(&'b mut self, f: &mut dyn FnMut(&'b mut V)) {
+        self.values_mut().for_each(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> Traversable for HashSet<T, S> {
+    type Item = T;
+    #[inline]
+    fn each<'b>(&'b self, f: &mut dyn FnMut(&'b T)) {
+        self.iter().for_each(f)
+    }
+    #[inline]
+    fn each_mut<'b>(&'b mut self, _f: &mut dyn FnMut(&'b mut T)) {}
+}
+
+impl<T> Traversable for BTreeSet<T> {
+    type Item = T;
+    #[inline]
+    fn each<'b>(&'b self, f: &mut dyn FnMut(&'b T)) {
+        self.iter().for_each(f)
+    }
+    #[inline]
+    fn each_mut<'b>(&'b mut self, _f: &mut dyn FnMut(&'b mut T)) {}
+}
+
+/// Keypath with any number of foci: every element of one or more collections on the way.
+///
+/// # Example
+/// ```
+/// use rust_key_paths::{KpType, Traversal};
+///
+/// struct Address { city: String }
+/// struct Employee { address: Address }
+/// struct Company { employees: Vec<Employee> }
+///
+/// let employees: KpType<'static, Company, Vec<Employee>> =
+///     KpType::new(|c: &Company| Some(&c.employees), |c: &mut Company| Some(&mut c.employees));
+/// let address: KpType<'static, Employee, Address> =
+///     KpType::new(|e: &Employee| Some(&e.address), |e: &mut Employee| Some(&mut e.address));
+/// let city: KpType<'static, Address, String> =
+///     KpType::new(|a: &Address| Some(&a.city), |a: &mut Address| Some(&mut a.city));
+///
+/// // company.employees[*].address.city
+/// let cities: Traversal<Company, String> = employees.each().then(address).then(city);
+///
+/// let mut company = Company {
+///     employees: vec![
+///         Employee { address: Address { city: "Pune".into() } },
+///         Employee { address: Address { city: "Oslo".into() } },
+///     ],
+/// };
+/// assert_eq!(cities.count(&company), 2);
+/// assert_eq!(cities.modify_all(&mut company, |c| c.make_ascii_uppercase()), 2);
+/// assert_eq!(cities.iter(&company).collect::<Vec<_>>(), ["PUNE", "OSLO"]);
+/// ```
+pub struct Traversal<'a, R, V> {
+    visit: Visit<'a, R, V>,
+    visit_mut: VisitMut<'a, R, V>,
+}
+
+// Shares the visitor closures; never clones R or V.
+impl<'a, R, V> Clone for Traversal<'a, R, V> {
+    fn clone(&self) -> Self {
+        Self {
+            visit: Rc::clone(&self.visit),
+            visit_mut: Rc::clone(&self.visit_mut),
+        }
+    }
+}
+
+impl<'a, R: 'a, V: 'a> Traversal<'a, R, V> {
+    /// Build from visitor functions that call `f` once per focused element.
+    pub fn new<F, FM>(visit: F, visit_mut: FM) -> Self
+    where
+        F: for<'b> Fn(&'b R, &mut dyn FnMut(&'b V)) + 'a,
+        FM: for<'b> Fn(&'b mut R, &mut dyn FnMut(&'b mut V)) + 'a,
+    {
+        Self {
+            visit: Rc::new(visit),
+            visit_mut: Rc::new(visit_mut),
+        }
+    }
+
+    /// Single-focus traversal: the value of `kp`, when it resolves.
+    pub fn from_kp<K>(kp: K) -> Self
+    where
+        K: ReadKp<Root = R, Value = V> + WriteKp + 'a,
+    {
+        let kp = Rc::new(kp);
+        let kp_mut = Rc::clone(&kp);
+        Self::new(
+            move |root, f| {
+                if let Some(v) = kp.read(root) {
+                    f(v)
+                }
+            },
+            move |root, f| {
+                if let Some(v) = kp_mut.write(root) {
+                    f(v)
+                }
+            },
+        )
+    }
+
+    /// Call `f` for every focused element, in collection order.
+    pub fn for_each<'b>(&self, root: &'b R, mut f: impl FnMut(&'b V))
+    where
+        V: 'b,
+    {
+        (self.visit)(root, &mut f)
+    }
+
+    /// The focused elements.
+    pub fn iter<'b>(&self, root: &'b R) -> alloc::vec::IntoIter<&'b V> {
+        let mut out = Vec::new();
+        (self.visit)(root, &mut |v| out.push(v));
+        out.into_iter()
+    }
+
+    /// The focused elements, mutably.
+    pub fn iter_mut<'b>(&self, root: &'b mut R) -> alloc::vec::IntoIter<&'b mut V> {
+        let mut out = Vec::new();
+        (self.visit_mut)(root, &mut |v| out.push(v));
+        out.into_iter()
+    }
+
+    /// Run `f` on every focused element. Returns how many were modified.
+    pub fn modify_all(&self, root: &mut R, mut f: impl FnMut(&mut V)) -> usize {
+        let mut n = 0;
+        (self.visit_mut)(root, &mut |v| {
+            f(v);
+            n += 1;
+        });
+        n
+    }
+
+    /// Number of focused elements.
+    pub fn count(&self, root: &R) -> usize {
+        let mut n = 0;
+        (self.visit)(root, &mut |_| n += 1);
+        n
+    }
+
+    /// Continue from every focused element through `kp`; elements where `kp` does not resolve
+    /// are skipped. Like [Kp::then], the intermediate value type must be `'static`.
+    pub fn then<K>(self, kp: K) -> Traversal<'a, R, K::Value>
+    where
+        V: 'static,
+        K: ReadKp<Root = V> + WriteKp + 'a,
+        K::Value: Sized + 'a,
+    {
+        let kp = Rc::new(kp);
+        let kp_mut = Rc::clone(&kp);
+        let Self { visit, visit_mut } = self;
+        Traversal::new(
+            move |root, f| {
+                visit(root, &mut |v| {
+                    if let Some(w) = kp.read(v) {
+                        f(w)
+                    }
+                })
+            },
+            move |root, f| {
+                visit_mut(root, &mut |v| {
+                    if let Some(w) = kp_mut.write(v) {
+                        f(w)
+                    }
+                })
+            },
+        )
+    }
+
+    /// Fan out again from every focused element (`a[*].b[*]`).
+    pub fn then_each<W: 'a>(self, next: Traversal<'a, V, W>) -> Traversal<'a, R, W>
+    where
+        V: 'static,
+    {
+        let Self { visit, visit_mut } = self;
+        let Traversal {
+            visit: next_visit,
+            visit_mut: next_visit_mut,
+        } = next;
+        Traversal::new(
+            move |root, f| visit(root, &mut |v| next_visit(v, f)),
+            move |root, f| visit_mut(root, &mut |v| next_visit_mut(v, f)),
+        )
+    }
+}
+
+impl<'a, C> Traversal<'a, C, C::Item>
+where
+    C: Traversable + 'a,
+    C::Item: 'a,
+{
+    /// Every element of a [Traversable] collection.
+    pub fn each() -> Self {
+        Self::new(|c: &C, f| c.each(f), |c: &mut C, f| c.each_mut(f))
+    }
+}
+
+impl<'a, K, V> Traversal<'a, BTreeMap<K, V>, V>
+where
+    K: Ord + Clone + 'a,
+    V: 'a,
+{
+    /// The values of a `BTreeMap` whose keys fall in `range`, in key order.
+    ///
+    /// An inverted range (`5..2`) or one excluding the same key at both ends visits nothing
+    /// (where `BTreeMap::range` would panic).
+    pub fn btree_range(range: impl RangeBounds<K>) -> Self {
+        let bounds: (Bound<K>, Bound<K>) = (range.start_bound().cloned(), range.end_bound().cloned());
+        let empty = match &bounds {
+            (Bound::Included(start) | Bound::Excluded(start), Bound::Included(end))
+            | (Bound::Included(start), Bound::Excluded(end)) => start > end,
+            (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
+            _ => false,
+        };
+        let bounds_mut = bounds.clone();
+        Self::new(
+            move |map: &BTreeMap<K, V>, f| {
+                if !empty {
+                    map.range(bounds.clone()).for_each(|(_, v)| f(v))
+                }
+            },
+            move |map: &mut BTreeMap<K, V>, f| {
+                if !empty {
+                    map.range_mut(bounds_mut.clone()).for_each(|(_, v)| f(v))
+                }
+            },
+        )
+    }
+}
+
+impl<'x, R, V, G, S> Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+{
+    /// Focus on every element of the collection this keypath points at (`field[*]`).
+    pub fn each<'a>(self) -> Traversal<'a, R, V::Item>
+    where
+        Self: 'a,
+        R: 'a,
+        V: Traversable + 'static,
+        V::Item: 'a,
+    {
+        Traversal::from_kp(self).then_each(Traversal::each())
+    }
+
+    /// Continue into a traversal rooted at this keypath's value.
+    pub fn then_each<'a, W: 'a>(self, next: Traversal<'a, V, W>) -> Traversal<'a, R, W>
+    where
+        Self: 'a,
+        R: 'a,
+        V: 'static,
+    {
+        Traversal::from_kp(self).then_each(next)
+    }
+}
@@ -0,0 +1,246 @@
+//! Auto-vivifying writes: [FillKp::get_mut_or_default] creates whatever is missing on the way to
+//! the value instead of returning `None`.
+//!
+//! [OrDefault] fills an empty `Option` with `Default::default()`, [Entry] inserts a missing map
+//! entry or grows a `Vec` / `VecDeque` to the index (see [KpEntry]). Plain field and variant
+//! keypaths have nothing to create, so they just write. Compose the steps with [Lens::compose];
+//! the inherent `Kp::then` only knows the setter and never creates anything.
+//!
+//! `#[derive(Kp)]` generates `{field}_or_default()` for `Option<T>` / `Option<Box<T>>` fields and
+//! `{field}_entry(key)` for `HashMap`, `BTreeMap`, `Vec` and `VecDeque` fields.
+//!
+//! # Example
+//! ```
+//! use rust_key_paths::vivify::FillKp;
+//! use rust_key_paths::{KpType, Lens, ReadKp};
+//! use std::collections::HashMap;
+//!
+//! #[derive(Default)]
+//! struct Settings { theme: String }
+//! struct User { settings: Option<HashMap<String, Settings>> }
+//!
+//! let settings: KpType<'static, User, Option<HashMap<String, Settings>>> =
+//!     KpType::new(|u: &User| Some(&u.settings), |u: &mut User| Some(&mut u.settings));
+//! let theme: KpType<'static, Settings, String> =
+//!     KpType::new(|s: &Settings| Some(&s.theme), |s: &mut Settings| Some(&mut s.theme));
+//!
+//! let dark = settings.or_default().entry("dark".to_string()).compose(theme);
+//!
+//! let mut user = User { settings: None };
+//! assert!(dark.read(&user).is_none());
+//! *dark.get_mut_or_default(&mut user).unwrap() = "#000".into();
+//! assert_eq!(dark.read(&user).map(String::as_str), Some("#000"));
+//! ```
+
+use core::ops::Shr;
+
+use crate::container::{KpContainer, KpEntry};
+use crate::lens::{Lens, ReadKp, Then, WriteKp};
+use crate::{EnumKp, Kp};
+
+/// A keypath whose writes can create missing intermediates.
+pub trait FillKp: WriteKp {
+    /// Like [WriteKp::write], but fills empty options, inserts missing entries and grows
+    /// vectors on the way. `None` only when a step cannot be created, e.g. an enum variant
+    /// that is not the active one.
+    fn get_mut_or_default<'a>(&self, root: &'a mut Self::Root) -> Option<&'a mut Self::Value>;
+
+    /// Continue into the `Some` of this keypath's `Option` value (see [OrDefault]).
+    fn or_default(self) -> OrDefault<Self>
+    where
+        Self: Sized,
+    {
+        OrDefault::new(self)
+    }
+
+    /// Continue into the item at `key` of this keypath's container value (see [Entry]).
+    fn entry<Key>(self, key: Key) -> Entry<Self, Key>
+    where
+        Self: Sized,
+    {
+        Entry::new(self, key)
+    }
+}
+
+impl<K: FillKp + ?Sized> FillKp for &K {
+    #[inline]
+    fn get_mut_or_default<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut K::Value> {
+        (**self).get_mut_or_default(root)
+    }
+}
+
+impl<'x, R, V, G, S> FillKp for Kp<R, V, &'x R, &'x V, &'x mut R, &'x mut V, G, S>
+where
+    G: for<'b> Fn(&'b R) -> Option<&'b V>,
+    S: for<'b> Fn(&'b mut R) -> Option<&'b mut V>,
+{
+    #[inline]
+    fn get_mut_or_default<'a>(&self, root: &'a mut R) -> Option<&'a mut V> {
+        self.write(root)
+    }
+}
+
+impl<'x, Enum, Variant, G, S, E> FillKp
+    for EnumKp<Enum, Variant, &'x Enum, &'x Variant, &'x mut Enum, &'x mut Variant, G, S, E>
+where
+    G: for<'b> Fn(&'b Enum) -> Option<&'b Variant>,
+    S: for<'b> Fn(&'b mut Enum) -> Option<&'b mut Variant>,
+    E: Fn(Variant) -> Enum,
+{
+    #[inline]
+    fn get_mut_or_default<'a>(&self, root: &'a mut Enum) -> Option<&'a mut Variant> {
+        self.write(root)
+    }
+}
+
+impl<A, B> FillKp for Then<A, B>
+where
+    A: FillKp,
+    A::Value: 'static,
+    B: FillKp<Root = A::Value>,
+{
+    #[inline]
+    fn get_mut_or_default<'a>(&self, root: &'a mut A::Root) -> Option<&'a mut B::Value> {
+        self.second.get_mut_or_default(self.first.get_mut_or_default(root)?)
+    }
+}
+
+/// `Option<T>` → `T`: reads and writes see `Some` only, [FillKp::get_mut_or_default] stores
+/// `T::default()` into an empty option first.
+#[derive(Clone, Copy)]
+pub struct OrDefault<K> {
+    pub(crate) kp: K,
+}
+
+impl<K> OrDefault<K> {
+    pub fn new(kp: K) -> Self {
+        Self { kp }
+    }
+}
+
+impl<K, T> Lens for OrDefault<K>
+where
+    K: Lens<Value = Option<T>>,
+{
+    type Root = K::Root;
+    type Value = T;
+}
+
+impl<K, T> ReadKp for OrDefault<K>
+where
+    K: ReadKp<Value = Option<T>>,
+{
+    #[inline]
+    fn read<'a>(&self, root: &'a K::Root) -> Option<&'a T> {
+        self.kp.read(root)?.as_ref()
+    }
+}
+
+impl<K, T> WriteKp for OrDefault<K>
+where
+    K: WriteKp<Value = Option<T>>,
+{
+    #[inline]
+    fn write<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut T> {
+        self.kp.write(root)?.as_mut()
+    }
+}
+
+impl<K, T> FillKp for OrDefault<K>
+where
+    K: FillKp<Value = Option<T>>,
+    T: Default,
+{
+    #[inline]
+    fn get_mut_or_default<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut T> {
+        Some(self.kp.get_mut_or_default(root)?.get_or_insert_with(T::default))
+    }
+}
+
+/// The item at `key` of a keyed container ([KpEntry]). Reads and writes see existing items
+/// only; [FillKp::get_mut_or_default] inserts (maps) or grows to (vectors) the missing one.
+#[derive(Clone)]
+pub struct Entry<K, Key> {
+    pub(crate) kp: K,
+    pub(crate) key: Key,
+}
+
+impl<K, Key> Entry<K, Key> {
+    pub fn new(kp: K, key: Key) -> Self {
+        Self { kp, key }
+    }
+
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+}
+
+impl<K, Key> Lens for Entry<K, Key>
+where
+    K: Lens,
+    K::Value: KpEntry<Key = Key>,
+{
+    type Root = K::Root;
+    type Value = <K::Value as KpContainer>::Item;
+}
+
+impl<K, Key> ReadKp for Entry<K, Key>
+where
+    K: ReadKp,
+    K::Value: KpEntry<Key = Key> + 'static,
+{
+    #[inline]
+    fn read<'a>(&self, root: &'a K::Root) -> Option<&'a Self::Value> {
+        self.kp.read(root)?.kp_get(&self.key)
+    }
+}
+
+impl<K, Key> WriteKp for Entry<K, Key>
+where
+    K: WriteKp,
+    K::Value: KpEntry<Key = Key> + 'static,
+{
+    #[inline]
+    fn write<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut Self::Value> {
+        self.kp.write(root)?.kp_get_mut(&self.key)
+    }
+}
+
+impl<K, Key> FillKp for Entry<K, Key>
+where
+    K: FillKp,
+    K::Value: KpEntry<Key = Key> + 'static,
+    Key: Clone,
+{
+    #[inline]
+    fn get_mut_or_default<'a>(&self, root: &'a mut K::Root) -> Option<&'a mut Self::Value> {
+        Some(self.kp.get_mut_or_default(root)?.kp_entry(self.key.clone()))
+    }
+}
+
+impl<K, T, N> Shr<N> for OrDefault<K>
+where
+    K: Lens<Value = Option<T>>,
+    N: Lens<Root = T>,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
+
+impl<K, Key, N> Shr<N> for Entry<K, Key>
+where
+    K: Lens,
+    K::Value: KpEntry<Key = Key>,
+    N: Lens<Root = <K::Value as KpContainer>::Item>,
+{
+    type Output = Then<Self, N>;
+
+    #[inline]
+    fn shr(self, next: N) -> Then<Self, N> {
+        self.compose(next)
+    }
+}
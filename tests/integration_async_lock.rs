@@ -106,3 +106,43 @@ async fn integration_async_lock_then_lock_then_chain() {
     let l3_again = async_kp.get(l2_again).await;
     assert_eq!(std_lock_kp.get(l3_again.unwrap()), Some(&99));
 }
+
+/// The same chain seen through the `AsyncReadKp` trait: a helper that only knows root and value.
+#[tokio::test]
+async fn integration_async_read_kp_through_tokio_lock() {
+    use rust_key_paths::lens::{AsyncReadKp, Lens};
+
+    async fn read_i32(kp: &impl AsyncReadKp<Root = Level2, Value = i32>, root: &Level2) -> Option<i32> {
+        kp.read_async(root).await.copied()
+    }
+
+    #[derive(Clone)]
+    struct Counter {
+        hits: i32,
+    }
+    struct Holder {
+        counter: Arc<tokio::sync::Mutex<Counter>>,
+    }
+
+    let holder = Holder {
+        counter: Arc::new(tokio::sync::Mutex::new(Counter { hits: 3 })),
+    };
+    let counter_lock: KpType<Holder, Arc<tokio::sync::Mutex<Counter>>> = Kp::new(
+        |h: &Holder| Some(&h.counter),
+        |h: &mut Holder| Some(&mut h.counter),
+    );
+    let counter: KpType<Counter, Counter> = Kp::new(|c: &Counter| Some(c), |c: &mut Counter| Some(c));
+    let hits: KpType<Counter, i32> = Kp::new(|c: &Counter| Some(&c.hits), |c: &mut Counter| Some(&mut c.hits));
+
+    let holder_hits = AsyncLockKp::new(counter_lock, TokioMutexAccess::new(), counter).compose(hits);
+    assert_eq!(holder_hits.read_async(&holder).await, Some(&3));
+
+    let level2 = Level2 {
+        value: 9,
+        rwlock: Arc::new(tokio::sync::RwLock::new(Level3 {
+            value: std::sync::RwLock::new(0),
+        })),
+    };
+    let value: KpType<Level2, i32> = Kp::new(|l: &Level2| Some(&l.value), |l: &mut Level2| Some(&mut l.value));
+    assert_eq!(read_i32(&value, &level2).await, Some(9));
+}